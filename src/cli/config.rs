@@ -1,6 +1,8 @@
 use clap::Subcommand;
 
+use super::OutputFormat;
 use crate::commands::Shell;
+use worktrunk::shell::BashRc;
 
 #[derive(Subcommand)]
 pub enum ConfigShellCommand {
@@ -25,7 +27,45 @@ wt config shell init fish | source
 Zsh (~/.zshrc):
 ```zsh
 eval "$(wt config shell init zsh)"
-```"#
+```
+
+Nushell (saved once to the autoload directory, since nushell can't eval at startup):
+```nu
+wt config shell init nu | save --force ($nu.data-dir | path join vendor autoload wt.nu)
+```
+
+Elvish (~/.config/elvish/rc.elv; completions aren't available yet):
+```elvish
+eval (wt config shell init elvish | slurp)
+```
+
+Xonsh (~/.config/xonsh/rc.xsh; completions aren't available yet):
+```xonsh
+execx($(wt config shell init xonsh))
+```
+
+Tcsh (~/.tcshrc, or ~/.cshrc for csh; completions aren't available yet):
+```csh
+eval "`wt config shell init tcsh`"
+```
+
+## Caching
+
+With `--cache`, the script is written to the user cache directory (e.g., `~/.cache/worktrunk/`) and the output is a line for the shell config that sources that file directly, so shell startup doesn't run wt at all. wt only runs again if the cached file goes missing. The cache is keyed by worktrunk version, so after upgrading, rerun the command to point the line at the new script:
+```console
+wt config shell init zsh --cache >> ~/.zshrc
+```
+
+## Sections
+
+By default the output contains both the shell wrapper (which lets `wt switch` change directory) and completion registration. Packagers who install completions separately can split them:
+
+```console
+wt config shell init zsh --integration-only
+wt config shell init zsh --completions-only
+```
+
+For fish, `--completions-only` prints the completion file normally installed to `~/.config/fish/completions/`. Elvish, xonsh, and csh have no completions, so `--completions-only` prints a notice and no code."#
     )]
     Init {
         /// Shell to generate code for
@@ -39,6 +79,26 @@ eval "$(wt config shell init zsh)"
         /// instead of `wt`, useful on Windows where `wt` conflicts with Windows Terminal.
         #[arg(long)]
         cmd: Option<String>,
+
+        /// Output a shell config line that sources a cached copy of the script
+        ///
+        /// The script is written under the user cache directory, keyed by shell,
+        /// command name, and worktrunk version, and rewritten whenever its content
+        /// changes. Ignored for nu and elvish.
+        #[arg(long, overrides_with = "no_cache")]
+        cache: bool,
+
+        /// Output the full script instead of a cached copy (default)
+        #[arg(long, overrides_with = "cache")]
+        no_cache: bool,
+
+        /// Output only the shell wrapper, without completions
+        #[arg(long, conflicts_with_all = ["completions_only", "cache"])]
+        integration_only: bool,
+
+        /// Output only completions, without the shell wrapper
+        #[arg(long, conflicts_with = "cache")]
+        completions_only: bool,
     },
 
     /// Write shell integration to config files
@@ -57,8 +117,27 @@ Install for specific shell only:
 wt config shell install zsh
 ```
 
+Write to a config file outside the default locations (e.g., with `ZDOTDIR` unset):
+```console
+wt config shell install zsh --rc-file ~/.config/zsh/.zshrc
+```
+
+Put the bash line in `~/.bash_profile` (e.g., macOS login shells that don't source `~/.bashrc`):
+```console
+wt config shell install bash --bash-rc bash_profile
+```
+
+Report what would change as JSON, for scripts:
+```console
+wt config shell install --dry-run --format=json
+```
+
 Shows proposed changes and waits for confirmation before modifying any files.
-Use --yes to skip confirmation."#
+Use --yes to skip confirmation, or --dry-run to only show the changes.
+
+Without a shell argument, the shell you're running (from `$SHELL`, or the parent process when that isn't set) is listed first.
+
+Warns when a shell's integration line is (or would be) in more than one of its config files, such as both `~/.bashrc` and `~/.bash_profile`, since it then loads twice. Nothing is removed; delete the extra line by hand."#
     )]
     Install {
         /// Shell to install (default: all)
@@ -69,11 +148,38 @@ Use --yes to skip confirmation."#
         #[arg(short, long)]
         yes: bool,
 
+        /// Show changes without writing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Write to this file instead of detected config files
+        ///
+        /// Created if it doesn't exist. The shell is inferred from the file
+        /// name (e.g. `.zshrc`, `config.fish`) unless given explicitly.
+        #[arg(long, value_name = "PATH")]
+        rc_file: Option<std::path::PathBuf>,
+
+        /// Bash startup file to write to
+        ///
+        /// By default bash uses `~/.bashrc`, falling back to `~/.bash_profile`
+        /// when only that exists. The chosen file is created if it doesn't exist.
+        #[arg(long, value_enum, value_name = "FILE", conflicts_with = "rc_file")]
+        bash_rc: Option<BashRc>,
+
+        /// Output format (table, json)
+        ///
+        /// JSON lists each shell as `{shell, config_path, action}`, where `action`
+        /// is `modified`, `already_configured`, `skipped`, or `would_modify`.
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
+
         /// Command name for shell integration (defaults to binary name)
         ///
         /// Use this to create shell integration for an alternate command name.
         /// For example, `--cmd=git-wt` creates a `git-wt` shell function
         /// instead of `wt`, useful on Windows where `wt` conflicts with Windows Terminal.
+        /// The name is recorded per shell in the user config (`[shell-cmd]`), so
+        /// later installs without `--cmd` keep using it.
         #[arg(long)]
         cmd: Option<String>,
     },
@@ -209,6 +315,85 @@ This tests:
         full: bool,
     },
 
+    /// Print effective configuration
+    #[command(
+        after_long_help = r#"Merges user config (`~/.config/worktrunk/config.toml`) with the current
+project's config (`.config/wt.toml`) and prints what `wt` actually uses, after
+environment variable overrides.
+
+Each setting is keyed by its source: `global` (user config), `project`, or
+`default`. Every hook type is listed under `hooks`; an empty table means the
+hook is unset. Global hooks run before project hooks.
+
+```console
+wt config print
+```
+
+```toml
+[worktree-path]
+default = "../{{ repo }}.{{ branch | sanitize }}"
+
+[hooks.pre-create]
+
+[hooks.post-create]
+project = "npm ci"
+```
+
+Use `--format=json` for scripting."#
+    )]
+    Print {
+        /// Output format (toml, json)
+        #[arg(long, default_value = "toml")]
+        format: super::ConfigPrintFormat,
+    },
+
+    /// Get a user config setting
+    #[command(
+        after_long_help = r#"Prints the value of a setting in the user config file
+(`~/.config/worktrunk/config.toml`). Nested settings use dotted keys; strings print
+as-is and other values as TOML. Unset settings print a message to stderr and
+nothing to stdout.
+
+```console
+wt config get commit-generation.command
+```
+
+Reads only the file; environment variable overrides are not applied. Use
+`wt config print` for the effective configuration."#
+    )]
+    Get {
+        /// Setting to read (e.g., `worktree-path`, `commit-generation.command`)
+        key: String,
+    },
+
+    /// Set a user config setting
+    #[command(
+        after_long_help = r#"Writes a setting to the user config file (`~/.config/worktrunk/config.toml`),
+creating it if needed. Comments and other settings are preserved.
+
+Nested settings use dotted keys; snake_case spellings like
+`commit_generation.command` are accepted. The value is typed by the config
+schema: `true`, `42`, and `["-m", "haiku"]` are read as TOML where the setting
+expects a boolean, number, or list, and anything else is stored as a string.
+
+```console
+wt config set commit-generation.command llm
+wt config set commit-generation.args '["-m", "claude-haiku-4.5"]'
+wt config set list.full true
+wt config set worktree-path '.worktrees/{{ branch | sanitize }}'
+```
+
+Values that don't fit the setting, and keys the config doesn't recognize, are
+rejected without changing the file."#
+    )]
+    Set {
+        /// Setting to write (e.g., `worktree-path`, `commit-generation.command`)
+        key: String,
+
+        /// New value
+        value: String,
+    },
+
     /// Manage internal data and cache
     #[command(
         after_long_help = r#"State is stored in `.git/` (config entries and log files), separate from configuration files.
@@ -10,7 +10,7 @@ pub enum HookCommand {
     /// Lists user and project hooks. Project hooks show approval status (❓ = needs approval).
     Show {
         /// Hook type to show (default: all)
-        #[arg(value_parser = ["post-create", "post-start", "post-switch", "pre-commit", "pre-merge", "post-merge", "pre-remove"])]
+        #[arg(value_parser = ["pre-create", "post-create", "post-start", "post-switch", "pre-commit", "pre-merge", "post-merge", "pre-remove"])]
         hook_type: Option<String>,
 
         /// Show expanded commands with current variables
@@ -18,6 +18,30 @@ pub enum HookCommand {
         expanded: bool,
     },
 
+    /// Run pre-create hooks
+    ///
+    /// Blocking, fail-fast — a failing command exits non-zero.
+    PreCreate {
+        /// Filter by command name
+        ///
+        /// Supports `user:name` or `project:name` to filter by source.
+        /// `user:` alone runs all user hooks; `project:` alone runs all project hooks.
+        #[arg(add = crate::completion::hook_command_name_completer())]
+        name: Option<String>,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Set template variable, overriding built-ins (KEY=VALUE)
+        #[arg(long = "var", value_name = "KEY=VALUE", value_parser = super::parse_key_val, action = clap::ArgAction::Append)]
+        vars: Vec<(String, String)>,
+
+        /// Print the expanded commands without running them
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// Run post-create hooks
     ///
     /// Blocking — waits for completion before continuing.
@@ -33,9 +57,13 @@ pub enum HookCommand {
         #[arg(short, long)]
         yes: bool,
 
-        /// Override built-in template variable (KEY=VALUE)
+        /// Set template variable, overriding built-ins (KEY=VALUE)
         #[arg(long = "var", value_name = "KEY=VALUE", value_parser = super::parse_key_val, action = clap::ArgAction::Append)]
         vars: Vec<(String, String)>,
+
+        /// Print the expanded commands without running them
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Run post-start hooks
@@ -57,13 +85,25 @@ pub enum HookCommand {
         #[arg(long)]
         foreground: bool,
 
+        /// Run commands concurrently in foreground (implies --foreground)
+        ///
+        /// Output lines are prefixed with the command name. At most
+        /// `post-start-parallelism` commands run at once (default: number of CPUs).
+        /// Fails if any command fails.
+        #[arg(long)]
+        parallel: bool,
+
         /// Deprecated: use --foreground instead
         #[arg(long = "no-background", hide = true)]
         no_background: bool,
 
-        /// Override built-in template variable (KEY=VALUE)
+        /// Set template variable, overriding built-ins (KEY=VALUE)
         #[arg(long = "var", value_name = "KEY=VALUE", value_parser = super::parse_key_val, action = clap::ArgAction::Append)]
         vars: Vec<(String, String)>,
+
+        /// Print the expanded commands without running them
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Run post-switch hooks
@@ -89,9 +129,13 @@ pub enum HookCommand {
         #[arg(long = "no-background", hide = true)]
         no_background: bool,
 
-        /// Override built-in template variable (KEY=VALUE)
+        /// Set template variable, overriding built-ins (KEY=VALUE)
         #[arg(long = "var", value_name = "KEY=VALUE", value_parser = super::parse_key_val, action = clap::ArgAction::Append)]
         vars: Vec<(String, String)>,
+
+        /// Print the expanded commands without running them
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Run pre-commit hooks
@@ -107,9 +151,13 @@ pub enum HookCommand {
         #[arg(short, long)]
         yes: bool,
 
-        /// Override built-in template variable (KEY=VALUE)
+        /// Set template variable, overriding built-ins (KEY=VALUE)
         #[arg(long = "var", value_name = "KEY=VALUE", value_parser = super::parse_key_val, action = clap::ArgAction::Append)]
         vars: Vec<(String, String)>,
+
+        /// Print the expanded commands without running them
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Run pre-merge hooks
@@ -125,9 +173,13 @@ pub enum HookCommand {
         #[arg(short, long)]
         yes: bool,
 
-        /// Override built-in template variable (KEY=VALUE)
+        /// Set template variable, overriding built-ins (KEY=VALUE)
         #[arg(long = "var", value_name = "KEY=VALUE", value_parser = super::parse_key_val, action = clap::ArgAction::Append)]
         vars: Vec<(String, String)>,
+
+        /// Print the expanded commands without running them
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Run post-merge hooks
@@ -143,9 +195,13 @@ pub enum HookCommand {
         #[arg(short, long)]
         yes: bool,
 
-        /// Override built-in template variable (KEY=VALUE)
+        /// Set template variable, overriding built-ins (KEY=VALUE)
         #[arg(long = "var", value_name = "KEY=VALUE", value_parser = super::parse_key_val, action = clap::ArgAction::Append)]
         vars: Vec<(String, String)>,
+
+        /// Print the expanded commands without running them
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Run pre-remove hooks
@@ -161,9 +217,13 @@ pub enum HookCommand {
         #[arg(short, long)]
         yes: bool,
 
-        /// Override built-in template variable (KEY=VALUE)
+        /// Set template variable, overriding built-ins (KEY=VALUE)
         #[arg(long = "var", value_name = "KEY=VALUE", value_parser = super::parse_key_val, action = clap::ArgAction::Append)]
         vars: Vec<(String, String)>,
+
+        /// Print the expanded commands without running them
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Manage command approvals
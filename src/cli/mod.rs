@@ -14,14 +14,15 @@ pub use step::StepCommand;
 use clap::builder::styling::{AnsiColor, Color, Styles};
 use clap::{Command, CommandFactory, Parser, Subcommand, ValueEnum};
 use std::sync::OnceLock;
-use worktrunk::config::{DEPRECATED_TEMPLATE_VARS, TEMPLATE_VARS};
 
 use crate::commands::Shell;
 
-/// Parse key=value string into a tuple, validating that the key is a known template variable.
+/// Parse key=value string into a tuple.
 ///
-/// Used by the `--var` flag on hook commands to override built-in template variables.
-/// Values are shell-escaped during template expansion (see `expand_template` in expansion.rs).
+/// Used by the `--var` flag on hook commands to override built-in template variables
+/// or inject custom ones. Keys aren't restricted to `TEMPLATE_VARS` so hooks can be
+/// tested with arbitrary variables. Values are shell-escaped during template expansion
+/// (see `expand_template` in expansion.rs).
 pub(super) fn parse_key_val(s: &str) -> Result<(String, String), String> {
     let (key, value) = s
         .split_once('=')
@@ -29,13 +30,6 @@ pub(super) fn parse_key_val(s: &str) -> Result<(String, String), String> {
     if key.is_empty() {
         return Err("invalid KEY=VALUE: key cannot be empty".to_string());
     }
-    if !TEMPLATE_VARS.contains(&key) && !DEPRECATED_TEMPLATE_VARS.contains(&key) {
-        return Err(format!(
-            "unknown variable `{key}`; valid variables: {} (deprecated: {})",
-            TEMPLATE_VARS.join(", "),
-            DEPRECATED_TEMPLATE_VARS.join(", ")
-        ));
-    }
     Ok((key.to_string(), value.to_string()))
 }
 
@@ -162,6 +156,46 @@ pub enum OutputFormat {
     Json,
 }
 
+/// Output format for `wt config print`
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum ConfigPrintFormat {
+    /// TOML output
+    Toml,
+    /// JSON output
+    Json,
+}
+
+/// Output format for `wt list`
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum ListFormat {
+    /// Human-readable table format
+    Table,
+    /// JSON output
+    Json,
+    /// Stable line-based output, modeled on `git worktree list --porcelain`
+    Porcelain,
+}
+
+/// Format for `-v` / `RUST_LOG` log output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogFormat {
+    /// Human-readable lines
+    #[default]
+    Text,
+    /// One JSON object per line, with timestamp, level, target, and message
+    Json,
+}
+
+/// Format for errors that end a command
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ErrorFormat {
+    /// Styled messages with hints
+    #[default]
+    Text,
+    /// One JSON object, `{"error": {"kind", "message"}}`
+    Json,
+}
+
 #[derive(Parser)]
 #[command(name = "wt")]
 #[command(about = "Git worktree management for parallel AI agent workflows", long_about = None)]
@@ -196,6 +230,20 @@ pub struct Cli {
     )]
     pub directory: Option<std::path::PathBuf>,
 
+    /// Repository to operate on
+    ///
+    /// Like `-C`, but errors unless the path is in a git repository. Shell
+    /// setup (`wt config shell`) ignores it.
+    #[arg(
+        long,
+        global = true,
+        value_name = "path",
+        conflicts_with = "directory",
+        display_order = 100,
+        help_heading = "Global Options"
+    )]
+    pub repo: Option<std::path::PathBuf>,
+
     /// User config file path
     #[arg(
         long,
@@ -206,7 +254,7 @@ pub struct Cli {
     )]
     pub config: Option<std::path::PathBuf>,
 
-    /// Show debug info (-v), or also write diagnostic report (-vv)
+    /// Show debug info (-v), or also trace logs and a diagnostic report (-vv)
     #[arg(
         long,
         short = 'v',
@@ -217,6 +265,39 @@ pub struct Cli {
     )]
     pub verbose: u8,
 
+    /// Hide info and hint messages; errors and warnings still show
+    #[arg(
+        long,
+        short = 'q',
+        global = true,
+        conflicts_with = "verbose",
+        display_order = 103,
+        help_heading = "Global Options"
+    )]
+    pub quiet: bool,
+
+    /// Log output format
+    #[arg(
+        long,
+        global = true,
+        value_name = "format",
+        default_value = "text",
+        display_order = 104,
+        help_heading = "Global Options"
+    )]
+    pub log_format: LogFormat,
+
+    /// Error output format
+    #[arg(
+        long,
+        global = true,
+        value_name = "format",
+        default_value = "text",
+        display_order = 105,
+        help_heading = "Global Options"
+    )]
+    pub error_format: ErrorFormat,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -237,11 +318,12 @@ wt switch feature-auth           # Switch to worktree
 wt switch -                      # Previous worktree (like cd -)
 wt switch --create new-feature   # Create new branch and worktree
 wt switch --create hotfix --base production
+wt switch                        # Pick from existing worktrees
 ```
 
 ## Creating a branch
 
-The `--create` flag creates a new branch from the `--base` branch (defaults to default branch). Without `--create`, the branch must already exist.
+The `--create` flag creates a new branch from the `--base` branch (defaults to default branch). Without `--create`, the branch must already exist; with it, an existing branch is switched to as usual.
 
 ## Creating worktrees
 
@@ -249,18 +331,22 @@ If the branch already has a worktree, `wt switch` changes directories to it. Oth
 
 When creating a worktree, worktrunk:
 
-1. Creates worktree at configured path
-2. Switches to new directory
-3. Runs [post-create hooks](@/hook.md#post-create) (blocking)
-4. Spawns [post-start hooks](@/hook.md#post-start) (background)
+1. Runs [pre-create hooks](@/hook.md#pre-create) (blocking; failure aborts)
+2. Creates worktree at configured path
+3. Switches to new directory
+4. Runs [post-create hooks](@/hook.md#post-create) (blocking)
+5. Spawns [post-start hooks](@/hook.md#post-start) (background)
 
 ```console
-wt switch feature                        # Existing branch → creates worktree
-wt switch --create feature               # New branch and worktree
-wt switch --create fix --base release    # New branch from release
-wt switch --create temp --no-verify      # Skip hooks
+wt switch feature                          # Existing branch → creates worktree
+wt switch --create feature                 # New branch and worktree
+wt switch --create fix --base release      # New branch from release
+wt switch --create fix --branch-from v1.2  # New branch from a tag
+wt switch --create temp --no-verify        # Skip hooks
 ```
 
+`--no-verify` (or its alias `--no-hooks`) still creates the worktree and changes into it, but skips every hook, including post-create setup such as installing dependencies. The worktree may not be ready to use until that setup runs; run it later with [`wt hook post-create`](@/hook.md).
+
 ## Shortcuts
 
 | Shortcut | Meaning |
@@ -275,6 +361,48 @@ wt switch ^                      # Default branch worktree
 wt switch --create fix --base=@  # Branch from current HEAD
 ```
 
+## Picking a worktree
+
+Without a branch (or with `--list`), `wt switch` shows a picker of existing worktrees and switches to the selected one. When stdin isn't a terminal, it prints the worktrees (branch and path, one per line) and exits instead. For previews and branches without worktrees, see [`wt select`](@/select.md).
+
+## Fuzzy matching
+
+When the name isn't a branch, it's matched against the branches of existing worktrees: first as a substring, then as letters in order (`flg` matches `feature/login`). A single match is switched to; several are listed so the name can be narrowed. Use `--exact` in scripts to turn this off.
+
+```console
+wt switch login                  # Switches to feature/login
+```
+
+## Printing the path
+
+Outside the shell wrapper (editors, scripts, shells without integration), `--print-path` resolves an existing worktree and prints its absolute path to stdout, without switching or running hooks:
+
+```console
+cd "$(wt switch feature --print-path)"
+```
+
+It exits non-zero when the branch has no worktree, and never writes a `cd` directive, so it behaves the same with shell integration active. It can't be combined with `--create`, `--base`, `--execute`, or `--clobber`.
+
+## Porcelain output
+
+Tools that wrap `wt switch` can pass `--porcelain` to switch as usual and get the destination as a directive line on stdout:
+
+```
+wt-directive:1 cd /path/to/worktree
+```
+
+Each line is `wt-directive:<version>`, a verb, and an argument running verbatim to the end of the line (no quoting). `cd` is the only verb in version 1. The format won't change within a version; an incompatible change bumps the version, so parsers should ignore lines with a version they don't know. Messages still go to stderr, and the shell wrapper's own directive file is written as before.
+
+## Post-switch command
+
+`post-switch-command` in the user config runs after every switch, in any repository. Unlike [post-switch hooks](@/hook.md#post-switch), which run in the background, the shell wrapper runs it in your shell once it has changed directory, so tools that hook the shell see the new worktree:
+
+```toml
+post-switch-command = "direnv reload"
+```
+
+It requires shell integration, and runs even with `--no-verify`. Use `--no-post-switch` to skip it for one switch.
+
 ## When wt switch fails
 
 - **Branch doesn't exist** — Use `--create`, or check `wt list --branches`
@@ -294,12 +422,19 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
     Switch {
         /// Branch name
         ///
-        /// Shortcuts: '^' (default branch), '-' (previous), '@' (current)
+        /// Shortcuts: '^' (default branch), '-' (previous), '@' (current).
+        /// Omit to pick from existing worktrees.
         #[arg(add = crate::completion::worktree_branch_completer())]
-        branch: String,
+        branch: Option<String>,
+
+        /// Pick from existing worktrees
+        ///
+        /// Prints the worktrees instead when stdin isn't a terminal.
+        #[arg(long, conflicts_with_all = ["branch", "create", "base", "print_path"])]
+        list: bool,
 
         /// Create a new branch
-        #[arg(short = 'c', long)]
+        #[arg(short = 'c', long, requires = "branch")]
         create: bool,
 
         /// Base branch
@@ -308,6 +443,29 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
         #[arg(short = 'b', long, add = crate::completion::branch_value_completer())]
         base: Option<String>,
 
+        /// Start the new branch at a ref
+        ///
+        /// Like --base, but checked upfront: the ref (branch, tag, or SHA)
+        /// must exist, and it's an error unless --create makes a new branch.
+        #[arg(
+            long,
+            value_name = "REF",
+            conflicts_with_all = ["base", "list", "print_path"],
+            add = crate::completion::branch_value_completer()
+        )]
+        branch_from: Option<String>,
+
+        /// Check out a ref in detached HEAD
+        ///
+        /// Enters a scratch worktree at the commit the ref names (branch,
+        /// tag, or SHA), creating it on first use. Skips hooks.
+        #[arg(
+            long,
+            requires = "branch",
+            conflicts_with_all = ["list", "create", "base", "branch_from", "execute", "clobber", "exact", "print_path"]
+        )]
+        detach: bool,
+
         /// Command to run after switch
         ///
         /// Replaces the wt process with the command after switching, giving
@@ -343,9 +501,46 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
         #[arg(long)]
         clobber: bool,
 
+        /// Match the branch name exactly
+        ///
+        /// Disables fuzzy matching of names that aren't a branch.
+        #[arg(long)]
+        exact: bool,
+
+        /// Print the worktree's path instead of switching
+        ///
+        /// Prints only the absolute path to stdout, for use outside shell
+        /// integration. Fails if the branch has no worktree.
+        #[arg(
+            long,
+            requires = "branch",
+            conflicts_with_all = ["create", "base", "execute", "clobber"]
+        )]
+        print_path: bool,
+
+        /// Print a versioned cd directive to stdout
+        ///
+        /// For tools that wrap wt: after switching, prints
+        /// `wt-directive:1 cd <path>` so the caller can change directory.
+        #[arg(long, conflicts_with_all = ["execute", "print_path"])]
+        porcelain: bool,
+
         /// Skip hooks
-        #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
+        #[arg(
+            long = "no-verify",
+            visible_alias = "no-hooks",
+            action = clap::ArgAction::SetFalse,
+            default_value_t = true
+        )]
         verify: bool,
+
+        /// Skip the configured post-switch-command
+        #[arg(
+            long = "no-post-switch",
+            action = clap::ArgAction::SetFalse,
+            default_value_t = true
+        )]
+        post_switch: bool,
     },
 
     /// List worktrees and their status
@@ -378,6 +573,12 @@ Include branches that don't have worktrees:
 $ wt list --branches --full
 ```
 
+Flag worktrees that are ready for cleanup:
+
+```console
+$ wt list --stale
+```
+
 Output as JSON for scripting:
 
 ```console
@@ -397,6 +598,7 @@ $ wt list --format=json
 | Remote⇅ | Commits ahead/behind tracking branch |
 | URL | Dev server URL from project config (dimmed if port not listening) |
 | CI | Pipeline status (`--full`) |
+| Stale | `merged` or `stale` (`--stale`) |
 | Commit | Short hash (8 chars) |
 | Age | Time since last commit |
 | Message | Last commit message (truncated) |
@@ -419,6 +621,10 @@ The CI column shows GitHub/GitLab pipeline status:
 
 CI indicators are clickable links to the PR or pipeline page. Any CI dot appears dimmed when there are unpushed local changes (stale status). PRs/MRs are checked first, then branch workflows/pipelines for branches with an upstream. Local-only branches show blank. Results are cached for 30-60 seconds; use `wt config state` to view or clear.
 
+### Stale worktrees
+
+`--stale` marks branches that are likely safe to clean up. `merged` means HEAD is reachable from the default branch, as in `git branch --merged`. `stale` means the HEAD commit is older than the given number of days (30 by default, e.g. `--stale 90`). A branch that is both shows `merged`. The default branch is never marked.
+
 ## Status symbols
 
 The Status column has multiple subcolumns. Within each, only the first matching symbol is shown (listed in priority order):
@@ -501,6 +707,8 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `ci` | object | CI status (see below, absent when no CI) |
 | `url` | string | Dev server URL from project config (absent when not configured) |
 | `url_active` | boolean | Whether the URL's port is listening (absent when not configured) |
+| `merged` | boolean | HEAD is merged into the default branch (with `--stale`) |
+| `stale` | boolean | HEAD commit is older than the `--stale` threshold (with `--stale`) |
 | `statusline` | string | Pre-formatted status with ANSI colors |
 | `symbols` | string | Raw status symbols without colors (e.g., `"!?↓"`) |
 
@@ -575,6 +783,23 @@ When `main_state == "integrated"`: `"ancestor"` `"trees_match"` `"no_added_chang
 
 Missing a field that would be generally useful? Open an issue at https://github.com/max-sixty/worktrunk.
 
+## Porcelain output
+
+`--format=porcelain` prints one record per item, separated by blank lines, with one `key value` attribute per line — the same shape as `git worktree list --porcelain`:
+
+```
+worktree /path/to/repo
+HEAD 1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b
+branch main
+
+worktree /path/to/repo.feature
+HEAD 9f8e7d6c5b4a39281706f5e4d3c2b1a098f7e6d5
+detached
+locked reason text
+```
+
+Worktrees start with `worktree`; branches without worktrees (`--branches`) omit it. `branch` is replaced by `detached` for detached HEAD. `locked` and `prunable` appear only when set, followed by the reason if git recorded one. The format is stable across releases; new attributes may be added, so ignore unknown keys.
+
 ## See also
 
 - [`wt select`](@/select.md) — Interactive worktree picker with live preview
@@ -589,9 +814,9 @@ Missing a field that would be generally useful? Open an issue at https://github.
         #[command(subcommand)]
         subcommand: Option<ListSubcommand>,
 
-        /// Output format (table, json)
+        /// Output format (table, json, porcelain)
         #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
-        format: OutputFormat,
+        format: ListFormat,
 
         /// Include branches without worktrees
         #[arg(long)]
@@ -605,6 +830,10 @@ Missing a field that would be generally useful? Open an issue at https://github.
         #[arg(long)]
         full: bool,
 
+        /// Flag merged branches and HEAD commits older than `days` (default 30)
+        #[arg(long, value_name = "days", num_args = 0..=1, default_missing_value = "30")]
+        stale: Option<u32>,
+
         /// Show fast info immediately, update with slow info
         ///
         /// Displays local data (branches, paths, status) first, then updates
@@ -648,6 +877,12 @@ Force-delete an unmerged branch:
 wt remove -D experimental
 ```
 
+Remove every worktree whose branch is merged into the default branch:
+
+```console
+wt remove --all-merged
+```
+
 ## Branch cleanup
 
 By default, branches are deleted when merging them would add nothing. This works with squash-merge and rebase workflows where commit history differs but file changes match.
@@ -664,7 +899,11 @@ The "same commit" check uses the local default branch; for other checks, **targe
 
 Branches showing `_` or `⊂` are dimmed as safe to delete.
 
-Use `-D` to force-delete branches with unmerged changes. Use `--no-delete-branch` to keep the branch regardless of status.
+Use `-D` to force-delete branches with unmerged changes. Use `--no-delete-branch` (or its alias `--keep-branch`) to keep the branch regardless of status; combine with `--force` to remove a worktree with uncommitted changes while keeping its branch.
+
+## Uncommitted changes
+
+A worktree with uncommitted changes (including untracked files) isn't removed silently. In an interactive terminal, `wt remove` lists the changes and asks before discarding them. Otherwise, such as in scripts, it declines and exits with an error. `--force` or `--yes` discards the changes without asking. With `--all-merged`, worktrees with uncommitted changes are skipped with a warning unless `--force` is given.
 
 ## Background removal
 
@@ -686,7 +925,12 @@ Removal runs in the background by default (returns immediately). Logs are writte
         branches: Vec<String>,
 
         /// Keep branch after removal
-        #[arg(long = "no-delete-branch", action = clap::ArgAction::SetFalse, default_value_t = true)]
+        #[arg(
+            long = "no-delete-branch",
+            visible_alias = "keep-branch",
+            action = clap::ArgAction::SetFalse,
+            default_value_t = true
+        )]
         delete_branch: bool,
 
         /// Delete unmerged branches
@@ -706,15 +950,321 @@ Removal runs in the background by default (returns immediately). Logs are writte
         verify: bool,
 
         /// Skip approval prompts
+        ///
+        /// Also removes worktrees with uncommitted changes without asking.
         #[arg(short, long)]
         yes: bool,
 
         /// Force worktree removal
         ///
-        /// Remove worktrees even if they contain untracked files (like build
-        /// artifacts). Without this flag, removal fails if untracked files exist.
+        /// Remove worktrees even if they have uncommitted changes or untracked
+        /// files (like build artifacts), discarding them.
+        ///
+        /// Without this flag, removal asks first in an interactive terminal and
+        /// fails otherwise.
+        ///
+        /// Pre-remove hook failures become warnings instead of aborting.
         #[arg(short, long)]
         force: bool,
+
+        /// Remove every worktree merged into the default branch
+        ///
+        /// Lists worktrees whose branch is merged and asks before removing
+        /// them. Skips the current and main worktrees, and worktrees with
+        /// uncommitted changes unless `--force`.
+        #[arg(long, conflicts_with_all = ["branches", "force_delete"])]
+        all_merged: bool,
+    },
+
+    /// Rename a branch and its worktree
+    #[command(
+        after_long_help = r#"Renames the branch and moves its worktree directory to match, so the directory isn't left named after the old branch.
+
+## Examples
+
+Rename a branch:
+
+```console
+wt rename feature-auth feature-login
+```
+
+## Behavior
+
+1. Renames the branch (`git branch -m`)
+2. Moves the worktree to the path the new name gets from `worktree-path` (`git worktree move`)
+3. Switches upstream from `origin/OLD` to `origin/NEW` when that remote branch exists; otherwise suggests pushing under the new name
+4. Changes directory to the moved worktree when run from inside it
+
+The worktree only moves when it's at the path `worktree-path` gives the old name; the main worktree and worktrees placed elsewhere keep their directory. The default branch can't be renamed, and the new name must not already exist.
+
+## See also
+
+- [`wt switch`](@/switch.md) — Switch to the renamed worktree
+- [`wt remove`](@/remove.md) — Remove a worktree and its branch
+"#
+    )]
+    Rename {
+        /// Branch to rename
+        #[arg(add = crate::completion::local_branches_completer())]
+        old: String,
+
+        /// New branch name
+        new: String,
+    },
+
+    /// Move a worktree to a new directory
+    #[command(
+        after_long_help = r#"Moves a worktree with `git worktree move`, keeping its branch. When run from inside the worktree, changes directory to the same place in its new location.
+
+## Examples
+
+Move a worktree out of the way:
+
+```console
+wt move feature ../archive/feature
+```
+
+Move the current worktree:
+
+```console
+wt move @ ~/scratch/experiment
+```
+
+## Behavior
+
+The target path must not exist; relative paths resolve against the current directory. The main worktree can't be moved.
+
+Worktrunk's state (previous branch for `wt switch -`, markers, CI cache) is keyed by branch, so it keeps working after the move. Once a worktree is away from its `worktree-path` location, `wt rename` renames its branch but leaves the directory where it is.
+
+## See also
+
+- [`wt switch`](@/switch.md) — Switch to the moved worktree
+- [`wt remove`](@/remove.md) — Remove a worktree and its branch
+"#
+    )]
+    Move {
+        /// Worktree to move
+        ///
+        /// Branch name or shortcut: `@` (current), `-` (previous), `^` (main).
+        #[arg(add = crate::completion::worktree_branch_completer())]
+        name: String,
+
+        /// New location for the worktree
+        path: std::path::PathBuf,
+    },
+
+    /// Clone a repository set up for worktrees
+    #[command(
+        after_long_help = r#"Clones a repository, runs its post-create hooks, and changes directory into the default branch's worktree.
+
+## Examples
+
+Clone into `./repo`:
+
+```console
+wt clone https://github.com/user/repo.git
+```
+
+Clone into the nested bare layout:
+
+```console
+wt clone --bare https://github.com/user/repo.git
+```
+
+## Layouts
+
+By default `wt clone` runs a regular `git clone`: the default branch is the main worktree at `<dir>`, and new worktrees go wherever `worktree-path` puts them.
+
+With `--bare`, the repository is cloned bare into `<dir>/.git` and the default branch is checked out at `<dir>/<branch>`. The remote's fetch refspec is set so remote-tracking branches and upstreams work as in a regular clone. To create later worktrees beside it, set `worktree-path = "../{{ branch | sanitize }}"` (see [Bare repository layout](@/tips-patterns.md#bare-repository-layout)).
+
+`<dir>` defaults to the last component of the URL without `.git`, and must be empty or missing.
+
+## Hooks
+
+The project's post-create hooks run in the new worktree after approval, as they do for `wt switch --create`. Use `--no-verify` to skip them.
+
+## See also
+
+- [`wt switch`](@/switch.md) — Create more worktrees
+"#
+    )]
+    Clone {
+        /// Repository URL or path
+        url: String,
+
+        /// Directory to clone into
+        dir: Option<String>,
+
+        /// Use a bare repository with the default branch as a subdirectory
+        #[arg(long)]
+        bare: bool,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Skip hooks
+        #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
+        verify: bool,
+    },
+
+    /// Summarize the current worktree
+    #[command(
+        after_long_help = r#"Shows the current branch, how it compares to its upstream, whether the working tree has uncommitted changes, and which hooks are configured.
+
+## Examples
+
+Show the current worktree:
+
+```console
+wt status
+```
+
+Check for uncommitted changes from a script:
+
+```console
+wt status --format=json | jq .dirty
+```
+
+Check whether a pre-merge hook is configured:
+
+```console
+wt status --format=json | jq '.hooks["pre-merge"]'
+```
+
+## Output
+
+- **Branch** — Current branch and worktree path, or the commit when HEAD is detached
+- **Upstream** — Tracking branch with commits ahead (`↑`) and behind (`↓`); `(none)` without one
+- **Tree** — `clean` or `uncommitted changes` (including untracked files)
+- **Hooks** — Hook types with commands in user and project config
+- **Unset** — Hook types with no commands in either config; omitted when every hook is configured
+
+## JSON output
+
+`--format=json` prints an object with `path`, `branch` (`null` when detached), `detached`, `head`, `upstream` (`name`, `ahead`, `behind`, or `null`), `dirty`, `hooks` (each hook type mapped to whether it has commands in either config), and `hook_sources` (`user` and `project` lists).
+
+## See also
+
+- [`wt list`](@/list.md) — Status of every worktree
+- [`wt hook show`](@/hook.md) — Configured hook commands
+"#
+    )]
+    Status {
+        /// Output format (table, json)
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
+    },
+
+    /// List commits unique to the current branch
+    #[command(
+        after_long_help = r#"Shows the commits on the current branch since it diverged from the default branch — the same range as `git log <default-branch>..HEAD`.
+
+## Examples
+
+Show what the current branch adds:
+
+```console
+wt log
+```
+
+Show one line per commit:
+
+```console
+wt log --oneline
+```
+
+Count commits from a script:
+
+```console
+wt log --format=json | jq length
+```
+
+## Output
+
+Each commit shows its short SHA, subject, author, and age. `--oneline` drops the author and age. On the default branch there are no unique commits, so nothing is printed.
+
+## JSON output
+
+`--format=json` prints an array of objects with `sha`, `subject`, `author`, and `date` (ISO 8601), newest first.
+
+## See also
+
+- [`wt list`](@/list.md) — Commits ahead of the default branch for every worktree
+- [`wt step squash`](@/step.md) — Squash these commits into one
+"#
+    )]
+    Log {
+        /// Show only the SHA and subject
+        #[arg(long)]
+        oneline: bool,
+
+        /// Output format (table, json)
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
+    },
+
+    /// Diagnose setup problems
+    #[command(
+        after_long_help = r#"Checks that Worktrunk is set up to work from this shell and directory.
+
+## Examples
+
+```console
+wt doctor
+```
+
+## Checks
+
+- **Shell integration** — A shell config file contains the `wt config shell init` line, and it's active in the current shell
+- **PATH** — `wt` is on `PATH`, since the shell wrapper runs it by name
+- **Commit generation** — The configured LLM command exists and is executable (a warning when no command is configured)
+- **Repository** — The current directory is inside a git worktree
+
+Each check passes, warns, or fails. `wt doctor` exits non-zero if any check fails.
+
+## See also
+
+- [`wt config show`](@/config.md) — Full configuration and diagnostics
+"#
+    )]
+    Doctor,
+
+    /// Remove worktrees whose upstream branch was deleted
+    #[command(
+        after_long_help = r#"Cleans up after branches are merged and deleted on the remote.
+
+First cleans up metadata for worktrees whose directories no longer exist (`git worktree prune`). Then lists worktrees whose branch tracks an upstream that has been deleted, and removes them after confirmation.
+
+## Examples
+
+Preview what would be removed:
+
+```console
+wt prune --dry-run
+```
+
+Remove without prompting:
+
+```console
+git fetch --prune && wt prune --yes
+```
+
+## Candidates
+
+A worktree is a candidate when `git branch -vv` shows its upstream as `[gone]`. Git only marks an upstream gone once the remote-tracking branch is pruned, so run `git fetch --prune` first (or set `fetch.prune`).
+
+The current worktree, the main worktree, and locked worktrees are never removed. Removal works like [`wt remove`](@/remove.md): branches are deleted only if merged, and pre-remove hooks run.
+"#
+    )]
+    Prune {
+        /// Show what would be removed without removing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip confirmation and approval prompts
+        #[arg(short, long)]
+        yes: bool,
     },
 
     /// Merge worktree into target branch
@@ -770,6 +1320,14 @@ wt merge --no-commit
 
 Use `--no-commit` to skip committing uncommitted changes and squashing; rebase still runs by default and can rewrite commits unless `--no-rebase` is passed. Useful after preparing commits manually with `wt step`. Requires a clean working tree.
 
+Use `--autostash` to leave uncommitted changes out of the merge: they're stashed before the pipeline runs, so pre-merge hooks check only the committed state, and restored afterward — also when a step fails. The worktree is kept so the changes have somewhere to return. If restoring them would conflict, or a rebase is left unfinished, they stay in the stash and the entry (e.g. `stash@{0}`) is reported.
+
+Use `--no-verify` to skip pre-commit and pre-merge checks, along with the hooks that run during worktree removal; a warning notes when pre-merge checks were skipped. Post-merge hooks still run.
+
+## Aborting
+
+If a step fails before the target is updated — a rebase conflict, a failing pre-merge hook — run `wt merge --abort` to undo it. It aborts any rebase or merge left in progress and moves the branch back to where it was when `wt merge` started. Changes that `wt merge` committed or squashed come back as uncommitted changes. If the branch has moved since `wt merge` stopped (for example, new commits), it refuses rather than discard them. With no unfinished merge, it reports that there is nothing to abort.
+
 ## Local CI
 
 For personal projects, pre-merge hooks open up the possibility of a workflow with much faster iteration — an order of magnitude more small changes instead of fewer large ones.
@@ -834,7 +1392,7 @@ lint = "cargo clippy"
         #[arg(long, overrides_with = "no_verify", hide = true)]
         verify: bool,
 
-        /// Skip hooks
+        /// Skip pre-merge checks (post-merge still runs)
         #[arg(long = "no-verify", overrides_with = "verify")]
         no_verify: bool,
 
@@ -845,6 +1403,27 @@ lint = "cargo clippy"
         /// What to stage before committing [default: all]
         #[arg(long)]
         stage: Option<crate::commands::commit::StageMode>,
+
+        /// Stash uncommitted changes instead of committing them
+        ///
+        /// Merges only the branch's commits, then restores the changes and
+        /// keeps the worktree.
+        #[arg(long, conflicts_with = "stage")]
+        autostash: bool,
+
+        /// Undo an unfinished merge
+        ///
+        /// Aborts a rebase or merge left in progress and restores the branch to
+        /// where it was before `wt merge` started.
+        #[arg(
+            long,
+            conflicts_with_all = [
+                "target", "squash", "no_squash", "commit", "no_commit", "rebase",
+                "no_rebase", "remove", "no_remove", "verify", "no_verify", "yes", "stage",
+                "autostash",
+            ]
+        )]
+        abort: bool,
     },
     /// Interactive worktree selector
     ///
@@ -932,6 +1511,7 @@ wt step push
 ## Operations
 
 - `commit` — Stage and commit with [LLM-generated message](@/llm-commits.md)
+- `amend` — Reword the last commit with an [LLM-generated message](@/llm-commits.md); refuses merge commits and pushed commits without `--force`
 - `squash` — Squash all branch commits into one with [LLM-generated message](@/llm-commits.md)
 - `rebase` — Rebase onto target branch
 - `push` — Fast-forward target to current branch
@@ -974,6 +1554,22 @@ wt step commit --show-prompt | less
 wt step commit --show-prompt | llm -m gpt-5-nano
 ```
 
+### `--preview`
+
+Generate the commit message and print it to stdout without committing. Changes are staged as for a real commit and the configured LLM runs (or the fallback message is used), but hooks are skipped and nothing is committed. Useful for tuning templates:
+
+```bash
+wt step commit --preview --template-file ./prompt.txt
+```
+
+### `--since`
+
+Generate the commit message from the changes since a ref (`git diff <REF>..`) instead of the staged diff. The staged changes are still what gets committed; branch and recent commits in the prompt come from the current branch:
+
+```bash
+wt step commit --show-prompt --since main
+```
+
 ## See also
 
 - [`wt merge`](@/merge.md) — Runs commit → squash → rebase → hooks → push → cleanup automatically
@@ -1000,6 +1596,7 @@ Hooks run automatically during `wt switch`, `wt merge`, & `wt remove`. `wt hook
 
 | Hook | When | Blocking | Fail-fast |
 |------|------|----------|-----------|
+| `pre-create` | Before worktree created | Yes | Yes |
 | `post-create` | After worktree created | Yes | No |
 | `post-start` | After worktree created | No (background) | No |
 | `post-switch` | After every switch | No (background) | No |
@@ -1011,6 +1608,17 @@ Hooks run automatically during `wt switch`, `wt merge`, & `wt remove`. `wt hook
 **Blocking**: Command waits for hook to complete before continuing.
 **Fail-fast**: First failure aborts the operation.
 
+### pre-create
+
+Runs before `git worktree add`, from the repository root. A failing command aborts the switch and no worktree is created. `{{ worktree_path }}` and `{{ worktree_name }}` refer to the worktree about to be created.
+
+**Use cases**: Enforcing branch naming conventions, checking prerequisites.
+
+```toml
+[pre-create]
+naming = "echo '{{ branch }}' | grep -Eq '^(feat|fix|chore)/'"
+```
+
 ### post-create
 
 Runs after worktree creation, **blocks until complete**. The worktree switch doesn't finish until these commands succeed.
@@ -1038,6 +1646,8 @@ server = "npm run dev"
 
 Output logged to `.git/wt-logs/{branch}-{source}-post-start-{name}.log` (source is `user` or `project`).
 
+To wait for the commands instead, run `wt hook post-start --parallel`: they run concurrently in the foreground, with each output line prefixed by `[source:name]`. At most `post-start-parallelism` commands run at once (a top-level key in the project config; defaults to the number of CPUs).
+
 ### post-switch
 
 Runs after **every** switch operation, **in background**. Triggers on all switch results: creating new worktrees, switching to existing ones, or switching to the current worktree.
@@ -1086,7 +1696,7 @@ post-merge = "cargo install --path ."
 
 ### pre-remove
 
-Runs before worktree removal during `wt remove`, **fail-fast**. All commands must exit 0 for removal to proceed.
+Runs before worktree removal during `wt remove`, **fail-fast**. All commands must exit 0 for removal to proceed; with `wt remove --force`, failures are shown as warnings and removal continues.
 
 **Use cases**: Cleanup tasks, saving state, notifying external systems.
 
@@ -1104,6 +1714,16 @@ cleanup = "rm -rf /tmp/cache/{{ branch }}"
 
 See [`wt merge`](@/merge.md#pipeline) for the complete pipeline.
 
+### Logging foreground hooks
+
+Background hooks always log to `.git/wt-logs/`. To also keep the output of hooks that run in the foreground, set `hook-log-dir` in the project config:
+
+```toml
+hook-log-dir = ".git/wt-logs/hooks"
+```
+
+Each command's output still streams to the terminal and is copied to `{branch}-{source}-{hook}-{name}-{timestamp}.log` (relative paths resolve from the repository root). When a command fails, the log path is shown as a hint. Logged commands don't run attached to a TTY, so some tools drop color.
+
 ## Configuration
 
 Hooks are defined in `.config/wt.toml`. They can be a single command or multiple named commands:
@@ -1179,6 +1799,17 @@ print(f"Setting up {ctx['repo']} on branch {ctx['branch']}")
 
 The JSON includes all template variables plus `hook_type` and `hook_name`.
 
+### Environment variables
+
+Scripts can also read the most common values from the environment, without templating:
+
+| Variable | Template equivalent |
+|----------|---------------------|
+| `WT_BRANCH` | `{{ branch }}` |
+| `WT_WORKTREE` | `{{ worktree_path }}` |
+| `WT_REPO_ROOT` | `{{ repo_path }}` |
+| `WT_TARGET_BRANCH` | `{{ target }}` (only set when the hook has a target) |
+
 ## Designing effective hooks
 
 ### post-create vs post-start
@@ -1346,7 +1977,7 @@ wt hook post-create --var branch=feature/test  # Override template variable
 
 The `user:` and `project:` prefixes filter by source. Use `user:` or `project:` alone to run all hooks from that source, or `user:name` / `project:name` to run a specific hook.
 
-The `--var KEY=VALUE` flag overrides built-in template variables — useful for testing hooks with different contexts without switching to that context.
+The `--var KEY=VALUE` flag overrides built-in template variables — useful for testing hooks with different contexts without switching to that context. Keys that aren't built-in variables are added to the template context as-is.
 
 ## Language-specific tips
 
@@ -1477,8 +2108,14 @@ worktree-path = "../worktrees/{{ repo }}/{{ branch | sanitize }}"
 # Nested bare repo (git clone --bare <url> project/.git)
 # Creates: ~/code/project/feature-login (sibling to .git)
 worktree-path = "../{{ branch | sanitize }}"
+
+# Under the home directory
+# Creates: ~/worktrees/myproject/feature-login
+worktree-path = "~/worktrees/{{ repo }}/{{ branch | sanitize }}"
 ```
 
+`~` and environment variables (`$VAR`, `${VAR}`) expand in `worktree-path` and other config paths (`template-file`, `hook-log-dir`). An unset variable expands to nothing, with a warning in `-v` output.
+
 ### Command settings
 
 Set persistent flag values for commands. These apply unless explicitly overridden on the command line.
@@ -1493,11 +2130,12 @@ branches = true  # --branches
 remotes = true   # --remotes
 ```
 
-**`wt step commit` and `wt merge` staging:**
+**`wt step commit` and `wt merge` staging and signing:**
 
 ```toml
 [commit]
 stage = "all"    # "all" (default), "tracked", or "none"
+sign = true      # Sign commits wt creates (default: git's commit.gpgsign)
 ```
 
 **`wt merge`:**
@@ -1568,6 +2206,22 @@ lint = "npm run lint"
 
 See [`wt hook`](@/hook.md) for complete documentation on hook types, execution order, template variables, and [JSON context](@/hook.md#json-context).
 
+Worktrunk looks for `.config/wt.toml` in the current directory and each parent up to the worktree root, so a subdirectory can carry its own config. If none is found, it falls back to the main worktree's config (for bare repositories, the repository directory).
+
+Projects that don't want a `.config/` directory can use `.wt.toml` or `wt.toml` instead. When a directory has more than one, `.config/wt.toml` wins, then `.wt.toml`, then `wt.toml`; the others are ignored with a warning.
+
+### Shared config
+
+`include` merges other config files underneath this one, so subprojects of a monorepo can share hook definitions:
+
+```toml
+include = ["../common-wt.toml"]
+
+pre-merge = "cargo test"
+```
+
+Paths are relative to the including file, with `~` and environment variables expanded. Included files are merged in order, then the including file on top: later values win, and tables such as `[post-start]` or `[env]` merge key by key. Included files can include others; an include that leads back to a file already being loaded is an error.
+
 ### Dev server URL
 
 The `[list]` section adds a URL column to `wt list`:
@@ -1590,6 +2244,56 @@ platform = "github"  # or "gitlab"
 
 By default, the platform is detected from the remote URL. Use this when URL detection fails (e.g., `git.mycompany.com` instead of `github.mycompany.com`).
 
+### Environment variables
+
+The `[env]` section exports variables into the shell after `wt switch`:
+
+```toml
+[env]
+VIRTUAL_ENV = "{{ worktree_path }}/.venv"
+DATABASE_URL = "postgres://localhost/{{ branch | sanitize }}"
+```
+
+Values support the same template variables as hooks. Each entry is approved alongside the switch hooks, shown as `KEY=template`. Exporting requires shell integration, and is skipped along with hooks (`--no-verify`, or declined approval).
+
+Variables the shell uses to find or run programs can't be set: `PATH`, `CDPATH`, `IFS`, `GLOBIGNORE`, `PROMPT_COMMAND`, prompt strings (`PS1`–`PS4`, zsh's `PROMPT`, `RPROMPT` and `RPS1`), `LD_*`, `DYLD_*`, `BASH_ENV`, `ENV`, `SHELLOPTS`, `BASHOPTS`, `ZDOTDIR`, `FPATH`, zsh's `precmd_functions`, `preexec_functions` and `chpwd_functions`, and fish's `fish_function_path`, `fish_complete_path` and `fish_user_paths`.
+
+### Editor command
+
+`open-editor-command` runs in the shell after `wt switch`, from the new worktree:
+
+```toml
+open-editor-command = "code ."
+```
+
+It supports the same template variables as hooks and is approved alongside them. Like `[env]`, it requires shell integration and is skipped along with hooks.
+
+### Generated files
+
+The `[post-create-files]` section writes files into each new worktree before `post-create` hooks run:
+
+```toml
+[post-create-files]
+".env.local" = "DATABASE_URL=postgres://localhost/{{ branch | sanitize }}"
+".vscode/settings.json" = { template = "{}", overwrite = true }
+```
+
+Paths are relative to the worktree, and contents support the same template variables as hooks. Each file is approved alongside the `post-create` hooks, and skipped along with them. A file the worktree already has (for example, one that's committed) is left alone with a warning, unless its entry sets `overwrite = true`. Paths that lead outside the worktree — through `..`, `.git`, or a symlink — are refused.
+
+### Commit message generation
+
+The `[commit-generation]` section overrides the user's settings field by field — `command`, `args`, `model`, and the template keys. Unset fields are inherited, so a project can share a prompt while each developer keeps their own LLM command:
+
+```toml
+[commit-generation]
+template = """
+Write a conventional commit message for:
+{{ git_diff }}
+"""
+```
+
+A project-supplied `command` or `args` runs arbitrary code, so it needs approval like hook commands. If declined, the message is generated without the LLM.
+
 ## Shell integration
 
 Worktrunk needs shell integration to change directories when switching worktrees. Install with:
@@ -1609,6 +2313,21 @@ eval "$(wt config shell init zsh)"
 
 # For fish: add to ~/.config/fish/config.fish
 wt config shell init fish | source
+
+# For elvish: add to ~/.config/elvish/rc.elv
+eval (wt config shell init elvish | slurp)
+
+# For xonsh: add to ~/.config/xonsh/rc.xsh
+execx($(wt config shell init xonsh))
+
+# For tcsh: add to ~/.tcshrc (csh: ~/.cshrc, with `init csh`)
+eval "`wt config shell init tcsh`"
+```
+
+Nushell can't evaluate generated code at startup, so save the script to its autoload directory once (and again after upgrading):
+
+```nu
+wt config shell init nu | save --force ($nu.data-dir | path join vendor autoload wt.nu)
 ```
 
 Without shell integration, `wt switch` prints the target directory but cannot `cd` into it.
@@ -1627,6 +2346,15 @@ Or via environment variable:
 export WORKTRUNK_SKIP_SHELL_INTEGRATION_PROMPT=true
 ```
 
+### Command name per shell
+
+`wt config shell install <shell> --cmd <name>` records the name for that shell, so reinstalling without `--cmd` regenerates the same integration:
+
+```toml
+[shell-cmd]
+zsh = "git-wt"
+```
+
 ## Environment variables
 
 All user config options can be overridden with environment variables using the `WORKTRUNK_` prefix.
@@ -1668,17 +2396,26 @@ WORKTRUNK_COMMIT_GENERATION__ARGS="test: automated commit" \
 | Variable | Purpose |
 |----------|---------|
 | `WORKTRUNK_BIN` | Override binary path for shell wrappers (useful for testing dev builds) |
-| `WORKTRUNK_CONFIG_PATH` | Override user config file location |
+| `WORKTRUNK_CONFIG` | Load user config from this file, which must exist |
+| `WORKTRUNK_CONFIG_PATH` | Override user config file location (defaults apply if the file is missing) |
 | `WORKTRUNK_DIRECTIVE_FILE` | Internal: set by shell wrappers to enable directory changes |
 | `WORKTRUNK_SHELL` | Internal: set by shell wrappers to indicate shell type (e.g., `powershell`) |
 | `WORKTRUNK_MAX_CONCURRENT_COMMANDS` | Max parallel git commands (default: 32). Lower if hitting file descriptor limits. |
 | `NO_COLOR` | Disable colored output ([standard](https://no-color.org/)) |
 | `CLICOLOR_FORCE` | Force colored output even when not a TTY |
 
+The user config file is the first of: `--config`, `WORKTRUNK_CONFIG`, `WORKTRUNK_CONFIG_PATH`, then the default location.
+
 <!-- subdoc: create -->
 
 <!-- subdoc: show -->
 
+<!-- subdoc: print -->
+
+<!-- subdoc: get -->
+
+<!-- subdoc: set -->
+
 <!-- subdoc: state -->
 "#
     )]
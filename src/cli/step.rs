@@ -19,6 +19,68 @@ pub enum StepCommand {
         #[arg(long)]
         stage: Option<crate::commands::commit::StageMode>,
 
+        /// Model for commit message generation
+        ///
+        /// Overrides `commit-generation.model`. Replaces `{model}` in
+        /// `commit-generation.args`, or is appended after `commit-generation.model-arg`.
+        #[arg(long, value_name = "NAME")]
+        model: Option<String>,
+
+        /// Prompt template for this commit
+        ///
+        /// Overrides `commit-generation.template` and `template-file`.
+        #[arg(
+            long,
+            value_name = "STR",
+            value_parser = clap::builder::NonEmptyStringValueParser::new()
+        )]
+        template: Option<String>,
+
+        /// Prompt template file for this commit
+        ///
+        /// Overrides `commit-generation.template` and `template-file`.
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with = "template",
+            value_parser = clap::builder::NonEmptyStringValueParser::new()
+        )]
+        template_file: Option<String>,
+
+        /// Show prompt without running LLM
+        ///
+        /// Outputs the rendered prompt to stdout for debugging or manual piping.
+        #[arg(long)]
+        show_prompt: bool,
+
+        /// Show generated commit message without committing
+        ///
+        /// Stages changes and runs the LLM as a real commit would, then prints
+        /// the message to stdout. Skips hooks; changes stay staged.
+        #[arg(long, conflicts_with = "show_prompt")]
+        preview: bool,
+
+        /// Describe changes since a commit
+        ///
+        /// The LLM sees the diff from this ref to `HEAD` (`git diff <REF>..`)
+        /// instead of the staged changes. Branch and recent commits still come
+        /// from the current branch.
+        #[arg(long, value_name = "REF", add = crate::completion::branch_value_completer())]
+        since: Option<String>,
+    },
+
+    /// Reword the last commit with an LLM commit message
+    ///
+    /// Generates a new message for `HEAD` from the changes it introduced, using the same prompt template as `wt step commit`, and amends the commit with it. Staged changes are left out of the commit.
+    Amend {
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Amend merge commits and commits already pushed to the upstream
+        #[arg(long)]
+        force: bool,
+
         /// Show prompt without running LLM
         ///
         /// Outputs the rendered prompt to stdout for debugging or manual piping.
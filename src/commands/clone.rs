@@ -0,0 +1,151 @@
+//! Clone a repository ready for worktree use.
+//!
+//! The default is a regular clone, where the default branch is the main
+//! worktree and new worktrees go wherever `worktree-path` puts them. `--bare`
+//! sets up the nested bare layout instead: the repository at `<dir>/.git` and
+//! the default branch checked out at `<dir>/<branch>`.
+
+use std::path::{Path, PathBuf};
+
+use color_print::cformat;
+use worktrunk::config::{WorktrunkConfig, sanitize_branch_name};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{info_message, progress_message};
+
+use super::command_approval::approve_create_hooks;
+use super::command_executor::CommandContext;
+use crate::output;
+
+/// Handle `wt clone`.
+///
+/// Runs the project's post-create hooks in the initial worktree unless
+/// `verify` is false, then changes directory into it.
+pub fn handle_clone(
+    url: &str,
+    dir: Option<&str>,
+    bare: bool,
+    yes: bool,
+    verify: bool,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<()> {
+    let cwd = Repository::current();
+    let dir = match dir {
+        Some(dir) => dir.to_string(),
+        None => directory_from_url(url).ok_or_else(|| GitError::Other {
+            message: cformat!("Cannot infer a directory from <bold>{url}</>; pass one"),
+        })?,
+    };
+    // Absolute, since git runs from inside the clone for the bare layout
+    let target = dunce::canonicalize(cwd.base_path())?.join(&dir);
+    let existed = target.exists();
+    if existed && target.read_dir()?.next().is_some() {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Cannot clone into <bold>{}</>: directory is not empty",
+                format_path_for_display(&target)
+            ),
+        }
+        .into());
+    }
+
+    output::print(progress_message(cformat!("Cloning <bold>{url}</>...")))?;
+    let worktree_path = if bare {
+        // Don't leave a half-set-up layout behind (git clone cleans up after itself)
+        clone_bare(&cwd, url, &target).inspect_err(|_| {
+            if !existed {
+                let _ = std::fs::remove_dir_all(&target);
+            }
+        })?
+    } else {
+        let target_str = target.to_string_lossy();
+        cwd.run_command(&["clone", "--quiet", "--", url, &target_str])?;
+        target
+    };
+
+    let repo = Repository::at(&worktree_path);
+    let branch = repo.current_branch()?.unwrap_or("HEAD").to_string();
+
+    if verify {
+        let ctx = CommandContext::new(
+            &repo,
+            config,
+            Some(&branch),
+            &worktree_path,
+            &worktree_path,
+            yes,
+        );
+        if approve_create_hooks(&ctx)? {
+            ctx.execute_post_create_commands(&[])?;
+        } else {
+            output::print(info_message(
+                "Commands declined, skipping post-create hooks",
+            ))?;
+        }
+    }
+
+    output::handle_clone_output(&worktree_path, &branch)
+}
+
+/// Bare-clone into `<target>/.git` and check out the default branch beside it.
+///
+/// Returns the path of the new worktree.
+fn clone_bare(cwd: &Repository, url: &str, target: &Path) -> anyhow::Result<PathBuf> {
+    let git_dir = target.join(".git");
+    let git_dir_str = git_dir.to_string_lossy();
+    cwd.run_command(&["clone", "--bare", "--quiet", "--", url, &git_dir_str])?;
+
+    // Bare clones map remote branches straight onto local ones and fetch no
+    // remote-tracking refs; restore the usual refspec so upstreams work.
+    let repo = Repository::at(&git_dir);
+    repo.run_command(&[
+        "config",
+        "remote.origin.fetch",
+        "+refs/heads/*:refs/remotes/origin/*",
+    ])?;
+    repo.run_command(&["fetch", "--quiet", "origin"])?;
+
+    let branch = repo
+        .run_command(&["symbolic-ref", "--short", "HEAD"])?
+        .trim()
+        .to_string();
+    let worktree_path = target.join(sanitize_branch_name(&branch));
+    let worktree_str = worktree_path.to_string_lossy();
+    repo.run_command(&["worktree", "add", "--quiet", &worktree_str, &branch])?;
+    repo.run_command(&[
+        "branch",
+        "--set-upstream-to",
+        &format!("origin/{branch}"),
+        &branch,
+    ])?;
+    Ok(worktree_path)
+}
+
+/// Directory name `git clone` would pick: the URL's last component without `.git`
+fn directory_from_url(url: &str) -> Option<String> {
+    let trimmed = url.trim_end_matches('/');
+    let trimmed = trimmed.strip_suffix(".git").unwrap_or(trimmed);
+    let name = trimmed.rsplit(['/', ':', '\\']).next()?;
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directory_from_url() {
+        let cases = [
+            ("https://github.com/user/repo.git", Some("repo")),
+            ("https://github.com/user/repo/", Some("repo")),
+            ("git@github.com:user/repo.git", Some("repo")),
+            ("git@host:repo", Some("repo")),
+            ("/srv/git/project.git/", Some("project")),
+            ("../local", Some("local")),
+            ("", None),
+        ];
+        for (url, expected) in cases {
+            assert_eq!(directory_from_url(url).as_deref(), expected, "{url}");
+        }
+    }
+}
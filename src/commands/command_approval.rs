@@ -22,7 +22,9 @@ use super::project_config::{HookCommand, collect_commands_for_hooks};
 use crate::output;
 use anyhow::Context;
 use color_print::cformat;
-use worktrunk::config::WorktrunkConfig;
+use worktrunk::config::{
+    Command, CommitGenerationConfig, ProjectConfig, WorktrunkConfig, is_shell_control_var,
+};
 use worktrunk::git::{GitError, HookType};
 use worktrunk::styling::{
     INFO_SYMBOL, PROMPT_SYMBOL, WARNING_SYMBOL, eprint, format_bash_with_gutter, hint_message,
@@ -177,6 +179,136 @@ pub fn approve_hooks(
     approve_hooks_filtered(ctx, hook_types, None)
 }
 
+/// The project's `[post-create-files]`, as commands for the approval prompt
+///
+/// Each file is shown as a heredoc writing its template to the path, so the
+/// saved approval covers both.
+fn post_create_file_commands(project_config: &ProjectConfig) -> Vec<HookCommand> {
+    project_config
+        .post_create_files
+        .iter()
+        .flatten()
+        .map(|(path, file)| HookCommand {
+            hook_type: HookType::PostCreate,
+            command: Command::new(
+                Some("post-create-files".to_string()),
+                format!(
+                    "cat > {} <<'EOF'\n{}\nEOF",
+                    shlex::try_quote(path).unwrap_or(path.into()),
+                    file.template()
+                ),
+            ),
+        })
+        .collect()
+}
+
+/// Like `approve_hooks` for `post-create`, plus the project's `[post-create-files]`.
+///
+/// Used by `wt clone`, which creates a worktree without switching to it.
+pub fn approve_create_hooks(
+    ctx: &super::command_executor::CommandContext<'_>,
+) -> anyhow::Result<bool> {
+    let project_config = match ctx.repo.load_project_config()? {
+        Some(cfg) => cfg,
+        None => return Ok(true),
+    };
+
+    let mut commands = collect_commands_for_hooks(&project_config, &[HookType::PostCreate]);
+    commands.extend(post_create_file_commands(&project_config));
+    if commands.is_empty() {
+        return Ok(true);
+    }
+
+    let project_id = ctx.repo.project_identifier()?;
+    approve_command_batch(&commands, project_id, ctx.config, ctx.yes, false)
+}
+
+/// Like `approve_hooks`, plus the rest of what `wt switch` takes from the project:
+/// `[post-create-files]` (when `hook_types` includes `post-create`), `[env]`, and
+/// `open-editor-command`.
+///
+/// `wt switch` is the only command that exports the env or runs the editor
+/// command, so they're approved in the same prompt as the switch hooks, listed
+/// under `post-switch`. Each env entry is shown as `KEY=template`; variables
+/// that are never exported (see [`is_shell_control_var`]) aren't listed.
+pub fn approve_switch_hooks(
+    ctx: &super::command_executor::CommandContext<'_>,
+    hook_types: &[HookType],
+) -> anyhow::Result<bool> {
+    let project_config = match ctx.repo.load_project_config()? {
+        Some(cfg) => cfg,
+        None => return Ok(true),
+    };
+
+    let mut commands = collect_commands_for_hooks(&project_config, hook_types);
+    if hook_types.contains(&HookType::PostCreate) {
+        commands.extend(post_create_file_commands(&project_config));
+    }
+    for (key, template) in project_config.env.iter().flatten() {
+        if is_shell_control_var(key) {
+            continue;
+        }
+        commands.push(HookCommand {
+            hook_type: HookType::PostSwitch,
+            command: Command::new(Some("env".to_string()), format!("{key}={template}")),
+        });
+    }
+    if let Some(editor) = project_config.open_editor_command {
+        commands.push(HookCommand {
+            hook_type: HookType::PostSwitch,
+            command: Command::new(Some("open-editor-command".to_string()), editor),
+        });
+    }
+
+    if commands.is_empty() {
+        return Ok(true);
+    }
+
+    let project_id = ctx.repo.project_identifier()?;
+    approve_command_batch(&commands, project_id, ctx.config, ctx.yes, false)
+}
+
+/// Approve a commit generation command supplied by the project.
+///
+/// The project's `[commit-generation]` can replace the `command` or `args` that
+/// get run, so these are approved like hooks, listed under `pre-commit`. Returns
+/// the settings to generate with: unchanged if approved (or nothing needed
+/// approval), or with the command cleared if declined, so the message is
+/// generated without an LLM.
+pub fn approve_commit_generation(
+    ctx: &super::command_executor::CommandContext<'_>,
+) -> anyhow::Result<CommitGenerationConfig> {
+    let config = ctx.config.commit_generation.clone();
+    let overrides_command = ctx
+        .repo
+        .load_project_config()?
+        .and_then(|project| project.commit_generation)
+        .is_some_and(|overrides| overrides.overrides_command());
+    if !overrides_command || !config.is_configured() {
+        return Ok(config);
+    }
+
+    let command = HookCommand {
+        hook_type: HookType::PreCommit,
+        command: Command::new(
+            Some("commit-generation".to_string()),
+            crate::llm::llm_command_display(&config),
+        ),
+    };
+    let project_id = ctx.repo.project_identifier()?;
+    if approve_command_batch(&[command], project_id, ctx.config, ctx.yes, false)? {
+        return Ok(config);
+    }
+
+    output::print(worktrunk::styling::info_message(
+        "Commands declined, generating commit message without LLM",
+    ))?;
+    Ok(CommitGenerationConfig {
+        command: None,
+        ..config
+    })
+}
+
 /// Like `approve_hooks` but with optional name filter for targeted hook approval.
 ///
 /// When `name_filter` is provided, only commands matching that name are shown
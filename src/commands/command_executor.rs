@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::path::Path;
 use worktrunk::HookType;
@@ -5,11 +6,38 @@ use worktrunk::config::{Command, CommandConfig, WorktrunkConfig, expand_template
 use worktrunk::git::Repository;
 use worktrunk::path::to_posix_path;
 
+/// `WT_*` environment variables for a hook command, built by [`hook_env`].
+pub type HookEnv = Vec<(&'static str, String)>;
+
 #[derive(Debug)]
 pub struct PreparedCommand {
     pub name: Option<String>,
     pub expanded: String,
     pub context_json: String,
+    pub env: HookEnv,
+    /// The user's `hook-shell`, if set
+    pub shell: Option<String>,
+}
+
+impl PreparedCommand {
+    /// The command line to spawn: `expanded`, run by `hook-shell` when one is set.
+    ///
+    /// `expanded` stays the form that's shown and approved.
+    pub fn runnable(&self) -> Cow<'_, str> {
+        match &self.shell {
+            Some(shell) => Cow::Owned(format!(
+                "{} -c {}",
+                shell_escape::escape(shell.into()),
+                shell_escape::escape(self.expanded.as_str().into())
+            )),
+            None => Cow::Borrowed(&self.expanded),
+        }
+    }
+
+    /// `env` as borrowed pairs, for passing to the spawn functions.
+    pub fn env_vars(&self) -> Vec<(&str, &str)> {
+        self.env.iter().map(|(k, v)| (*k, v.as_str())).collect()
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -129,6 +157,23 @@ pub fn build_hook_context(
     map
 }
 
+/// Environment variables exposed to every hook command.
+///
+/// Mirrors the template variables, so scripts can read `$WT_BRANCH` etc.
+/// without templating. `WT_TARGET_BRANCH` is only set for hooks with a target
+/// (merge, rebase, commit).
+pub fn hook_env(context: &HashMap<String, String>) -> HookEnv {
+    [
+        ("WT_BRANCH", "branch"),
+        ("WT_WORKTREE", "worktree_path"),
+        ("WT_TARGET_BRANCH", "target"),
+        ("WT_REPO_ROOT", "repo_path"),
+    ]
+    .into_iter()
+    .filter_map(|(env_var, key)| context.get(key).map(|value| (env_var, value.clone())))
+    .collect()
+}
+
 /// Expand commands from a CommandConfig without approval
 ///
 /// This is the canonical command expansion implementation.
@@ -138,12 +183,13 @@ fn expand_commands(
     ctx: &CommandContext<'_>,
     extra_vars: &[(&str, &str)],
     hook_type: HookType,
-) -> anyhow::Result<Vec<(Command, String)>> {
+) -> anyhow::Result<Vec<(Command, String, HookEnv)>> {
     if commands.is_empty() {
         return Ok(Vec::new());
     }
 
     let base_context = build_hook_context(ctx, extra_vars);
+    let env = hook_env(&base_context);
 
     // Convert to &str references for expand_template
     let vars: HashMap<&str, &str> = base_context
@@ -174,6 +220,7 @@ fn expand_commands(
         result.push((
             Command::with_expansion(cmd.name.clone(), cmd.template.clone(), expanded_str),
             context_json,
+            env.clone(),
         ));
     }
 
@@ -203,10 +250,12 @@ pub fn prepare_commands(
 
     Ok(expanded_with_json
         .into_iter()
-        .map(|(cmd, context_json)| PreparedCommand {
+        .map(|(cmd, context_json, env)| PreparedCommand {
             name: cmd.name,
             expanded: cmd.expanded,
             context_json,
+            env,
+            shell: ctx.config.hook_shell.clone(),
         })
         .collect())
 }
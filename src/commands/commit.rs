@@ -2,7 +2,7 @@ use anyhow::Context;
 use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::CommitGenerationConfig;
-use worktrunk::git::Repository;
+use worktrunk::git::{GitError, Repository};
 use worktrunk::styling::{
     format_with_gutter, hint_message, info_message, progress_message, success_message,
 };
@@ -22,6 +22,8 @@ pub struct CommitOptions<'a> {
     pub stage_mode: StageMode,
     pub warn_about_untracked: bool,
     pub show_no_squash_note: bool,
+    /// Describe the changes since this commit instead of the staged diff
+    pub since: Option<&'a str>,
 }
 
 impl<'a> CommitOptions<'a> {
@@ -34,17 +36,59 @@ impl<'a> CommitOptions<'a> {
             stage_mode: StageMode::All,
             warn_about_untracked: true,
             show_no_squash_note: false,
+            since: None,
         }
     }
 }
 
 pub(crate) struct CommitGenerator<'a> {
     config: &'a CommitGenerationConfig,
+    /// `[commit] sign`: `Some` forces signing on or off, `None` defers to git
+    sign: Option<bool>,
+    /// Commit whose changes up to `HEAD` the message describes (`--since`)
+    since: Option<&'a str>,
 }
 
 impl<'a> CommitGenerator<'a> {
-    pub fn new(config: &'a CommitGenerationConfig) -> Self {
-        Self { config }
+    pub fn new(config: &'a CommitGenerationConfig, sign: Option<bool>) -> Self {
+        Self {
+            config,
+            sign,
+            since: None,
+        }
+    }
+
+    /// Generate messages from the changes since `since` rather than the staged diff
+    pub fn with_since(mut self, since: Option<&'a str>) -> Self {
+        self.since = since;
+        self
+    }
+
+    /// Create a commit from the index with `message`
+    ///
+    /// Passes `-S` or `--no-gpg-sign` when `[commit] sign` is set; otherwise
+    /// git's `commit.gpgsign` applies. A signing failure fails the commit with
+    /// git's error rather than falling back to an unsigned commit.
+    pub fn create_commit(&self, repo: &Repository, message: &str) -> anyhow::Result<()> {
+        self.run_commit(repo, vec!["commit", "-m", message])
+    }
+
+    /// Replace the message of `HEAD` with `message`
+    ///
+    /// `--only` keeps anything already staged out of the amended commit, so
+    /// only the message changes. Signing follows [`Self::create_commit`].
+    pub fn amend_commit(&self, repo: &Repository, message: &str) -> anyhow::Result<()> {
+        self.run_commit(repo, vec!["commit", "--amend", "--only", "-m", message])
+    }
+
+    fn run_commit(&self, repo: &Repository, mut args: Vec<&str>) -> anyhow::Result<()> {
+        match self.sign {
+            Some(true) => args.push("-S"),
+            Some(false) => args.push("--no-gpg-sign"),
+            None => {}
+        }
+        repo.run_command(&args)?;
+        Ok(())
     }
 
     pub fn format_message_for_display(&self, message: &str) -> String {
@@ -84,7 +128,7 @@ impl<'a> CommitGenerator<'a> {
 
         // Fail early if nothing is staged (avoids confusing LLM prompt with empty diff)
         if !repo.has_staged_changes()? {
-            anyhow::bail!("Nothing to commit");
+            return Err(GitError::NoStagedChanges.into());
         }
 
         let stats_parts = repo.diff_stats_summary(&["diff", "--staged", "--shortstat"]);
@@ -120,12 +164,12 @@ impl<'a> CommitGenerator<'a> {
         crate::output::print(progress_message(full_progress_msg))?;
 
         self.emit_hint_if_needed()?;
-        let commit_message = crate::llm::generate_commit_message(self.config)?;
+        let commit_message = crate::llm::generate_commit_message(self.config, self.since)?;
 
         let formatted_message = self.format_message_for_display(&commit_message);
         crate::output::print(format_with_gutter(&formatted_message, None))?;
 
-        repo.run_command(&["commit", "-m", &commit_message])
+        self.create_commit(&repo, &commit_message)
             .context("Failed to commit")?;
 
         let commit_hash = repo
@@ -182,11 +226,47 @@ impl CommitOptions<'_> {
             .map_err(worktrunk::git::add_hook_skip_hint)?;
         }
 
+        self.stage_changes()?;
+
+        let generation = super::command_approval::approve_commit_generation(self.ctx)?;
+        CommitGenerator::new(
+            &generation,
+            self.ctx.config.commit.as_ref().and_then(|c| c.sign),
+        )
+        .with_since(self.since)
+        .commit_staged_changes(self.show_no_squash_note, self.stage_mode)
+    }
+
+    /// Print the message [`Self::commit`] would generate, without committing
+    ///
+    /// Stages changes the same way so the message describes the same diff, but
+    /// skips pre-commit hooks and leaves the staged changes uncommitted.
+    pub fn preview(self) -> anyhow::Result<()> {
+        self.stage_changes()?;
+
+        if !self.ctx.repo.has_staged_changes()? {
+            return Err(GitError::NoStagedChanges.into());
+        }
+
+        let generation = super::command_approval::approve_commit_generation(self.ctx)?;
+        let generator = CommitGenerator::new(&generation, None).with_since(self.since);
+        if generation.is_configured() {
+            crate::output::print(progress_message("Generating commit message..."))?;
+        }
+        generator.emit_hint_if_needed()?;
+
+        let commit_message = crate::llm::generate_commit_message(&generation, self.since)?;
+        crate::output::stdout(commit_message)?;
+
+        Ok(())
+    }
+
+    /// Stage changes according to `stage_mode`
+    fn stage_changes(&self) -> anyhow::Result<()> {
         if self.warn_about_untracked && self.stage_mode == StageMode::All {
             self.ctx.repo.warn_if_auto_staging_untracked()?;
         }
 
-        // Stage changes based on mode
         match self.stage_mode {
             StageMode::All => {
                 // Stage everything: tracked modifications + untracked files
@@ -207,8 +287,7 @@ impl CommitOptions<'_> {
             }
         }
 
-        CommitGenerator::new(&self.ctx.config.commit_generation)
-            .commit_staged_changes(self.show_no_squash_note, self.stage_mode)
+        Ok(())
     }
 }
 
@@ -220,7 +299,7 @@ mod tests {
     #[test]
     fn test_format_message_for_display_single_line() {
         let config = CommitGenerationConfig::default();
-        let generator = CommitGenerator::new(&config);
+        let generator = CommitGenerator::new(&config, None);
         let result = generator.format_message_for_display("Simple commit message");
         // Should contain the message text with styling
         assert!(result.contains("Simple commit message"));
@@ -231,7 +310,7 @@ mod tests {
     #[test]
     fn test_format_message_for_display_multiline() {
         let config = CommitGenerationConfig::default();
-        let generator = CommitGenerator::new(&config);
+        let generator = CommitGenerator::new(&config, None);
         let result = generator.format_message_for_display("First line\nSecond line\nThird line");
         assert!(result.contains("First line"));
         assert!(result.contains("Second line"));
@@ -241,7 +320,7 @@ mod tests {
     #[test]
     fn test_format_message_for_display_empty() {
         let config = CommitGenerationConfig::default();
-        let generator = CommitGenerator::new(&config);
+        let generator = CommitGenerator::new(&config, None);
         let result = generator.format_message_for_display("");
         assert_eq!(result, "");
     }
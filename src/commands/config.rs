@@ -1,8 +1,10 @@
 use anyhow::Context;
+use clap::ValueEnum;
 use color_print::cformat;
 use etcetera::base_strategy::{BaseStrategy, choose_base_strategy};
 use std::fmt::Write as _;
 use std::path::PathBuf;
+use worktrunk::HookType;
 use worktrunk::config::{
     ProjectConfig, WorktrunkConfig, find_unknown_project_keys, find_unknown_user_keys,
 };
@@ -15,7 +17,7 @@ use worktrunk::styling::{
 };
 use worktrunk::utils::get_now;
 
-use super::configure_shell::{ConfigAction, scan_shell_configs};
+use super::configure_shell::{ConfigAction, ShellCmds, scan_shell_configs};
 use super::list::ci_status::CachedCiStatus;
 use crate::cli::version_str;
 use crate::display::format_relative_time_short;
@@ -155,6 +157,264 @@ pub fn handle_config_show(full: bool) -> anyhow::Result<()> {
     Ok(())
 }
 
+pub fn handle_config_print(format: crate::cli::ConfigPrintFormat) -> anyhow::Result<()> {
+    let user_config = WorktrunkConfig::load().context("Failed to load config")?;
+
+    // Outside a repository only the user config applies
+    let repo = Repository::current();
+    let project_config = if repo.worktree_root().is_ok() {
+        ProjectConfig::load(&repo, false).context("Failed to load project config")?
+    } else {
+        None
+    };
+
+    let effective = effective_config(&user_config, project_config.as_ref())?;
+    let rendered = match format {
+        crate::cli::ConfigPrintFormat::Toml => toml::to_string_pretty(&effective)?,
+        crate::cli::ConfigPrintFormat::Json => serde_json::to_string_pretty(&effective)?,
+    };
+    output::stdout(rendered.trim_end())?;
+
+    Ok(())
+}
+
+/// Merge user and project config into one table, keyed by setting then source
+///
+/// Settings map each source (`default`, `global`, `project`) to its value and are
+/// omitted when unset. Every hook type appears under `hooks`, empty when unset.
+fn effective_config(
+    user_config: &WorktrunkConfig,
+    project_config: Option<&ProjectConfig>,
+) -> anyhow::Result<toml::Table> {
+    let mut settings = toml::Table::new();
+    let mut hooks: toml::Table = HookType::value_variants()
+        .iter()
+        .map(|hook_type| (hook_type.to_string(), toml::Table::new().into()))
+        .collect();
+
+    let mut add_source = |source: &str, table: toml::Table| {
+        for (key, value) in table {
+            let target = if hooks.contains_key(&key) {
+                &mut hooks
+            } else if is_unset(&value) {
+                continue;
+            } else {
+                &mut settings
+            };
+            if let toml::Value::Table(sources) = target
+                .entry(key)
+                .or_insert_with(|| toml::Table::new().into())
+            {
+                sources.insert(source.to_string(), value);
+            }
+        }
+    };
+
+    if !user_config.has_custom_worktree_path() {
+        add_source(
+            "default",
+            toml::Table::from_iter([(
+                "worktree-path".to_string(),
+                user_config.worktree_path().into(),
+            )]),
+        );
+    }
+
+    let mut user_table = toml::Table::try_from(user_config)?;
+    // Per-project approvals are state, not settings
+    user_table.remove("projects");
+    add_source("global", user_table);
+
+    if let Some(project_config) = project_config {
+        add_source("project", toml::Table::try_from(project_config)?);
+    }
+
+    settings.insert("hooks".to_string(), hooks.into());
+    Ok(settings)
+}
+
+/// Whether a serialized setting carries no values (e.g., `commit-generation` with
+/// only the empty `command` and `args` that config loading defaults to)
+fn is_unset(value: &toml::Value) -> bool {
+    match value {
+        toml::Value::String(s) => s.is_empty(),
+        toml::Value::Array(items) => items.is_empty(),
+        toml::Value::Table(table) => table.values().all(is_unset),
+        _ => false,
+    }
+}
+
+/// Sections of the user config whose keys are struct fields (rather than
+/// user-chosen names like hook commands or `shell-cmd` shells)
+const TYPED_SECTIONS: &[&str] = &["commit-generation", "list", "commit", "merge", "select"];
+
+/// Split a dotted config key into TOML key segments
+///
+/// Accepts the snake_case spelling of settings (`commit_generation.command`) by
+/// converting field names to kebab-case. User-chosen names, such as named hook
+/// commands, are kept as written.
+fn config_key_segments(key: &str) -> anyhow::Result<Vec<String>> {
+    let mut segments: Vec<String> = key.split('.').map(str::to_string).collect();
+    if segments.iter().any(|s| s.is_empty()) {
+        anyhow::bail!("Invalid config key: {key}");
+    }
+    segments[0] = segments[0].replace('_', "-");
+    if TYPED_SECTIONS.contains(&segments[0].as_str())
+        && let Some(field) = segments.get_mut(1)
+    {
+        *field = field.replace('_', "-");
+    }
+    Ok(segments)
+}
+
+/// Look up a dotted key in user config TOML content
+fn get_config_value(contents: &str, segments: &[String]) -> anyhow::Result<Option<toml::Value>> {
+    let table: toml::Table = toml::from_str(contents).context("Failed to parse config file")?;
+    let mut value = toml::Value::Table(table);
+    for segment in segments {
+        let toml::Value::Table(mut table) = value else {
+            return Ok(None);
+        };
+        match table.remove(segment) {
+            Some(next) => value = next,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(value))
+}
+
+/// Set a dotted key in user config TOML content, preserving everything else
+///
+/// The value is parsed as a TOML literal (`true`, `42`, `["-s"]`) when that
+/// matches the config schema, and otherwise stored as a string. Returns the
+/// updated content and the value that was written.
+fn set_config_value(
+    contents: &str,
+    segments: &[String],
+    raw: &str,
+) -> anyhow::Result<(String, toml::Value)> {
+    let doc: toml_edit::DocumentMut = contents.parse().context("Failed to parse config file")?;
+
+    let literal = raw.parse::<toml_edit::Value>().ok();
+    let candidates = literal
+        .into_iter()
+        .chain(std::iter::once(toml_edit::Value::from(raw)));
+
+    let mut last_error = None;
+    for mut candidate in candidates {
+        candidate.decor_mut().clear();
+        let mut doc = doc.clone();
+        insert_config_value(&mut doc, segments, candidate)?;
+        let updated = doc.to_string();
+
+        match toml::from_str::<WorktrunkConfig>(&updated) {
+            Ok(_) if find_unknown_user_keys(&updated).contains(&segments[0]) => {
+                anyhow::bail!("Unknown config key: {}", segments[0]);
+            }
+            Ok(_) => {
+                let value = get_config_value(&updated, segments)?
+                    .context("Config value missing after update")?;
+                return Ok((updated, value));
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    let error = last_error
+        .map(|e| e.message().to_string())
+        .unwrap_or_default();
+    anyhow::bail!("Invalid value for {}: {error}", segments.join("."))
+}
+
+/// Insert a value at a dotted key, creating intermediate tables as needed
+fn insert_config_value(
+    doc: &mut toml_edit::DocumentMut,
+    segments: &[String],
+    mut value: toml_edit::Value,
+) -> anyhow::Result<()> {
+    let (last, parents) = segments.split_last().expect("config keys are non-empty");
+    let mut table: &mut dyn toml_edit::TableLike = doc.as_table_mut();
+    for (i, segment) in parents.iter().enumerate() {
+        table = table
+            .entry(segment)
+            .or_insert_with(|| toml_edit::Item::Table(toml_edit::Table::new()))
+            .as_table_like_mut()
+            .with_context(|| format!("{} is not a table", segments[..=i].join(".")))?;
+    }
+    // Assign in place so the key and value keep their decor (e.g. comments)
+    match table.get_mut(last) {
+        Some(item) => {
+            if let Some(existing) = item.as_value() {
+                *value.decor_mut() = existing.decor().clone();
+            }
+            *item = toml_edit::Item::Value(value);
+        }
+        None => {
+            table.insert(last, toml_edit::Item::Value(value));
+        }
+    }
+    Ok(())
+}
+
+/// Render a config value for display: strings raw, everything else as TOML
+fn format_config_value(value: &toml::Value) -> anyhow::Result<String> {
+    Ok(match value {
+        toml::Value::String(s) => s.clone(),
+        toml::Value::Table(table) => toml::to_string_pretty(table)?.trim_end().to_string(),
+        other => other.to_string(),
+    })
+}
+
+/// Read the user config file that `wt` loads, treating a missing file as empty
+fn read_user_config_file() -> anyhow::Result<(PathBuf, String)> {
+    let config_path = worktrunk::config::get_config_path().context(
+        "Cannot determine config directory. Set $HOME or $XDG_CONFIG_HOME environment variable",
+    )?;
+    let contents = match std::fs::read_to_string(&config_path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e).context("Failed to read config file"),
+    };
+    Ok((config_path, contents))
+}
+
+/// Handle `wt config get`
+pub fn handle_config_get(key: &str) -> anyhow::Result<()> {
+    let segments = config_key_segments(key)?;
+    let (_, contents) = read_user_config_file()?;
+
+    match get_config_value(&contents, &segments)? {
+        Some(value) => output::stdout(format_config_value(&value)?)?,
+        None => output::print(info_message(cformat!(
+            "<bold>{}</> is unset",
+            segments.join(".")
+        )))?,
+    }
+
+    Ok(())
+}
+
+/// Handle `wt config set`
+pub fn handle_config_set(key: &str, value: &str) -> anyhow::Result<()> {
+    let segments = config_key_segments(key)?;
+    let (config_path, contents) = read_user_config_file()?;
+
+    let (updated, value) = set_config_value(&contents, &segments, value)?;
+
+    if let Some(parent) = config_path.parent() {
+        std::fs::create_dir_all(parent).context("Failed to create config directory")?;
+    }
+    std::fs::write(&config_path, updated).context("Failed to write config file")?;
+
+    output::print(success_message(cformat!(
+        "Set <bold>{}</> to <bold>{}</>",
+        segments.join("."),
+        value
+    )))?;
+
+    Ok(())
+}
+
 /// Check if Claude Code CLI is available
 fn is_claude_available() -> bool {
     use std::process::{Command, Stdio};
@@ -299,13 +559,16 @@ fn render_diagnostics(out: &mut String) -> anyhow::Result<()> {
         return Ok(());
     }
 
+    let args = commit_config
+        .resolved_args()
+        .unwrap_or_else(|_| commit_config.args.clone());
     let command_display = format!(
         "{}{}",
         commit_config.command.as_ref().unwrap(),
-        if commit_config.args.is_empty() {
+        if args.is_empty() {
             String::new()
         } else {
-            format!(" {}", commit_config.args.join(" "))
+            format!(" {}", args.join(" "))
         }
     );
 
@@ -409,7 +672,10 @@ fn render_project_config(out: &mut String) -> anyhow::Result<()> {
             return Ok(());
         }
     };
-    let config_path = repo_root.join(".config").join("wt.toml");
+    let config_path = ProjectConfig::find_path(&repo)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| repo_root.join(".config").join("wt.toml"));
 
     writeln!(
         out,
@@ -471,8 +737,13 @@ fn render_shell_status(out: &mut String) -> anyhow::Result<()> {
     writeln!(out)?;
 
     // Use the same detection logic as `wt config shell install`
+    // An invalid user config is reported above; fall back to the binary name
     let cmd = crate::binary_name();
-    let scan_result = match scan_shell_configs(None, true, &cmd) {
+    let cmds = WorktrunkConfig::load().map_or_else(
+        |_| ShellCmds::uniform(cmd.clone()),
+        |config| ShellCmds::recorded(&config, cmd.clone()),
+    );
+    let scan_result = match scan_shell_configs(None, None, true, &cmds) {
         Ok(r) => r,
         Err(e) => {
             writeln!(
@@ -566,7 +837,7 @@ fn render_shell_status(out: &mut String) -> anyhow::Result<()> {
 
                 // For fish, check completions file separately
                 if matches!(shell, Shell::Fish)
-                    && let Ok(completion_path) = shell.completion_path(&cmd)
+                    && let Ok(completion_path) = shell.completion_path(cmds.for_shell(shell))
                 {
                     let completion_display = format_path_for_display(&completion_path);
                     if completion_path.exists() {
@@ -1751,4 +2022,96 @@ mod tests {
         let path = result.unwrap();
         assert!(path.ends_with("worktrunk/config.toml"));
     }
+
+    // ==================== config get/set tests ====================
+
+    fn segments(key: &str) -> Vec<String> {
+        config_key_segments(key).unwrap()
+    }
+
+    #[test]
+    fn test_config_key_segments() {
+        assert_eq!(
+            segments("commit_generation.command"),
+            ["commit-generation", "command"]
+        );
+        assert_eq!(
+            segments("commit_generation.template_file"),
+            ["commit-generation", "template-file"]
+        );
+        // Named hook commands are user-chosen and kept verbatim
+        assert_eq!(segments("post_create.my_task"), ["post-create", "my_task"]);
+        assert!(config_key_segments("list..full").is_err());
+        assert!(config_key_segments("").is_err());
+    }
+
+    #[test]
+    fn test_set_config_value_round_trips() {
+        let (contents, _) =
+            set_config_value("", &segments("commit_generation.command"), "llm").unwrap();
+        let (contents, _) =
+            set_config_value(&contents, &segments("commit-generation.args"), r#"["-s"]"#).unwrap();
+        let (contents, value) = set_config_value(
+            &contents,
+            &segments("skip-shell-integration-prompt"),
+            "true",
+        )
+        .unwrap();
+        assert_eq!(value, toml::Value::Boolean(true));
+
+        let config: WorktrunkConfig = toml::from_str(&contents).unwrap();
+        assert_eq!(config.commit_generation.command.as_deref(), Some("llm"));
+        assert_eq!(config.commit_generation.args, ["-s"]);
+        assert!(config.skip_shell_integration_prompt);
+
+        assert_eq!(
+            get_config_value(&contents, &segments("commit-generation.command")).unwrap(),
+            Some(toml::Value::String("llm".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_set_config_value_uses_schema_type() {
+        // A literal that doesn't fit a string setting is stored as a string
+        let (contents, value) = set_config_value("", &segments("worktree-path"), "42").unwrap();
+        assert_eq!(value, toml::Value::String("42".to_string()));
+        assert_eq!(contents, "worktree-path = \"42\"\n");
+
+        let err = set_config_value("", &segments("list.full"), "maybe").unwrap_err();
+        assert!(err.to_string().contains("expected a boolean"), "{err}");
+    }
+
+    #[test]
+    fn test_set_config_value_preserves_other_content() {
+        let original = "# Paths\nworktree-path = \"x\" # inline\n\n[list]\nfull = false\n";
+        let (contents, _) =
+            set_config_value(original, &segments("worktree-path"), "../{{ branch }}").unwrap();
+        assert_eq!(
+            contents,
+            "# Paths\nworktree-path = \"../{{ branch }}\" # inline\n\n[list]\nfull = false\n"
+        );
+    }
+
+    #[test]
+    fn test_set_config_value_rejects_unknown_key() {
+        let err = set_config_value("", &segments("bogus"), "1").unwrap_err();
+        assert_eq!(err.to_string(), "Unknown config key: bogus");
+
+        let err = set_config_value("list = 1\n", &segments("list.full"), "true").unwrap_err();
+        assert_eq!(err.to_string(), "list is not a table");
+    }
+
+    #[test]
+    fn test_get_config_value_unset() {
+        let contents = "[list]\nfull = true\n";
+        assert_eq!(
+            get_config_value(contents, &segments("list.branches")).unwrap(),
+            None
+        );
+        assert_eq!(
+            get_config_value(contents, &segments("list.full.deeper")).unwrap(),
+            None
+        );
+        assert_eq!(get_config_value("", &segments("merge")).unwrap(), None);
+    }
 }
@@ -1,8 +1,10 @@
+use std::collections::BTreeMap;
 use std::fs::{self, OpenOptions};
 use std::io::{self, BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
+use worktrunk::config::WorktrunkConfig;
 use worktrunk::path::format_path_for_display;
-use worktrunk::shell::{self, Shell};
+use worktrunk::shell::{self, BashRc, Shell};
 use worktrunk::shell_exec::ShellConfig;
 use worktrunk::styling::{
     INFO_SYMBOL, PROMPT_SYMBOL, SUCCESS_SYMBOL, format_bash_with_gutter, format_with_gutter,
@@ -11,6 +13,40 @@ use worktrunk::styling::{
 
 use crate::output;
 
+/// Command name for each shell's integration
+///
+/// `--cmd` applies to every shell being installed. Otherwise each shell uses the
+/// name recorded under `[shell-cmd]` in the user config, falling back to the
+/// binary name.
+pub struct ShellCmds {
+    default: String,
+    recorded: BTreeMap<String, String>,
+}
+
+impl ShellCmds {
+    /// Use `cmd` for every shell
+    pub fn uniform(cmd: String) -> Self {
+        Self {
+            default: cmd,
+            recorded: BTreeMap::new(),
+        }
+    }
+
+    /// Use the names recorded in the user config, falling back to `default`
+    pub fn recorded(config: &WorktrunkConfig, default: String) -> Self {
+        Self {
+            default,
+            recorded: config.shell_cmd.clone(),
+        }
+    }
+
+    pub fn for_shell(&self, shell: Shell) -> &str {
+        self.recorded
+            .get(&shell.to_string())
+            .map_or(&self.default, String::as_str)
+    }
+}
+
 pub struct ConfigureResult {
     pub shell: Shell,
     pub path: PathBuf,
@@ -45,6 +81,9 @@ pub struct ScanResult {
     pub skipped: Vec<(Shell, PathBuf)>, // Shell + first path that was checked
     /// Zsh was configured but compinit is missing (completions won't work without it)
     pub zsh_needs_compinit: bool,
+    /// Shells whose integration line is in more than one of their config files,
+    /// so it loads twice (e.g., both `~/.bashrc` and `~/.bash_profile`)
+    pub duplicates: Vec<(Shell, Vec<PathBuf>)>,
 }
 
 pub struct CompletionResult {
@@ -95,6 +134,15 @@ impl ConfigAction {
         }
     }
 
+    /// Name of this action in `--format=json` output
+    pub fn json_name(&self) -> &'static str {
+        match self {
+            ConfigAction::Added | ConfigAction::Created => "modified",
+            ConfigAction::AlreadyExists => "already_configured",
+            ConfigAction::WouldAdd | ConfigAction::WouldCreate => "would_modify",
+        }
+    }
+
     /// Returns the appropriate symbol for this action
     pub fn symbol(&self) -> &'static str {
         match self {
@@ -105,17 +153,62 @@ impl ConfigAction {
     }
 }
 
+/// Structured install report for `--format=json`
+///
+/// One entry per shell that was configured (or would be, with `--dry-run`) or
+/// skipped because its config file wasn't found.
+pub fn install_report_json(scan_result: &ScanResult) -> serde_json::Value {
+    let configured = scan_result.configured.iter().map(|result| {
+        serde_json::json!({
+            "shell": result.shell.to_string(),
+            "config_path": result.path,
+            "action": result.action.json_name(),
+        })
+    });
+    let skipped = scan_result.skipped.iter().map(|(shell, path)| {
+        serde_json::json!({
+            "shell": shell.to_string(),
+            "config_path": path,
+            "action": "skipped",
+        })
+    });
+    serde_json::Value::Array(configured.chain(skipped).collect())
+}
+
+/// Install shell integration.
+///
+/// With `rc_file`, writes to that exact file instead of discovering config files.
+/// The shell comes from `shell_filter`, or is inferred from the file name.
+///
+/// With `bash_rc`, bash integration goes to that startup file (created if missing)
+/// rather than the first existing one.
+///
+/// With `dry_run`, returns the preview (`WouldAdd`/`WouldCreate` actions) without
+/// prompting or writing anything.
 pub fn handle_configure_shell(
     shell_filter: Option<Shell>,
     skip_confirmation: bool,
-    cmd: String,
+    dry_run: bool,
+    rc_file: Option<&Path>,
+    bash_rc: Option<BashRc>,
+    cmds: &ShellCmds,
 ) -> Result<ScanResult, String> {
+    let shell_filter = match (rc_file, shell_filter) {
+        (Some(path), None) => Some(shell_for_rc_file(path).ok_or_else(|| {
+            format!(
+                "Cannot infer shell from {}; specify it, e.g. `install zsh --rc-file <PATH>`",
+                format_path_for_display(path)
+            )
+        })?),
+        _ => shell_filter,
+    };
+
     // First, do a dry-run to see what would be changed
-    let preview = scan_shell_configs(shell_filter, true, &cmd)?;
+    let preview = scan_shell_configs_at(shell_filter, rc_file, bash_rc, true, cmds)?;
 
     // Preview completions that would be written
     let shells: Vec<_> = preview.configured.iter().map(|r| r.shell).collect();
-    let completion_preview = process_shell_completions(&shells, true, &cmd)?;
+    let completion_preview = process_shell_completions(&shells, true, cmds)?;
 
     // If nothing to do, return early
     if preview.configured.is_empty() {
@@ -124,6 +217,7 @@ pub fn handle_configure_shell(
             completion_results: completion_preview,
             skipped: preview.skipped,
             zsh_needs_compinit: false,
+            duplicates: preview.duplicates,
         });
     }
 
@@ -136,13 +230,14 @@ pub fn handle_configure_shell(
         .iter()
         .any(|r| !matches!(r.action, ConfigAction::AlreadyExists));
 
-    // If nothing needs to be changed, just return the preview results
-    if !needs_shell_changes && !needs_completion_changes {
+    // If nothing needs to be changed (or we're only previewing), return the preview results
+    if dry_run || (!needs_shell_changes && !needs_completion_changes) {
         return Ok(ScanResult {
             configured: preview.configured,
             completion_results: completion_preview,
             skipped: preview.skipped,
             zsh_needs_compinit: false,
+            duplicates: preview.duplicates,
         });
     }
 
@@ -151,7 +246,7 @@ pub fn handle_configure_shell(
         && !prompt_for_install(
             &preview.configured,
             &completion_preview,
-            &cmd,
+            cmds,
             "Install shell integration?",
         )?
     {
@@ -159,8 +254,8 @@ pub fn handle_configure_shell(
     }
 
     // User confirmed (or --yes flag was used), now actually apply the changes
-    let result = scan_shell_configs(shell_filter, false, &cmd)?;
-    let completion_results = process_shell_completions(&shells, false, &cmd)?;
+    let result = scan_shell_configs_at(shell_filter, rc_file, bash_rc, false, cmds)?;
+    let completion_results = process_shell_completions(&shells, false, cmds)?;
 
     // Zsh completions require compinit to be enabled. Unlike bash/fish, zsh doesn't
     // enable its completion system by default - users must explicitly call compinit.
@@ -212,35 +307,112 @@ pub fn handle_configure_shell(
         completion_results,
         skipped: result.skipped,
         zsh_needs_compinit,
+        duplicates: result.duplicates,
+    })
+}
+
+/// Infer which shell an rc file belongs to from its file name
+fn shell_for_rc_file(path: &Path) -> Option<Shell> {
+    let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+    if name.ends_with(".fish") {
+        Some(Shell::Fish)
+    } else if name.ends_with(".nu") {
+        Some(Shell::Nu)
+    } else if name.ends_with(".elv") {
+        Some(Shell::Elvish)
+    } else if name.ends_with(".xsh") || name == ".xonshrc" {
+        Some(Shell::Xonsh)
+    } else if name == ".tcshrc" {
+        Some(Shell::Tcsh)
+    } else if matches!(name.as_str(), ".cshrc" | ".login") {
+        Some(Shell::Csh)
+    } else if name.ends_with(".ps1") {
+        Some(Shell::PowerShell)
+    } else if name.contains("zsh") || matches!(name.as_str(), ".zprofile" | ".zlogin") {
+        Some(Shell::Zsh)
+    } else if name.contains("bash") || name == ".profile" {
+        Some(Shell::Bash)
+    } else {
+        None
+    }
+}
+
+/// Like `scan_shell_configs`, but writes to `rc_file` when given.
+///
+/// An explicit rc file bypasses discovery and is created if missing.
+fn scan_shell_configs_at(
+    shell_filter: Option<Shell>,
+    rc_file: Option<&Path>,
+    bash_rc: Option<BashRc>,
+    dry_run: bool,
+    cmds: &ShellCmds,
+) -> Result<ScanResult, String> {
+    let (Some(rc_file), Some(shell)) = (rc_file, shell_filter) else {
+        return scan_shell_configs(shell_filter, bash_rc, dry_run, cmds);
+    };
+
+    let configured = configure_shell_file(shell, rc_file, dry_run, true, cmds.for_shell(shell))
+        .map_err(|e| format!("Failed to configure {}: {}", shell, e))?;
+
+    Ok(ScanResult {
+        configured: configured.into_iter().collect(),
+        completion_results: Vec::new(), // Completions handled separately in handle_configure_shell
+        skipped: Vec::new(),
+        zsh_needs_compinit: false, // Caller handles compinit detection
+        duplicates: Vec::new(),
     })
 }
 
+/// Install shell integration into each shell's first existing config file.
+///
+/// `bash_rc` pins bash to one startup file instead of the first existing one.
 pub fn scan_shell_configs(
     shell_filter: Option<Shell>,
+    bash_rc: Option<BashRc>,
     dry_run: bool,
-    cmd: &str,
+    cmds: &ShellCmds,
 ) -> Result<ScanResult, String> {
     #[cfg(windows)]
-    let default_shells = vec![Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
+    let mut default_shells = vec![Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
     #[cfg(not(windows))]
-    let default_shells = vec![Shell::Bash, Shell::Zsh, Shell::Fish];
+    let mut default_shells = vec![Shell::Bash, Shell::Zsh, Shell::Fish];
+
+    // List the shell the user is running first, so it leads the preview and prompt
+    if let Some(detected) = Shell::detect()
+        && let Some(pos) = default_shells.iter().position(|&s| s == detected)
+    {
+        default_shells[..=pos].rotate_right(1);
+    }
 
     let shells = shell_filter.map_or(default_shells, |shell| vec![shell]);
 
     let mut results = Vec::new();
     let mut skipped = Vec::new();
+    let mut duplicates = Vec::new();
 
     for shell in shells {
-        let paths = shell
+        let cmd = cmds.for_shell(shell);
+        let mut paths = shell
             .config_paths(cmd)
             .map_err(|e| format!("Failed to get config paths for {}: {}", shell, e))?;
+        let candidates = paths.clone();
+
+        // A chosen bash startup file counts as explicitly targeting it
+        let pinned = match (shell, bash_rc) {
+            (Shell::Bash, Some(rc)) => {
+                paths.retain(|p| p.file_name().is_some_and(|n| n == rc.file_name()));
+                true
+            }
+            _ => false,
+        };
+        let explicit = shell_filter.is_some() || pinned;
 
         // Find the first existing config file
         let target_path = paths.iter().find(|p| p.exists());
 
-        // For Fish, also check if the parent directory (conf.d/) exists
+        // For Fish (and Nushell), also check if the parent directory (conf.d/) exists
         // since we create the file there rather than modifying an existing one
-        let has_config_location = if matches!(shell, Shell::Fish) {
+        let has_config_location = if matches!(shell, Shell::Fish | Shell::Nu) {
             paths
                 .first()
                 .and_then(|p| p.parent())
@@ -252,13 +424,25 @@ pub fn scan_shell_configs(
         };
 
         // Only configure if explicitly targeting this shell OR if config file/location exists
-        let should_configure = shell_filter.is_some() || has_config_location;
+        let should_configure = explicit || has_config_location;
 
         if should_configure {
             let path = target_path.or_else(|| paths.first());
             if let Some(path) = path {
-                match configure_shell_file(shell, path, dry_run, shell_filter.is_some(), cmd) {
-                    Ok(Some(result)) => results.push(result),
+                match configure_shell_file(shell, path, dry_run, explicit, cmd) {
+                    Ok(Some(result)) => {
+                        // In a dry run the target doesn't have the line yet, but will
+                        let pending = matches!(
+                            result.action,
+                            ConfigAction::WouldAdd | ConfigAction::WouldCreate
+                        )
+                        .then_some(result.path.as_path());
+                        let files = files_with_integration_line(&candidates, pending, cmd);
+                        if files.len() > 1 {
+                            duplicates.push((shell, files));
+                        }
+                        results.push(result);
+                    }
                     Ok(None) => {} // No action needed
                     Err(e) => {
                         // For non-critical errors, we could continue with other shells
@@ -270,7 +454,7 @@ pub fn scan_shell_configs(
         } else if shell_filter.is_none() {
             // Track skipped shells (only when not explicitly filtering)
             // For Fish, we check for conf.d directory; for others, the config file
-            let skipped_path = if matches!(shell, Shell::Fish) {
+            let skipped_path = if matches!(shell, Shell::Fish | Shell::Nu) {
                 paths
                     .first()
                     .and_then(|p| p.parent())
@@ -294,9 +478,34 @@ pub fn scan_shell_configs(
         completion_results: Vec::new(), // Completions handled separately in handle_configure_shell
         skipped,
         zsh_needs_compinit: false, // Caller handles compinit detection
+        duplicates,
     })
 }
 
+/// Config files among `candidates` that contain the integration line
+///
+/// Read-only: `pending` is a file the line is about to be written to, counted
+/// as if it were already there. Shells with a single candidate file never
+/// report more than one.
+fn files_with_integration_line(
+    candidates: &[PathBuf],
+    pending: Option<&Path>,
+    cmd: &str,
+) -> Vec<PathBuf> {
+    candidates
+        .iter()
+        .filter(|path| {
+            pending == Some(path.as_path())
+                || fs::read_to_string(path).is_ok_and(|content| {
+                    content
+                        .lines()
+                        .any(|line| shell::is_shell_integration_line(line, cmd))
+                })
+        })
+        .cloned()
+        .collect()
+}
+
 fn configure_shell_file(
     shell: Shell,
     path: &Path,
@@ -319,6 +528,22 @@ fn configure_shell_file(
         );
     }
 
+    // For Nushell, the autoload file holds the whole init script, since nushell
+    // can't evaluate generated code at startup
+    if matches!(shell, Shell::Nu) {
+        let content = shell::ShellInit::with_prefix(shell, cmd.to_string())
+            .generate()
+            .map_err(|e| format!("Failed to generate shell code: {}", e))?;
+        return configure_fish_file(
+            shell,
+            path,
+            content.trim(),
+            dry_run,
+            explicit_shell,
+            &config_line,
+        );
+    }
+
     // For other shells, check if file exists
     if path.exists() {
         // Read the file and check if our integration already exists
@@ -337,8 +562,14 @@ fn configure_shell_file(
                 )
             })?;
 
-            // Canonical detection: check if the line matches exactly what we write
-            if line.trim() == config_line {
+            // Canonical detection: check if the line matches exactly what we write.
+            // PowerShell profiles are commonly hand-edited with variants like
+            // `Invoke-Expression (& wt config shell init powershell | Out-String)`,
+            // so any detected integration line counts there.
+            if line.trim() == config_line
+                || (matches!(shell, Shell::PowerShell)
+                    && shell::is_shell_integration_line(&line, cmd))
+            {
                 return Ok(Some(ConfigureResult {
                     shell,
                     path: path.to_path_buf(),
@@ -507,7 +738,7 @@ fn configure_fish_file(
 pub fn show_install_preview(
     results: &[ConfigureResult],
     completion_results: &[CompletionResult],
-    cmd: &str,
+    cmds: &ShellCmds,
 ) {
     use anstyle::Style;
 
@@ -522,8 +753,8 @@ pub fn show_install_preview(
 
         let shell = result.shell;
         let path = format_path_for_display(&result.path);
-        // Bash/Zsh: inline completions; Fish: separate completion file
-        let what = if matches!(shell, Shell::Fish) {
+        // Bash/Zsh: inline completions; Fish: separate completion file; Elvish/Xonsh: none
+        let what = if matches!(shell, Shell::Fish) || !shell.supports_completion() {
             "shell extension"
         } else {
             "shell extension & completions"
@@ -556,7 +787,7 @@ pub fn show_install_preview(
         ));
 
         // Show the completion content that will be written
-        let fish_completion = fish_completion_content(cmd);
+        let fish_completion = fish_completion_content(cmds.for_shell(shell));
         let _ = output::print(format_bash_with_gutter(fish_completion.trim()));
         let _ = output::blank(); // Blank line after
     }
@@ -570,7 +801,7 @@ pub fn show_install_preview(
 pub fn prompt_for_install(
     results: &[ConfigureResult],
     completion_results: &[CompletionResult],
-    cmd: &str,
+    cmds: &ShellCmds,
     prompt_text: &str,
 ) -> Result<bool, String> {
     use std::io::Write;
@@ -598,7 +829,7 @@ pub fn prompt_for_install(
             }
             "?" => {
                 eprintln!();
-                show_install_preview(results, completion_results, cmd);
+                show_install_preview(results, completion_results, cmds);
                 // Loop back to prompt again
             }
             _ => {
@@ -632,7 +863,7 @@ fn prompt_yes_no() -> Result<bool, String> {
 }
 
 /// Fish completion content - finds command in PATH, with WORKTRUNK_BIN as optional override
-fn fish_completion_content(cmd: &str) -> String {
+pub(crate) fn fish_completion_content(cmd: &str) -> String {
     format!(
         r#"# worktrunk completions for fish
 complete --keep-order --exclusive --command {cmd} --arguments "(test -n \"\$WORKTRUNK_BIN\"; or set -l WORKTRUNK_BIN (type -P {cmd}); COMPLETE=fish \$WORKTRUNK_BIN -- (commandline --current-process --tokenize --cut-at-cursor) (commandline --current-token))"
@@ -648,10 +879,9 @@ complete --keep-order --exclusive --command {cmd} --arguments "(test -n \"\$WORK
 pub fn process_shell_completions(
     shells: &[Shell],
     dry_run: bool,
-    cmd: &str,
+    cmds: &ShellCmds,
 ) -> Result<Vec<CompletionResult>, String> {
     let mut results = Vec::new();
-    let fish_completion = fish_completion_content(cmd);
 
     for &shell in shells {
         // Only fish has a separate completion file
@@ -659,6 +889,9 @@ pub fn process_shell_completions(
             continue;
         }
 
+        let cmd = cmds.for_shell(shell);
+        let fish_completion = fish_completion_content(cmd);
+
         let completion_path = shell
             .completion_path(cmd)
             .map_err(|e| format!("Failed to get completion path for {}: {}", shell, e))?;
@@ -714,10 +947,10 @@ pub fn process_shell_completions(
 pub fn handle_unconfigure_shell(
     shell_filter: Option<Shell>,
     skip_confirmation: bool,
-    cmd: &str,
+    cmds: &ShellCmds,
 ) -> Result<UninstallScanResult, String> {
     // First, do a dry-run to see what would be changed
-    let preview = scan_for_uninstall(shell_filter, true, cmd)?;
+    let preview = scan_for_uninstall(shell_filter, true, cmds)?;
 
     // If nothing to do, return early
     if preview.results.is_empty() && preview.completion_results.is_empty() {
@@ -732,16 +965,16 @@ pub fn handle_unconfigure_shell(
     }
 
     // User confirmed (or --yes flag was used), now actually apply the changes
-    scan_for_uninstall(shell_filter, false, cmd)
+    scan_for_uninstall(shell_filter, false, cmds)
 }
 
 fn scan_for_uninstall(
     shell_filter: Option<Shell>,
     dry_run: bool,
-    cmd: &str,
+    cmds: &ShellCmds,
 ) -> Result<UninstallScanResult, String> {
     #[cfg(windows)]
-    let default_shells = vec![Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
+    let mut default_shells = vec![Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
     #[cfg(not(windows))]
     let default_shells = vec![Shell::Bash, Shell::Zsh, Shell::Fish];
 
@@ -751,12 +984,13 @@ fn scan_for_uninstall(
     let mut not_found = Vec::new();
 
     for &shell in &shells {
+        let cmd = cmds.for_shell(shell);
         let paths = shell
             .config_paths(cmd)
             .map_err(|e| format!("Failed to get config paths for {}: {}", shell, e))?;
 
-        // For Fish, delete entire {cmd}.fish file
-        if matches!(shell, Shell::Fish) {
+        // For Fish and Nushell, delete the entire {cmd}.fish / {cmd}.nu file
+        if matches!(shell, Shell::Fish | Shell::Nu) {
             if let Some(fish_path) = paths.first() {
                 if fish_path.exists() {
                     if dry_run {
@@ -820,7 +1054,7 @@ fn scan_for_uninstall(
         }
 
         let completion_path = shell
-            .completion_path(cmd)
+            .completion_path(cmds.for_shell(shell))
             .map_err(|e| format!("Failed to get completion path for {}: {}", shell, e))?;
 
         if completion_path.exists() {
@@ -928,8 +1162,8 @@ fn prompt_for_uninstall_confirmation(
         let bold = Style::new().bold();
         let shell = result.shell;
         let path = format_path_for_display(&result.path);
-        // Bash/Zsh: inline completions; Fish: separate completion file
-        let what = if matches!(shell, Shell::Fish) {
+        // Bash/Zsh: inline completions; Fish: separate completion file; Elvish/Xonsh: none
+        let what = if matches!(shell, Shell::Fish) || !shell.supports_completion() {
             "shell extension"
         } else {
             "shell extension & completions"
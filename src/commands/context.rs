@@ -24,6 +24,23 @@ pub struct CommandEnv {
     pub repo_root: PathBuf,
 }
 
+/// Apply the project's `[commit-generation]` overrides to the user's settings.
+///
+/// A project-supplied command still needs approval before it runs; see
+/// `approve_commit_generation`.
+pub fn apply_project_commit_generation(
+    repo: &Repository,
+    config: &mut WorktrunkConfig,
+) -> anyhow::Result<()> {
+    if let Some(overrides) = repo
+        .load_project_config()?
+        .and_then(|project| project.commit_generation)
+    {
+        overrides.apply_to(&mut config.commit_generation);
+    }
+    Ok(())
+}
+
 impl CommandEnv {
     /// Load the command environment for a specific action.
     ///
@@ -33,7 +50,8 @@ impl CommandEnv {
         let repo = Repository::current();
         let worktree_path = std::env::current_dir().context("Failed to get current directory")?;
         let branch = repo.require_current_branch(action)?;
-        let config = WorktrunkConfig::load().context("Failed to load config")?;
+        let mut config = WorktrunkConfig::load().context("Failed to load config")?;
+        apply_project_commit_generation(&repo, &mut config)?;
         let repo_root = repo
             .worktree_base()
             .context("Failed to determine repository root")?;
@@ -59,7 +77,8 @@ impl CommandEnv {
             .current_branch()
             .context("Failed to determine current branch")?
             .map(str::to_string);
-        let config = WorktrunkConfig::load().context("Failed to load config")?;
+        let mut config = WorktrunkConfig::load().context("Failed to load config")?;
+        apply_project_commit_generation(&repo, &mut config)?;
         let repo_root = repo
             .worktree_base()
             .context("Failed to determine repository root")?;
@@ -0,0 +1,177 @@
+//! Diagnose common setup problems.
+//!
+//! `wt doctor` runs a fixed list of checks and reports each as passing, a
+//! warning, or a failure. Any failure exits non-zero so the command can gate
+//! setup scripts; warnings don't.
+
+use color_print::cformat;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::{Repository, WorktrunkError};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{error_message, hint_message, success_message, warning_message};
+
+use super::configure_shell::{ConfigAction, ShellCmds, scan_shell_configs};
+use crate::output;
+
+enum Status {
+    Pass,
+    Warn,
+    Fail,
+}
+
+/// Result of a single check, with an optional hint on how to fix it
+struct Check {
+    status: Status,
+    message: String,
+    hint: Option<String>,
+}
+
+impl Check {
+    fn pass(message: impl Into<String>) -> Self {
+        Self {
+            status: Status::Pass,
+            message: message.into(),
+            hint: None,
+        }
+    }
+
+    fn warn(message: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            status: Status::Warn,
+            message: message.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn fail(message: impl Into<String>, hint: Option<String>) -> Self {
+        Self {
+            status: Status::Fail,
+            message: message.into(),
+            hint,
+        }
+    }
+}
+
+/// Handle `wt doctor`.
+pub fn handle_doctor(config: &WorktrunkConfig) -> anyhow::Result<()> {
+    let cmd = crate::binary_name();
+    let checks = [
+        check_shell_config(config, &cmd),
+        check_binary_on_path(&cmd),
+        check_commit_generation(config),
+        check_repository(),
+    ];
+
+    let mut failed = false;
+    for check in &checks {
+        let line = match check.status {
+            Status::Pass => success_message(&check.message),
+            Status::Warn => warning_message(&check.message),
+            Status::Fail => {
+                failed = true;
+                error_message(&check.message)
+            }
+        };
+        output::print(line)?;
+        if let Some(hint) = &check.hint {
+            output::print(hint_message(hint))?;
+        }
+    }
+
+    if failed {
+        return Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into());
+    }
+    Ok(())
+}
+
+/// Whether any shell rc file contains the `wt config shell init` line
+fn check_shell_config(config: &WorktrunkConfig, cmd: &str) -> Check {
+    let cmds = ShellCmds::recorded(config, cmd.to_string());
+    let scan = match scan_shell_configs(None, None, true, &cmds) {
+        Ok(scan) => scan,
+        Err(e) => return Check::fail(format!("Could not read shell configs: {e}"), None),
+    };
+
+    let configured: Vec<_> = scan
+        .configured
+        .iter()
+        .filter(|result| matches!(result.action, ConfigAction::AlreadyExists))
+        .map(|result| {
+            cformat!(
+                "<bold>{}</> @ {}",
+                result.shell,
+                format_path_for_display(&result.path)
+            )
+        })
+        .collect();
+
+    if configured.is_empty() {
+        return Check::fail(
+            "Shell integration not configured",
+            Some(cformat!(
+                "To enable shell integration, run <bright-black>{cmd} config shell install</>"
+            )),
+        );
+    }
+
+    let message = format!("Shell integration configured for {}", configured.join(", "));
+    if output::is_shell_integration_active() {
+        Check::pass(message)
+    } else {
+        Check::warn(
+            format!("{message}, but not active in this shell"),
+            "Restart the shell to load it",
+        )
+    }
+}
+
+/// Whether the shell wrapper can find the binary, since it runs `command <cmd>`
+fn check_binary_on_path(cmd: &str) -> Check {
+    if which::which(cmd).is_ok() {
+        Check::pass(cformat!("<bold>{cmd}</> found on PATH"))
+    } else {
+        Check::fail(
+            cformat!("<bold>{cmd}</> not found on PATH"),
+            Some("The shell wrapper runs it by name; add its directory to PATH".to_string()),
+        )
+    }
+}
+
+/// Whether the configured LLM command exists and is executable
+///
+/// Unconfigured is only a warning: commit messages fall back to a generated summary.
+fn check_commit_generation(config: &WorktrunkConfig) -> Check {
+    let generation = &config.commit_generation;
+    let Some(command) = generation
+        .command
+        .as_deref()
+        .filter(|_| generation.is_configured())
+    else {
+        return Check::warn(
+            "Commit generation not configured",
+            "Set [commit-generation] command in the user config to write messages with an LLM",
+        );
+    };
+
+    if which::which(command).is_ok() {
+        Check::pass(cformat!(
+            "Commit generation command <bold>{command}</> found"
+        ))
+    } else {
+        Check::fail(
+            cformat!("Commit generation command <bold>{command}</> not found or not executable"),
+            None,
+        )
+    }
+}
+
+/// Whether the current directory is inside a git worktree
+fn check_repository() -> Check {
+    match Repository::current().worktree_root() {
+        Ok(root) => Check::pass(format!(
+            "Inside git worktree @ {}",
+            format_path_for_display(root)
+        )),
+        Err(_) => Check::fail("Not inside a git worktree", None),
+    }
+}
@@ -21,7 +21,8 @@ use worktrunk::styling::{
 use super::command_executor::CommandContext;
 use super::context::CommandEnv;
 use super::hooks::{
-    HookFailureStrategy, check_name_filter_matched, prepare_hook_commands, run_hook_with_filter,
+    HookFailureStrategy, check_name_filter_matched, execute_hook_commands_parallel,
+    prepare_hook_commands, print_hook_commands, run_hook_with_filter,
     spawn_hook_commands_background,
 };
 use super::merge::{
@@ -29,6 +30,17 @@ use super::merge::{
 };
 use super::project_config::collect_commands_for_hooks;
 
+/// How `wt hook` runs hooks that normally run in background (post-start, post-switch)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HookRunMode {
+    /// Spawn detached processes (default)
+    Background,
+    /// Run sequentially, blocking until complete (for debugging)
+    Foreground,
+    /// Run concurrently, blocking until complete (post-start only)
+    Parallel,
+}
+
 /// Handle `wt hook` command
 ///
 /// When explicitly invoking hooks, ALL hooks run (both user and project).
@@ -39,17 +51,17 @@ use super::project_config::collect_commands_for_hooks;
 /// Custom variables from `--var KEY=VALUE` are merged into the template context,
 /// allowing hooks to be tested with different values without being in that context.
 ///
-/// The `foreground` parameter controls execution mode for hooks that normally run
-/// in background (post-start, post-switch):
-/// - `None` = use default behavior for this hook type
-/// - `Some(true)` = run in foreground (for debugging)
-/// - `Some(false)` = run in background (default for post-start/post-switch)
+/// The `mode` parameter controls execution mode for hooks that normally run
+/// in background (post-start, post-switch); `None` uses the default for the hook type.
+///
+/// With `dry_run`, the expanded commands are printed instead of run; no approval is needed.
 pub fn run_hook(
     hook_type: HookType,
     yes: bool,
-    foreground: Option<bool>,
+    mode: Option<HookRunMode>,
     name_filter: Option<&str>,
     custom_vars: &[(String, String)],
+    dry_run: bool,
 ) -> anyhow::Result<()> {
     use super::command_approval::approve_hooks_filtered;
 
@@ -61,13 +73,20 @@ pub fn run_hook(
     // Load project config (optional - user hooks can run without project config)
     let project_config = repo.load_project_config()?;
 
-    // "Approve at the Gate": approve project hooks upfront
-    // Pass name_filter to only approve the targeted hook, not all hooks of this type
-    let approved = approve_hooks_filtered(&ctx, &[hook_type], name_filter)?;
-    // If declined, return early - the whole point of `wt hook` is to run hooks
-    if !approved {
-        crate::output::print(worktrunk::styling::info_message("Commands declined"))?;
-        return Ok(());
+    // Fail fast with every problem at once, before approving or running anything
+    if let Some(config) = &project_config {
+        let errors = config.validate();
+        if !errors.is_empty() {
+            let config_path = match ProjectConfig::find_path(repo)? {
+                Some(path) => path,
+                None => repo.worktree_root()?.join(".config").join("wt.toml"),
+            };
+            return Err(GitError::ProjectConfigInvalid {
+                config_path,
+                errors,
+            }
+            .into());
+        }
     }
 
     // Build extra vars from command-line --var flags
@@ -98,8 +117,62 @@ pub fn run_hook(
         Ok(())
     }
 
+    if dry_run {
+        // Same target the real run uses for hooks that receive one
+        let target_branch = match hook_type {
+            HookType::PreCommit => repo.default_branch().ok(),
+            HookType::PreMerge | HookType::PostMerge => Some(ctx.branch_or_head().to_string()),
+            _ => None,
+        };
+        let mut extra_vars: Vec<(&str, &str)> = target_branch
+            .as_deref()
+            .into_iter()
+            .map(|t| ("target", t))
+            .collect();
+        extra_vars.extend(&custom_vars_refs);
+
+        let user_config = ctx.config.hooks.get(hook_type);
+        let project_config = project_config.as_ref().and_then(|c| c.hooks.get(hook_type));
+        require_hooks(user_config, project_config, hook_type)?;
+        return print_hook_commands(
+            &ctx,
+            user_config,
+            project_config,
+            hook_type,
+            &extra_vars,
+            name_filter,
+        );
+    }
+
+    // "Approve at the Gate": approve project hooks upfront
+    // Pass name_filter to only approve the targeted hook, not all hooks of this type
+    let approved = approve_hooks_filtered(&ctx, &[hook_type], name_filter)?;
+    // If declined, return early - the whole point of `wt hook` is to run hooks
+    if !approved {
+        crate::output::print(worktrunk::styling::info_message("Commands declined"))?;
+        return Ok(());
+    }
+
     // Execute the hook based on type
     match hook_type {
+        HookType::PreCreate => {
+            let user_config = user_hook!(pre_create);
+            let project_config = project_config
+                .as_ref()
+                .and_then(|c| c.hooks.pre_create.as_ref());
+            require_hooks(user_config, project_config, hook_type)?;
+            // Manual wt hook: user stays at cwd (no cd happens)
+            run_hook_with_filter(
+                &ctx,
+                user_config,
+                project_config,
+                hook_type,
+                &custom_vars_refs,
+                HookFailureStrategy::FailFast,
+                name_filter,
+                crate::output::pre_hook_display_path(ctx.worktree_path),
+            )
+        }
         HookType::PostCreate => {
             let user_config = user_hook!(post_create);
             let project_config = project_config
@@ -120,6 +193,10 @@ pub fn run_hook(
         }
         HookType::PostStart => {
             let user_config = user_hook!(post_start);
+            let parallelism = project_config
+                .as_ref()
+                .and_then(|c| c.post_start_parallelism)
+                .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
             let project_config = project_config
                 .as_ref()
                 .and_then(|c| c.hooks.post_start.as_ref());
@@ -127,25 +204,26 @@ pub fn run_hook(
 
             // Default to background (matching normal behavior during switch)
             // Use --foreground to run in foreground for debugging
-            if !foreground.unwrap_or(false) {
-                let commands = prepare_hook_commands(
-                    &ctx,
-                    user_config,
-                    project_config,
-                    hook_type,
-                    &custom_vars_refs,
-                    name_filter,
-                    None,
-                )?;
-                check_name_filter_matched(
-                    name_filter,
-                    commands.len(),
-                    user_config,
-                    project_config,
-                )?;
-                spawn_hook_commands_background(&ctx, commands, hook_type)
-            } else {
-                run_hook_with_filter(
+            match mode.unwrap_or(HookRunMode::Background) {
+                HookRunMode::Background => {
+                    let commands = prepare_hook_commands(
+                        &ctx,
+                        user_config,
+                        project_config,
+                        hook_type,
+                        &custom_vars_refs,
+                        name_filter,
+                        None,
+                    )?;
+                    check_name_filter_matched(
+                        name_filter,
+                        commands.len(),
+                        user_config,
+                        project_config,
+                    )?;
+                    spawn_hook_commands_background(&ctx, commands, hook_type)
+                }
+                HookRunMode::Foreground => run_hook_with_filter(
                     &ctx,
                     user_config,
                     project_config,
@@ -154,7 +232,25 @@ pub fn run_hook(
                     HookFailureStrategy::Warn,
                     name_filter,
                     crate::output::pre_hook_display_path(ctx.worktree_path),
-                )
+                ),
+                HookRunMode::Parallel => {
+                    let commands = prepare_hook_commands(
+                        &ctx,
+                        user_config,
+                        project_config,
+                        hook_type,
+                        &custom_vars_refs,
+                        name_filter,
+                        crate::output::pre_hook_display_path(ctx.worktree_path),
+                    )?;
+                    check_name_filter_matched(
+                        name_filter,
+                        commands.len(),
+                        user_config,
+                        project_config,
+                    )?;
+                    execute_hook_commands_parallel(&ctx, commands, hook_type, parallelism)
+                }
             }
         }
         HookType::PostSwitch => {
@@ -166,7 +262,7 @@ pub fn run_hook(
 
             // Default to background (matching normal behavior during switch)
             // Use --foreground to run in foreground for debugging
-            if !foreground.unwrap_or(false) {
+            if mode.unwrap_or(HookRunMode::Background) == HookRunMode::Background {
                 let commands = prepare_hook_commands(
                     &ctx,
                     user_config,
@@ -253,6 +349,7 @@ pub fn run_hook(
                 name_filter,
                 crate::output::pre_hook_display_path(ctx.worktree_path),
                 &custom_vars_refs,
+                HookFailureStrategy::FailFast,
             )
         }
     }
@@ -275,6 +372,7 @@ pub fn add_approvals(show_all: bool) -> anyhow::Result<()> {
 
     // Collect all commands from the project config
     let all_hooks = [
+        HookType::PreCreate,
         HookType::PostCreate,
         HookType::PostStart,
         HookType::PreCommit,
@@ -387,6 +485,7 @@ pub fn handle_hook_show(hook_type_filter: Option<&str>, expanded: bool) -> anyho
 
     // Parse hook type filter if provided
     let filter: Option<HookType> = hook_type_filter.map(|s| match s {
+        "pre-create" => HookType::PreCreate,
         "post-create" => HookType::PostCreate,
         "post-start" => HookType::PostStart,
         "post-switch" => HookType::PostSwitch,
@@ -457,6 +556,7 @@ fn render_user_hooks(
 
     // Collect all user hooks
     let hooks = [
+        (HookType::PreCreate, &config.hooks.pre_create),
         (HookType::PostCreate, &config.hooks.post_create),
         (HookType::PostStart, &config.hooks.post_start),
         (HookType::PostSwitch, &config.hooks.post_switch),
@@ -517,6 +617,7 @@ fn render_project_hooks(
 
     // Collect all project hooks
     let hooks = [
+        (HookType::PreCreate, &config.hooks.pre_create),
         (HookType::PostCreate, &config.hooks.post_create),
         (HookType::PostStart, &config.hooks.post_start),
         (HookType::PostSwitch, &config.hooks.post_switch),
@@ -1,11 +1,20 @@
+use std::fs::File;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
+use anyhow::Context;
 use color_print::cformat;
 use worktrunk::HookType;
-use worktrunk::config::CommandConfig;
+use worktrunk::config::{CommandConfig, expand_path};
 use worktrunk::git::WorktrunkError;
-use worktrunk::path::format_path_for_display;
-use worktrunk::styling::{format_bash_with_gutter, progress_message, warning_message};
+use worktrunk::path::{format_path_for_display, sanitize_for_filename};
+use worktrunk::shell_exec::execute_piped;
+use worktrunk::styling::{
+    eprintln, format_bash_with_gutter, hint_message, info_message, progress_message,
+    warning_message,
+};
+use worktrunk::utils::get_now;
 
 use super::command_executor::{CommandContext, PreparedCommand, prepare_commands};
 use crate::commands::process::spawn_detached;
@@ -52,6 +61,90 @@ impl SourcedCommand {
     }
 }
 
+/// Names used for each command's log file and output prefix
+///
+/// Unnamed commands are numbered `cmd-0`, `cmd-1`, ... in declaration order.
+fn command_names(commands: &[SourcedCommand]) -> Vec<String> {
+    let mut unnamed_index = 0usize;
+    commands
+        .iter()
+        .map(|cmd| match &cmd.prepared.name {
+            Some(n) => n.clone(),
+            None => {
+                let idx = unnamed_index;
+                unnamed_index += 1;
+                format!("cmd-{idx}")
+            }
+        })
+        .collect()
+}
+
+/// Directory from the project's `hook-log-dir`, if foreground hooks should be logged
+fn hook_log_dir(ctx: &CommandContext) -> anyhow::Result<Option<PathBuf>> {
+    let Some(dir) = ctx
+        .repo
+        .load_project_config()?
+        .and_then(|project| project.hook_log_dir)
+    else {
+        return Ok(None);
+    };
+    // Joining an absolute path replaces the base, so only relative paths use repo_root
+    Ok(Some(ctx.repo_root.join(expand_path(&dir))))
+}
+
+/// Log file that a foreground hook command's output is copied into
+struct HookLog {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl HookLog {
+    /// Create `{branch}-{source}-{hook}-{name}-{timestamp}.log` in `dir`
+    fn create(
+        dir: &Path,
+        ctx: &CommandContext,
+        cmd: &SourcedCommand,
+        name: &str,
+    ) -> anyhow::Result<Self> {
+        std::fs::create_dir_all(dir).with_context(|| {
+            format!(
+                "Failed to create hook log directory {}",
+                format_path_for_display(dir)
+            )
+        })?;
+        let timestamp = chrono::DateTime::from_timestamp(get_now() as i64, 0)
+            .unwrap_or_default()
+            .format("%Y%m%dT%H%M%SZ");
+        let path = dir.join(format!(
+            "{}-{}-{}-{}-{timestamp}.log",
+            sanitize_for_filename(ctx.branch_or_head()),
+            cmd.source,
+            cmd.hook_type,
+            sanitize_for_filename(name),
+        ));
+        let file = File::create(&path).with_context(|| {
+            format!(
+                "Failed to create hook log {}",
+                format_path_for_display(&path)
+            )
+        })?;
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Append a line of output; write errors are ignored so logging never fails a hook
+    fn write_line(&self, line: &str) {
+        let mut file = self.file.lock().expect("hook log lock poisoned");
+        let _ = writeln!(file, "{line}");
+    }
+
+    fn hint(&self) -> String {
+        format!("Output logged to {}", format_path_for_display(&self.path))
+    }
+}
+
 /// Controls how hook execution should respond to failures.
 #[derive(Clone, Copy)]
 pub enum HookFailureStrategy {
@@ -148,20 +241,12 @@ pub fn spawn_hook_commands_background(
 
     let operation_prefix = hook_type.to_string();
 
-    // Track index for unnamed commands to prevent log collisions
-    let mut unnamed_index = 0usize;
+    // Unnamed commands are numbered to prevent log collisions
+    let names = command_names(&commands);
 
-    for cmd in commands {
+    for (cmd, name) in commands.iter().zip(names) {
         cmd.announce()?;
 
-        let name = match &cmd.prepared.name {
-            Some(n) => n.clone(),
-            None => {
-                let idx = unnamed_index;
-                unnamed_index += 1;
-                format!("cmd-{idx}")
-            }
-        };
         // Include source in operation name to prevent log file collisions between
         // user and project hooks with the same name
         let operation = format!("{}-{}-{}", cmd.source, operation_prefix, name);
@@ -169,10 +254,11 @@ pub fn spawn_hook_commands_background(
         if let Err(err) = spawn_detached(
             ctx.repo,
             ctx.worktree_path,
-            &cmd.prepared.expanded,
+            &cmd.prepared.runnable(),
             ctx.branch_or_head(),
             &operation,
             Some(&cmd.prepared.context_json),
+            &cmd.prepared.env_vars(),
         ) {
             let err_msg = err.to_string();
             let message = match &cmd.prepared.name {
@@ -187,6 +273,40 @@ pub fn spawn_hook_commands_background(
     Ok(())
 }
 
+/// Print the commands a hook would run, without running them.
+///
+/// Uses the same template expansion as execution, so `wt hook <type> --dry-run`
+/// shows exactly what would be spawned.
+pub fn print_hook_commands(
+    ctx: &CommandContext,
+    user_config: Option<&CommandConfig>,
+    project_config: Option<&CommandConfig>,
+    hook_type: HookType,
+    extra_vars: &[(&str, &str)],
+    name_filter: Option<&str>,
+) -> anyhow::Result<()> {
+    let commands = prepare_hook_commands(
+        ctx,
+        user_config,
+        project_config,
+        hook_type,
+        extra_vars,
+        name_filter,
+        None,
+    )?;
+    check_name_filter_matched(name_filter, commands.len(), user_config, project_config)?;
+
+    for cmd in &commands {
+        let label = match &cmd.prepared.name {
+            Some(n) => cformat!("Would run {hook_type} <bold>{}:{n}</>:", cmd.source),
+            None => format!("Would run {hook_type} {} hook:", cmd.source),
+        };
+        crate::output::print(info_message(label))?;
+        crate::output::print(format_bash_with_gutter(&cmd.prepared.expanded))?;
+    }
+    Ok(())
+}
+
 /// Check if a name filter was provided but no commands matched.
 /// Returns an error listing available command names if so.
 pub(crate) fn check_name_filter_matched(
@@ -265,26 +385,34 @@ pub fn run_hook_with_filter(
 
     // Track first failure for Warn strategy (to propagate exit code after all commands run)
     let mut first_failure: Option<(String, Option<String>, i32)> = None;
+    let log_dir = hook_log_dir(ctx)?;
+    let names = command_names(&commands);
 
-    for cmd in commands {
+    for (cmd, name) in commands.iter().zip(names) {
         cmd.announce()?;
 
-        if let Err(err) = execute_command_in_worktree(
-            ctx.worktree_path,
-            &cmd.prepared.expanded,
-            Some(&cmd.prepared.context_json),
-        ) {
-            // Extract raw message and exit code from error
-            let (err_msg, exit_code) = if let Some(wt_err) = err.downcast_ref::<WorktrunkError>() {
-                match wt_err {
-                    WorktrunkError::ChildProcessExited { message, code } => {
-                        (message.clone(), Some(*code))
-                    }
-                    _ => (err.to_string(), None),
-                }
-            } else {
-                (err.to_string(), None)
-            };
+        let log = match &log_dir {
+            Some(dir) => Some(HookLog::create(dir, ctx, cmd, &name)?),
+            None => None,
+        };
+        let result = match &log {
+            // Piped rather than inherited, so the command won't see a TTY
+            Some(log) => {
+                crate::output::flush()?;
+                run_hook_command(
+                    ctx.worktree_path,
+                    &cmd.prepared,
+                    Some(&|line| {
+                        eprintln!("{line}");
+                        log.write_line(line);
+                    }),
+                )
+            }
+            None => run_hook_command(ctx.worktree_path, &cmd.prepared, None),
+        };
+
+        if let Err(err) = result {
+            let (err_msg, exit_code) = command_error_parts(&err);
 
             match &failure_strategy {
                 HookFailureStrategy::FailFast => {
@@ -294,6 +422,7 @@ pub fn run_hook_with_filter(
                         command_name: cmd.prepared.name.clone(),
                         error: err_msg,
                         exit_code,
+                        log_path: log.map(|log| log.path),
                     }
                     .into());
                 }
@@ -303,6 +432,9 @@ pub fn run_hook_with_filter(
                         None => format!("Command failed: {err_msg}"),
                     };
                     crate::output::print(warning_message(message))?;
+                    if let Some(log) = &log {
+                        crate::output::print(hint_message(log.hint()))?;
+                    }
 
                     // Track first failure to propagate exit code later (only for PostMerge)
                     if first_failure.is_none() && hook_type == HookType::PostMerge {
@@ -324,6 +456,7 @@ pub fn run_hook_with_filter(
             command_name,
             error,
             exit_code: Some(exit_code),
+            log_path: None,
         }
         .into());
     }
@@ -331,6 +464,149 @@ pub fn run_hook_with_filter(
     Ok(())
 }
 
+/// Run one prepared hook command in `worktree_path` and wait for it.
+///
+/// Every foreground hook goes through here, so each command gets its JSON
+/// context on stdin and its `WT_*` environment variables. With `on_line`,
+/// output is piped through it (for prefixes and hook logs); otherwise it
+/// streams to the terminal.
+fn run_hook_command(
+    worktree_path: &Path,
+    cmd: &PreparedCommand,
+    on_line: Option<&(dyn Fn(&str) + Sync)>,
+) -> anyhow::Result<()> {
+    let env = cmd.env_vars();
+    match on_line {
+        Some(on_line) => execute_piped(
+            &cmd.runnable(),
+            worktree_path,
+            Some(&cmd.context_json),
+            &env,
+            on_line,
+        ),
+        None => execute_command_in_worktree(
+            worktree_path,
+            &cmd.runnable(),
+            Some(&cmd.context_json),
+            &env,
+        ),
+    }
+}
+
+/// Extract the raw message and exit code from a command execution error.
+fn command_error_parts(err: &anyhow::Error) -> (String, Option<i32>) {
+    match err.downcast_ref::<WorktrunkError>() {
+        Some(WorktrunkError::ChildProcessExited { message, code }) => {
+            (message.clone(), Some(*code))
+        }
+        _ => (err.to_string(), None),
+    }
+}
+
+/// Run hook commands concurrently, at most `max_concurrency` at a time (blocking).
+///
+/// All commands are announced upfront, then each output line is prefixed with
+/// `[source:name]` (`[source:cmd-N]` for unnamed commands) so interleaved output
+/// stays readable. Every command runs to completion; if any failed, returns a
+/// `HookCommandFailed` error for the first failure in declaration order.
+pub fn execute_hook_commands_parallel(
+    ctx: &CommandContext,
+    commands: Vec<SourcedCommand>,
+    hook_type: HookType,
+    max_concurrency: usize,
+) -> anyhow::Result<()> {
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    if commands.is_empty() {
+        return Ok(());
+    }
+
+    for cmd in &commands {
+        cmd.announce()?;
+    }
+    crate::output::flush()?;
+
+    // Unnamed commands are numbered like their background log files (`cmd-N`)
+    let names = command_names(&commands);
+    let prefixes: Vec<String> = commands
+        .iter()
+        .zip(&names)
+        .map(|(cmd, name)| cformat!("<bright-black>[{}:{}]</>", cmd.source, name))
+        .collect();
+    let logs: Vec<Option<HookLog>> = match hook_log_dir(ctx)? {
+        Some(dir) => commands
+            .iter()
+            .zip(&names)
+            .map(|(cmd, name)| HookLog::create(&dir, ctx, cmd, name).map(Some))
+            .collect::<anyhow::Result<_>>()?,
+        None => commands.iter().map(|_| None).collect(),
+    };
+
+    let worktree_path = ctx.worktree_path;
+    let next = AtomicUsize::new(0);
+    let failures = Mutex::new(Vec::new());
+    std::thread::scope(|s| {
+        for _ in 0..max_concurrency.clamp(1, commands.len()) {
+            s.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(cmd) = commands.get(i) else { break };
+                    let (prefix, log) = (&prefixes[i], &logs[i]);
+                    // Each `eprintln!` holds the stderr lock, so lines don't tear
+                    if let Err(err) = run_hook_command(
+                        worktree_path,
+                        &cmd.prepared,
+                        Some(&|line| {
+                            eprintln!("{prefix} {line}");
+                            if let Some(log) = log {
+                                log.write_line(line);
+                            }
+                        }),
+                    ) {
+                        failures
+                            .lock()
+                            .expect("failures lock poisoned")
+                            .push((i, err));
+                    }
+                }
+            });
+        }
+    });
+
+    let mut failures = failures.into_inner().expect("failures lock poisoned");
+    failures.sort_by_key(|(i, _)| *i);
+
+    let mut failures = failures.into_iter();
+    let Some((first_index, first_err)) = failures.next() else {
+        return Ok(());
+    };
+
+    // The first failure becomes the error; any others are reported as warnings
+    for (i, err) in failures {
+        let (err_msg, _) = command_error_parts(&err);
+        let message = match &commands[i].prepared.name {
+            Some(name) => cformat!("Command <bold>{name}</> failed: {err_msg}"),
+            None => format!("Command failed: {err_msg}"),
+        };
+        crate::output::print(warning_message(message))?;
+        if let Some(log) = &logs[i] {
+            crate::output::print(hint_message(log.hint()))?;
+        }
+    }
+    crate::output::flush()?;
+
+    let (error, exit_code) = command_error_parts(&first_err);
+    Err(WorktrunkError::HookCommandFailed {
+        hook_type,
+        command_name: commands[first_index].prepared.name.clone(),
+        error,
+        exit_code,
+        log_path: logs[first_index].as_ref().map(|log| log.path.clone()),
+    }
+    .into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
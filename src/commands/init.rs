@@ -1,15 +1,129 @@
-use worktrunk::shell;
-use worktrunk::styling::println;
+use std::path::Path;
 
-pub fn handle_init(shell: shell::Shell, cmd: String) -> Result<(), String> {
-    let init = shell::ShellInit::with_prefix(shell, cmd);
+use worktrunk::shell::{self, InitSections};
+use worktrunk::styling::{println, warning_message};
+
+use super::configure_shell::fish_completion_content;
+use crate::output;
+
+pub fn handle_init(
+    shell: shell::Shell,
+    cmd: String,
+    cache: bool,
+    sections: InitSections,
+) -> Result<(), String> {
+    let init = shell::ShellInit::with_prefix(shell, cmd).with_sections(sections);
+
+    if sections == InitSections::Completions {
+        // Fish completions are a separate file rather than part of the init script
+        if matches!(shell, shell::Shell::Fish) {
+            println!("{}", fish_completion_content(&init.cmd).trim_end());
+        } else if !shell.supports_completion() {
+            let _ = output::print(warning_message(format!(
+                "Completion not yet supported for {shell}; nothing to output"
+            )));
+        } else {
+            let completions = init
+                .generate()
+                .map_err(|e| format!("Failed to generate shell code: {}", e))?;
+            println!("{}", completions);
+        }
+        return Ok(());
+    }
 
     // Generate shell integration code (includes dynamic completion registration)
-    let integration_output = init
+    let mut integration_output = init
         .generate()
         .map_err(|e| format!("Failed to generate shell code: {}", e))?;
 
+    // Shells without completion support still get the wrapper; note what's missing.
+    // Csh evals the output as a single line, where a trailing comment isn't safe.
+    if sections == InitSections::All
+        && !shell.supports_completion()
+        && !matches!(shell, shell::Shell::Tcsh | shell::Shell::Csh)
+    {
+        integration_output.push_str(&format!(
+            "\n\n# Completion not yet supported for {shell}; only the {} wrapper is installed",
+            init.cmd
+        ));
+    }
+
+    // With --cache, emit a one-line snippet for the shell config that sources the
+    // cached copy directly, so wt only runs again when the file is missing.
+    // Any failure falls back to the full script so the shell still gets integration.
+    if cache
+        && let Ok(Some(path)) = shell.init_cache_path(&init.cmd)
+        && let Some(snippet) = source_snippet(shell, &init.cmd, &path)
+    {
+        match write_init_cache(&path, &integration_output) {
+            Ok(()) => {
+                println!("{snippet}");
+                return Ok(());
+            }
+            Err(e) => log::debug!("Failed to write init cache {}: {e}", path.display()),
+        }
+    }
+
     println!("{}", integration_output);
 
     Ok(())
 }
+
+/// Write the init script to the cache, skipping the write if it's already current.
+///
+/// The cache path is keyed by version, but dev builds share a version, so the
+/// content is compared too. Writes go through a temp file so a shell starting
+/// concurrently never sources a partially written script.
+fn write_init_cache(path: &Path, contents: &str) -> std::io::Result<()> {
+    if std::fs::read_to_string(path).is_ok_and(|existing| existing == contents) {
+        return Ok(());
+    }
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let temp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+    std::fs::write(&temp_path, contents)?;
+    std::fs::rename(&temp_path, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&temp_path);
+    })
+}
+
+/// Shell code that sources the cached script at `path`, regenerating it with
+/// `{cmd} config shell init <shell> --cache` only when the file is missing.
+///
+/// Returns `None` for shells whose init output isn't evaluated at startup.
+fn source_snippet(shell: shell::Shell, cmd: &str, path: &Path) -> Option<String> {
+    let path = path.to_string_lossy();
+    let regenerate = format!("{cmd} config shell init {shell} --cache");
+    let snippet = match shell {
+        shell::Shell::Bash | shell::Shell::Zsh => {
+            let path = shell_escape::unix::escape(path);
+            format!(
+                "if [ -f {path} ]; then source {path}; else eval \"$(command {regenerate})\"; fi"
+            )
+        }
+        shell::Shell::Fish => {
+            let path = shell_escape::unix::escape(path);
+            format!("if test -f {path}; source {path}; else; command {regenerate} | source; end")
+        }
+        // Csh evals the output as a single line, so there's no if/else block
+        shell::Shell::Tcsh | shell::Shell::Csh => {
+            let path = shell_escape::unix::escape(path);
+            format!("if ( ! -f {path} ) {regenerate} >& /dev/null; source {path}")
+        }
+        shell::Shell::Xonsh => {
+            let path = format!("'{}'", path.replace('\\', "\\\\").replace('\'', "\\'"));
+            format!(
+                "execx(open({path}).read() if __import__(\"os\").path.isfile({path}) else $({regenerate}))"
+            )
+        }
+        shell::Shell::PowerShell => {
+            let path = format!("'{}'", path.replace('\'', "''"));
+            format!(
+                "if (Test-Path -LiteralPath {path}) {{ . {path} }} else {{ & {regenerate} | Out-String | Invoke-Expression }}"
+            )
+        }
+        shell::Shell::Nu | shell::Shell::Elvish => return None,
+    };
+    Some(snippet)
+}
@@ -56,7 +56,7 @@ use crate::commands::is_worktree_at_expected_path_with;
 use super::ci_status::PrStatus;
 use super::model::{
     AheadBehind, BranchDiffTotals, CommitDetails, DisplayFields, GitOperationState, ItemKind,
-    ListItem, UpstreamStatus, WorktreeData,
+    ListItem, Staleness, UpstreamStatus, WorktreeData,
 };
 
 use super::model::WorkingTreeStatus;
@@ -200,6 +200,11 @@ pub(super) enum TaskResult {
         /// Whether the port is listening (None if no URL or couldn't parse port)
         active: Option<bool>,
     },
+    /// Merged/stale markers (only with --stale)
+    Staleness {
+        item_idx: usize,
+        staleness: Staleness,
+    },
 }
 
 impl TaskResult {
@@ -220,7 +225,8 @@ impl TaskResult {
             | TaskResult::UserMarker { item_idx, .. }
             | TaskResult::Upstream { item_idx, .. }
             | TaskResult::CiStatus { item_idx, .. }
-            | TaskResult::UrlStatus { item_idx, .. } => *item_idx,
+            | TaskResult::UrlStatus { item_idx, .. }
+            | TaskResult::Staleness { item_idx, .. } => *item_idx,
         }
     }
 }
@@ -384,6 +390,10 @@ fn apply_default(items: &mut [ListItem], status_contexts: &mut [StatusContext],
             // URL is set at item creation, only default url_active
             items[idx].url_active = None;
         }
+        TaskKind::Staleness => {
+            // Conservative: don't flag for cleanup if we couldn't check
+            items[idx].staleness = Some(Staleness::default());
+        }
     }
 }
 
@@ -588,6 +598,9 @@ fn drain_results(
                     item.url_active = active;
                 }
             }
+            TaskResult::Staleness { staleness, .. } => {
+                item.staleness = Some(staleness);
+            }
         }
 
         // Invoke callback (progressive mode re-renders rows, buffered mode does nothing)
@@ -632,6 +645,8 @@ fn worktree_branch_set(worktrees: &[Worktree]) -> std::collections::HashSet<&str
 /// When false, behavior depends on `render_table`:
 /// - If `render_table` is true: renders final table (buffered mode)
 /// - If `render_table` is false: returns data without rendering (JSON mode)
+///
+/// `stale_days` enables the merged/stale markers from `--stale`.
 #[allow(clippy::too_many_arguments)]
 pub fn collect(
     repo: &Repository,
@@ -640,6 +655,7 @@ pub fn collect(
     skip_tasks: &std::collections::HashSet<TaskKind>,
     show_progress: bool,
     render_table: bool,
+    stale_days: Option<u32>,
     config: &worktrunk::config::WorktrunkConfig,
 ) -> anyhow::Result<Option<super::model::ListData>> {
     use super::progressive_table::ProgressiveTable;
@@ -790,6 +806,7 @@ pub fn collect(
                 pr_status: None,
                 url: None,
                 url_active: None,
+                staleness: None,
                 status_symbols: None,
                 display: DisplayFields::default(),
                 kind: ItemKind::Worktree(Box::new(worktree_data)),
@@ -817,6 +834,10 @@ pub fn collect(
     if url_template.is_none() {
         effective_skip_tasks.insert(TaskKind::UrlStatus);
     }
+    // Merged/stale markers only with --stale
+    if stale_days.is_none() {
+        effective_skip_tasks.insert(TaskKind::Staleness);
+    }
 
     // Calculate layout from items (worktrees, local branches, and remote branches)
     let layout = super::layout::calculate_layout_from_basics(
@@ -833,6 +854,7 @@ pub fn collect(
     let options = super::collect_progressive_impl::CollectOptions {
         skip_tasks: effective_skip_tasks,
         url_template: url_template.clone(),
+        stale_days,
     };
 
     // Track expected results per item - populated as spawns are queued
@@ -1308,6 +1330,7 @@ pub fn build_worktree_item(
         pr_status: None,
         url: None,
         url_active: None,
+        staleness: None,
         status_symbols: None,
         display: DisplayFields::default(),
         kind: ItemKind::Worktree(Box::new(WorktreeData::from_worktree(
@@ -22,7 +22,7 @@ use worktrunk::git::{LineDiff, Repository, Worktree};
 use super::ci_status::PrStatus;
 use super::collect::{ExpectedResults, TaskError, TaskKind, TaskResult, detect_git_operation};
 use super::model::{
-    AheadBehind, BranchDiffTotals, CommitDetails, UpstreamStatus, WorkingTreeStatus,
+    AheadBehind, BranchDiffTotals, CommitDetails, Staleness, UpstreamStatus, WorkingTreeStatus,
 };
 
 // ============================================================================
@@ -45,6 +45,9 @@ pub struct CollectOptions {
     /// URL template from project config (e.g., "http://localhost:{{ branch | hash_port }}").
     /// Expanded per-item in task spawning (post-skeleton) to minimize time-to-skeleton.
     pub url_template: Option<String>,
+
+    /// Age threshold in days for `--stale`. Staleness is only computed when set.
+    pub stale_days: Option<u32>,
 }
 
 /// Context for task computation. Cloned and moved into spawned threads.
@@ -65,6 +68,8 @@ pub struct TaskContext {
     /// Expanded URL for this item (from project config template).
     /// UrlStatusTask uses this to check if the port is listening.
     pub item_url: Option<String>,
+    /// Age threshold in days for the stale marker (from `--stale`).
+    pub stale_days: Option<u32>,
 }
 
 impl TaskContext {
@@ -201,6 +206,10 @@ impl TaskSpawner {
         if !skip.contains(&TaskKind::UrlStatus) && ctx.item_url.is_some() {
             self.spawn::<UrlStatusTask>(scope, ctx);
         }
+        // Staleness only runs with a threshold from --stale
+        if !skip.contains(&TaskKind::Staleness) && ctx.stale_days.is_some() {
+            self.spawn::<StalenessTask>(scope, ctx);
+        }
     }
 }
 
@@ -682,6 +691,45 @@ pub(crate) fn parse_port_from_url(url: &str) -> Option<u16> {
     port_str.parse().ok()
 }
 
+/// Task 14 (--stale only): Merged/stale markers for cleanup.
+///
+/// Unlike the integration checks, `merged` compares against the local default
+/// branch, matching `git branch --merged <default>`. The default branch itself
+/// is never flagged.
+pub struct StalenessTask;
+
+impl Task for StalenessTask {
+    const KIND: TaskKind = TaskKind::Staleness;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        let default_branch = ctx.require_default_branch(Self::KIND)?;
+        let stale_days = ctx
+            .stale_days
+            .ok_or_else(|| ctx.error(Self::KIND, "no stale threshold"))?;
+        if ctx.branch.as_deref() == Some(default_branch) {
+            return Ok(TaskResult::Staleness {
+                item_idx: ctx.item_idx,
+                staleness: Staleness::default(),
+            });
+        }
+
+        let repo = ctx.repo();
+        let merged = repo
+            .is_ancestor(&ctx.commit_sha, default_branch)
+            .map_err(|e| ctx.error(Self::KIND, e))?;
+        let timestamp = repo
+            .commit_timestamp(&ctx.commit_sha)
+            .map_err(|e| ctx.error(Self::KIND, e))?;
+        let age_secs = worktrunk::utils::get_now() as i64 - timestamp;
+        let stale = age_secs > i64::from(stale_days) * 24 * 60 * 60;
+
+        Ok(TaskResult::Staleness {
+            item_idx: ctx.item_idx,
+            staleness: Staleness { merged, stale },
+        })
+    }
+}
+
 // ============================================================================
 // Collection Entry Points
 // ============================================================================
@@ -757,6 +805,7 @@ pub fn collect_worktree_progressive(
         target: Some(target.to_string()),
         item_idx,
         item_url,
+        stale_days: options.stale_days,
     };
 
     collect_progressive(ctx, true, options, tx, expected_results);
@@ -793,6 +842,7 @@ pub fn collect_branch_progressive(
         target: Some(target.to_string()),
         item_idx,
         item_url: None,
+        stale_days: options.stale_days,
     };
 
     collect_progressive(ctx, false, options, tx, expected_results);
@@ -13,6 +13,7 @@ pub enum ColumnKind {
     Upstream,
     Url, // Dev server URL from project config template
     CiStatus,
+    Stale, // Merged/stale markers from `--stale`
     Commit,
     Time,
     Message,
@@ -32,6 +33,7 @@ impl ColumnKind {
             ColumnKind::Url => "URL",
             ColumnKind::Time => "Age",
             ColumnKind::CiStatus => "CI",
+            ColumnKind::Stale => "Stale",
             ColumnKind::Commit => "Commit",
             ColumnKind::Message => "Message",
         }
@@ -91,9 +93,10 @@ pub const COLUMN_SPECS: &[ColumnSpec] = &[
     ColumnSpec::new(ColumnKind::Upstream, 7, None),
     ColumnSpec::new(ColumnKind::Url, 8, Some(TaskKind::UrlStatus)),
     ColumnSpec::new(ColumnKind::CiStatus, 9, Some(TaskKind::CiStatus)),
-    ColumnSpec::new(ColumnKind::Commit, 10, None),
-    ColumnSpec::new(ColumnKind::Time, 11, None),
-    ColumnSpec::new(ColumnKind::Message, 12, None),
+    ColumnSpec::new(ColumnKind::Stale, 10, Some(TaskKind::Staleness)),
+    ColumnSpec::new(ColumnKind::Commit, 11, None),
+    ColumnSpec::new(ColumnKind::Time, 12, None),
+    ColumnSpec::new(ColumnKind::Message, 13, None),
 ];
 
 pub fn column_display_index(kind: ColumnKind) -> usize {
@@ -122,6 +125,7 @@ mod tests {
             ColumnKind::Upstream,
             ColumnKind::Url,
             ColumnKind::CiStatus,
+            ColumnKind::Stale,
             ColumnKind::Commit,
             ColumnKind::Time,
             ColumnKind::Message,
@@ -149,11 +153,18 @@ mod tests {
             .unwrap();
         assert_eq!(ci_status.requires_task, Some(TaskKind::CiStatus));
 
+        let stale = COLUMN_SPECS
+            .iter()
+            .find(|c| c.kind == ColumnKind::Stale)
+            .unwrap();
+        assert_eq!(stale.requires_task, Some(TaskKind::Staleness));
+
         // All other columns should not require a background task to render
         for spec in COLUMN_SPECS {
             if spec.kind != ColumnKind::BranchDiff
                 && spec.kind != ColumnKind::Url
                 && spec.kind != ColumnKind::CiStatus
+                && spec.kind != ColumnKind::Stale
             {
                 assert!(
                     spec.requires_task.is_none(),
@@ -220,6 +231,7 @@ mod tests {
             ColumnKind::Upstream,
             ColumnKind::Url,
             ColumnKind::CiStatus,
+            ColumnKind::Stale,
             ColumnKind::Commit,
             ColumnKind::Time,
             ColumnKind::Message,
@@ -90,6 +90,14 @@ pub struct JsonItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url_active: Option<bool>,
 
+    /// HEAD is merged into the default branch (only with --stale)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merged: Option<bool>,
+
+    /// HEAD commit is older than the --stale threshold (only with --stale)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stale: Option<bool>,
+
     /// Pre-formatted statusline for statusline tools (tmux, starship)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub statusline: Option<String>,
@@ -352,6 +360,8 @@ impl JsonItem {
             ci,
             url: item.url.clone(),
             url_active: item.url_active,
+            merged: item.staleness.map(|s| s.merged),
+            stale: item.staleness.map(|s| s.stale),
             statusline,
             symbols,
         }
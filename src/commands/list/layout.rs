@@ -96,7 +96,7 @@
 //!
 //! Exceptions that we can compute instantly from items:
 //! - `path`: true only if any worktree has `branch_worktree_mismatch` (computed from items)
-//! - `branch_diff`/`ci_status`: false if their required task is skipped
+//! - `branch_diff`/`ci_status`/`stale`: false if their required task is skipped
 //!
 //! Other columns (status, working_diff, ahead_behind, upstream) require expensive git operations,
 //! so we assume they have data until proven otherwise.
@@ -242,6 +242,7 @@ pub struct ColumnWidths {
     pub time: usize,
     pub url: usize,
     pub ci_status: usize,
+    pub stale: usize,
     pub message: usize,
     pub ahead_behind: DiffWidths,
     pub working_diff: DiffWidths,
@@ -259,6 +260,7 @@ pub struct ColumnDataFlags {
     pub upstream: bool,
     pub url: bool,
     pub ci_status: bool,
+    pub stale: bool,
     pub path: bool, // True if any worktree has branch_worktree_mismatch
 }
 
@@ -411,6 +413,7 @@ impl ColumnKind {
             ColumnKind::Url => flags.url,
             ColumnKind::Time => true,
             ColumnKind::CiStatus => flags.ci_status,
+            ColumnKind::Stale => flags.stale,
             ColumnKind::Commit => true,
             ColumnKind::Message => true,
         }
@@ -430,6 +433,7 @@ impl ColumnKind {
             ColumnKind::Time => ColumnIdeal::text(widths.time),
             ColumnKind::Url => ColumnIdeal::text(widths.url),
             ColumnKind::CiStatus => ColumnIdeal::text(widths.ci_status),
+            ColumnKind::Stale => ColumnIdeal::text(widths.stale),
             ColumnKind::Commit => ColumnIdeal::text(commit_width),
             ColumnKind::Message => None,
             ColumnKind::WorkingDiff => {
@@ -577,6 +581,7 @@ fn build_estimated_widths(
     let upstream_fixed = fit_header(ColumnKind::Upstream.header(), 7); // "↑99 ↓99"
     let age_estimate = 4; // "11mo" (short format)
     let ci_estimate = fit_header(ColumnKind::CiStatus.header(), 1); // Single indicator symbol
+    let stale_fixed = fit_header(ColumnKind::Stale.header(), 6); // "merged"
 
     // Assume columns will have data (better to show and hide than to not show).
     // This is a limitation of progressive mode - we can't know which columns have data
@@ -584,7 +589,7 @@ fn build_estimated_widths(
     //
     // Exceptions that we can compute instantly from items:
     // - path: true only if any worktree has branch_worktree_mismatch
-    // - branch_diff/ci_status: false if their required task is skipped
+    // - branch_diff/ci_status/stale: false if their required task is skipped
     let data_flags = ColumnDataFlags {
         status: true,
         working_diff: true,
@@ -593,6 +598,7 @@ fn build_estimated_widths(
         upstream: true,
         url: !skip_tasks.contains(&TaskKind::UrlStatus),
         ci_status: !skip_tasks.contains(&TaskKind::CiStatus),
+        stale: !skip_tasks.contains(&TaskKind::Staleness),
         path: has_branch_worktree_mismatch,
     };
 
@@ -610,6 +616,7 @@ fn build_estimated_widths(
         time: age_estimate,
         url: url_estimate,
         ci_status: ci_estimate,
+        stale: stale_fixed,
         message: 50, // Will be flexible during allocation
         // Commit counts (Arrows): compact notation, 2 digits covers up to 99
         ahead_behind: DiffWidths {
@@ -969,6 +976,7 @@ mod tests {
             upstream: true,
             url: true,
             ci_status: true,
+            stale: true,
             path: true,
         };
         let all_false = ColumnDataFlags {
@@ -979,6 +987,7 @@ mod tests {
             upstream: false,
             url: false,
             ci_status: false,
+            stale: false,
             path: false,
         };
 
@@ -1004,6 +1013,8 @@ mod tests {
         assert!(!ColumnKind::Url.has_data(&all_false));
         assert!(ColumnKind::CiStatus.has_data(&all_true));
         assert!(!ColumnKind::CiStatus.has_data(&all_false));
+        assert!(ColumnKind::Stale.has_data(&all_true));
+        assert!(!ColumnKind::Stale.has_data(&all_false));
         assert!(ColumnKind::Path.has_data(&all_true));
         assert!(!ColumnKind::Path.has_data(&all_false));
     }
@@ -1076,6 +1087,7 @@ mod tests {
             time: 4,
             url: 0,
             ci_status: 2,
+            stale: 6,
             message: 50,
             ahead_behind: DiffWidths {
                 total: 7,
@@ -1233,6 +1245,7 @@ mod tests {
             pr_status: None,
             url: None,
             url_active: None,
+            staleness: None,
             status_symbols: Some(StatusSymbols::default()),
             display: DisplayFields::default(),
             kind: ItemKind::Worktree(Box::new(WorktreeData {
@@ -1331,6 +1344,7 @@ mod tests {
             pr_status: None,
             url: None,
             url_active: None,
+            staleness: None,
             status_symbols: Some(StatusSymbols::default()),
             display: DisplayFields::default(),
             kind: ItemKind::Worktree(Box::new(WorktreeData {
@@ -123,6 +123,7 @@ pub(crate) mod columns;
 mod json_output;
 pub(crate) mod layout;
 pub mod model;
+mod porcelain_output;
 pub mod progressive;
 mod progressive_table;
 pub(crate) mod render;
@@ -141,10 +142,11 @@ pub use collect::{CollectOptions, build_worktree_item, populate_item};
 pub use model::StatuslineSegment;
 
 pub fn handle_list(
-    format: crate::OutputFormat,
+    format: crate::ListFormat,
     show_branches: bool,
     show_remotes: bool,
     show_full: bool,
+    stale_days: Option<u32>,
     render_mode: RenderMode,
     config: &worktrunk::config::WorktrunkConfig,
 ) -> anyhow::Result<()> {
@@ -171,12 +173,13 @@ pub fn handle_list(
 
     // Progressive rendering only for table format with Progressive mode
     let show_progress = match format {
-        crate::OutputFormat::Table => render_mode == RenderMode::Progressive,
-        crate::OutputFormat::Json => false, // JSON never shows progress
+        crate::ListFormat::Table => render_mode == RenderMode::Progressive,
+        // Machine-readable formats never show progress
+        crate::ListFormat::Json | crate::ListFormat::Porcelain => false,
     };
 
     // Render table in collect() for all table modes (progressive + buffered)
-    let render_table = matches!(format, crate::OutputFormat::Table);
+    let render_table = matches!(format, crate::ListFormat::Table);
 
     let list_data = collect::collect(
         &repo,
@@ -185,6 +188,7 @@ pub fn handle_list(
         &skip_tasks,
         show_progress,
         render_table,
+        stale_days,
         config,
     )?;
 
@@ -193,14 +197,20 @@ pub fn handle_list(
     };
 
     match format {
-        crate::OutputFormat::Json => {
+        crate::ListFormat::Json => {
             // Convert to new JSON structure
             let json_items = json_output::to_json_items(&items);
             let json =
                 serde_json::to_string_pretty(&json_items).context("Failed to serialize to JSON")?;
             crate::output::stdout(json)?;
         }
-        crate::OutputFormat::Table => {
+        crate::ListFormat::Porcelain => {
+            let porcelain = porcelain_output::format_items(&items);
+            if !porcelain.is_empty() {
+                crate::output::stdout(porcelain)?;
+            }
+        }
+        crate::ListFormat::Table => {
             // Table and summary already rendered in collect() for all modes
             // Nothing to do here - collect() handles the complete table rendering
         }
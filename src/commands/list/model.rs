@@ -279,6 +279,15 @@ pub struct BranchDiffTotals {
     pub diff: LineDiff,
 }
 
+/// Cleanup markers computed with `wt list --stale`
+#[derive(serde::Serialize, Default, Copy, Clone, Debug)]
+pub struct Staleness {
+    /// HEAD is reachable from the default branch (as in `git branch --merged`)
+    pub merged: bool,
+    /// HEAD commit is older than the `--stale` threshold
+    pub stale: bool,
+}
+
 #[derive(serde::Serialize, Default, Clone, Debug)]
 pub struct UpstreamStatus {
     #[serde(rename = "upstream_remote")]
@@ -369,6 +378,10 @@ pub struct ListItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url_active: Option<bool>,
 
+    /// Merged/stale markers - None unless `--stale` was requested
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub staleness: Option<Staleness>,
+
     /// Git status symbols - None until all dependencies are ready.
     /// Note: This field is not serialized directly. JSON output converts to JsonItem first.
     #[serde(skip)]
@@ -407,6 +420,7 @@ impl ListItem {
             pr_status: None,
             url: None,
             url_active: None,
+            staleness: None,
             status_symbols: None,
             display: DisplayFields::default(),
             kind: ItemKind::Branch,
@@ -0,0 +1,124 @@
+//! Porcelain output for `wt list --format=porcelain`
+//!
+//! A stable, line-based format modeled on `git worktree list --porcelain`:
+//! one record per item, one `key value` attribute per line, records separated
+//! by a blank line. Scripts that don't want a JSON parser can split on blank
+//! lines and match on the first word of each line.
+//!
+//! ```text
+//! worktree /path/to/repo
+//! HEAD 1a2b3c4d...
+//! branch main
+//!
+//! worktree /path/to/repo.feature
+//! HEAD 9f8e7d6c...
+//! detached
+//! locked reason text
+//! ```
+
+use super::model::{ItemKind, ListItem};
+
+/// Format all items as porcelain records (no trailing newline).
+pub fn format_items(items: &[ListItem]) -> String {
+    items
+        .iter()
+        .map(format_item)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Format a single item as a porcelain record.
+fn format_item(item: &ListItem) -> String {
+    let worktree_data = match &item.kind {
+        ItemKind::Worktree(data) => Some(data.as_ref()),
+        ItemKind::Branch => None,
+    };
+
+    let mut lines = Vec::new();
+
+    if let Some(data) = worktree_data {
+        lines.push(format!("worktree {}", data.path.display()));
+    }
+
+    lines.push(format!("HEAD {}", item.head));
+
+    match &item.branch {
+        Some(branch) => lines.push(format!("branch {branch}")),
+        None => lines.push("detached".to_string()),
+    }
+
+    if let Some(data) = worktree_data {
+        // Like git, the reason follows the key only when one was recorded
+        for (key, reason) in [("locked", &data.locked), ("prunable", &data.prunable)] {
+            match reason.as_deref() {
+                Some("") => lines.push(key.to_string()),
+                Some(reason) => lines.push(format!("{key} {reason}")),
+                None => {}
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::list::model::WorktreeData;
+    use std::path::PathBuf;
+
+    fn worktree_item(branch: Option<&str>, data: WorktreeData) -> ListItem {
+        let mut item = ListItem::new_branch("abc123".to_string(), branch.unwrap_or("").to_string());
+        item.branch = branch.map(str::to_string);
+        item.kind = ItemKind::Worktree(Box::new(data));
+        item
+    }
+
+    #[test]
+    fn test_format_worktree_with_branch() {
+        let data = WorktreeData {
+            path: PathBuf::from("/repo"),
+            ..Default::default()
+        };
+        let item = worktree_item(Some("main"), data);
+        assert_eq!(
+            format_item(&item),
+            "worktree /repo\nHEAD abc123\nbranch main"
+        );
+    }
+
+    #[test]
+    fn test_format_detached_locked_prunable() {
+        let data = WorktreeData {
+            path: PathBuf::from("/repo.detached"),
+            detached: true,
+            locked: Some(String::new()),
+            prunable: Some("gitdir file points to non-existent location".to_string()),
+            ..Default::default()
+        };
+        let item = worktree_item(None, data);
+        assert_eq!(
+            format_item(&item),
+            "worktree /repo.detached\nHEAD abc123\ndetached\nlocked\n\
+             prunable gitdir file points to non-existent location"
+        );
+    }
+
+    #[test]
+    fn test_format_branch_without_worktree() {
+        let item = ListItem::new_branch("def456".to_string(), "feature".to_string());
+        assert_eq!(format_item(&item), "HEAD def456\nbranch feature");
+    }
+
+    #[test]
+    fn test_format_items_separated_by_blank_line() {
+        let items = vec![
+            ListItem::new_branch("a".to_string(), "one".to_string()),
+            ListItem::new_branch("b".to_string(), "two".to_string()),
+        ];
+        assert_eq!(
+            format_items(&items),
+            "HEAD a\nbranch one\n\nHEAD b\nbranch two"
+        );
+    }
+}
@@ -578,6 +578,17 @@ impl ColumnLayout {
                     }
                 }
             }
+            ColumnKind::Stale => {
+                let mut cell = StyledLine::new();
+                // Merged takes precedence: it's the stronger signal for cleanup
+                match ctx.item.staleness {
+                    None => cell.push_styled("⋯", Style::new().dimmed()),
+                    Some(staleness) if staleness.merged => cell.push_raw("merged"),
+                    Some(staleness) if staleness.stale => cell.push_raw("stale"),
+                    Some(_) => {}
+                }
+                cell
+            }
             ColumnKind::Commit => {
                 let mut cell = StyledLine::new();
                 cell.push_styled(ctx.short_head().to_string(), Style::new().dimmed());
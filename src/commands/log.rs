@@ -0,0 +1,101 @@
+//! List commits unique to the current branch.
+//!
+//! `wt log` shows what the current branch adds on top of the default branch
+//! (`git log <default>..HEAD`). On the default branch itself there is nothing
+//! to show, which prints no commits rather than an error.
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+
+use crate::cli::OutputFormat;
+use crate::display::format_relative_time_short;
+use crate::output;
+
+/// Field separator for `git log --format` (ASCII unit separator)
+const FIELD_SEP: char = '\x1f';
+
+/// A commit on the current branch that isn't on the default branch
+struct LogEntry {
+    sha: String,
+    short_sha: String,
+    subject: String,
+    author: String,
+    /// Strict ISO 8601 author date
+    date: String,
+    /// Author date as a Unix timestamp
+    timestamp: i64,
+}
+
+/// Handle `wt log`.
+pub fn handle_log(oneline: bool, format: OutputFormat) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let entries = gather(&repo)?;
+
+    match format {
+        OutputFormat::Json => render_json(&entries),
+        OutputFormat::Table => render_table(&entries, oneline),
+    }
+}
+
+fn gather(repo: &Repository) -> anyhow::Result<Vec<LogEntry>> {
+    let default_branch = repo.default_branch()?;
+    let range = format!("{default_branch}..HEAD");
+    let format = ["%H", "%h", "%s", "%an", "%aI", "%at"].join("%x1f");
+    let output = repo.run_command(&["log", &format!("--format={format}"), &range])?;
+
+    output
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(parse_entry)
+        .collect()
+}
+
+fn parse_entry(line: &str) -> anyhow::Result<LogEntry> {
+    let fields: Vec<&str> = line.split(FIELD_SEP).collect();
+    let [sha, short_sha, subject, author, date, timestamp] = fields[..] else {
+        anyhow::bail!("Unexpected git log output: {line}");
+    };
+
+    Ok(LogEntry {
+        sha: sha.to_string(),
+        short_sha: short_sha.to_string(),
+        subject: subject.to_string(),
+        author: author.to_string(),
+        date: date.to_string(),
+        timestamp: timestamp.parse()?,
+    })
+}
+
+fn render_json(entries: &[LogEntry]) -> anyhow::Result<()> {
+    let output: Vec<_> = entries
+        .iter()
+        .map(|entry| {
+            serde_json::json!({
+                "sha": entry.sha,
+                "subject": entry.subject,
+                "author": entry.author,
+                "date": entry.date,
+            })
+        })
+        .collect();
+
+    output::stdout(serde_json::to_string_pretty(&output)?)?;
+    Ok(())
+}
+
+fn render_table(entries: &[LogEntry], oneline: bool) -> anyhow::Result<()> {
+    for entry in entries {
+        let LogEntry {
+            short_sha, subject, ..
+        } = entry;
+        let line = if oneline {
+            cformat!("<dim>{short_sha}</> {subject}")
+        } else {
+            let author = &entry.author;
+            let age = format_relative_time_short(entry.timestamp);
+            cformat!("<dim>{short_sha}</> {subject} <dim>({author}, {age})</>")
+        };
+        output::stdout(line)?;
+    }
+    Ok(())
+}
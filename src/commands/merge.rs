@@ -1,9 +1,11 @@
 use std::path::Path;
 
+use anyhow::Context;
+use color_print::cformat;
 use worktrunk::HookType;
-use worktrunk::config::ProjectConfig;
+use worktrunk::config::{ProjectConfig, WorktrunkConfig};
 use worktrunk::git::Repository;
-use worktrunk::styling::info_message;
+use worktrunk::styling::{hint_message, info_message, success_message, warning_message};
 
 use super::command_approval::approve_command_batch;
 use super::command_executor::CommandContext;
@@ -26,11 +28,16 @@ pub struct MergeOptions<'a> {
     pub verify: bool,
     pub yes: bool,
     pub stage_mode: super::commit::StageMode,
+    /// Stash uncommitted changes for the merge and restore them afterward
+    pub autostash: bool,
 }
 
 /// Collect all commands that will be executed during merge.
 ///
 /// Returns (commands, project_identifier) for batch approval.
+///
+/// `verify` only gates the checks that run before the merge lands (pre-commit,
+/// pre-merge) and the removal hooks; post-merge commands are always collected.
 fn collect_merge_commands(
     repo: &Repository,
     commit: bool,
@@ -52,11 +59,11 @@ fn collect_merge_commands(
 
     if verify {
         hooks.push(HookType::PreMerge);
-        hooks.push(HookType::PostMerge);
-        if will_remove {
-            hooks.push(HookType::PreRemove);
-            hooks.push(HookType::PostSwitch);
-        }
+    }
+    hooks.push(HookType::PostMerge);
+    if verify && will_remove {
+        hooks.push(HookType::PreRemove);
+        hooks.push(HookType::PostSwitch);
     }
 
     all_commands.extend(collect_commands_for_hooks(&project_config, &hooks));
@@ -75,6 +82,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         verify,
         yes,
         stage_mode,
+        autostash,
     } = opts;
 
     let env = CommandEnv::for_action("merge")?;
@@ -83,8 +91,11 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     // Merge requires being on a branch (can't merge from detached HEAD)
     let current_branch = env.require_branch("merge")?.to_string();
 
+    // With --autostash, uncommitted changes are set aside rather than committed
+    let stashing = autostash && repo.is_dirty()?;
+
     // Validate --no-commit: requires clean working tree
-    if !commit && repo.is_dirty()? {
+    if !commit && !stashing && repo.is_dirty()? {
         return Err(worktrunk::git::GitError::UncommittedChanges {
             action: Some("merge with --no-commit".into()),
             branch: Some(current_branch.clone()),
@@ -103,17 +114,20 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     // When current == target or we're in the main worktree, disable remove (can't remove it)
     let in_main = !repo.is_in_worktree().unwrap_or(false);
     let on_target = current_branch == target_branch;
-    let remove_effective = remove && !on_target && !in_main;
+    // Stashed changes return to this worktree, so it has to stay
+    let remove_effective = remove && !on_target && !in_main && !stashing;
 
     // Collect and approve all commands upfront for batch permission request
     let (all_commands, project_id) =
-        collect_merge_commands(repo, commit, verify, remove_effective)?;
+        collect_merge_commands(repo, commit && !stashing, verify, remove_effective)?;
 
     // Approve all commands in a single batch (shows templates, not expanded values)
     let approved = approve_command_batch(&all_commands, &project_id, config, yes, false)?;
 
     // If commands were declined, skip hooks but continue with merge
-    // Shadow verify to gate all subsequent hook execution on approval
+    // Shadow verify to gate all subsequent hook execution on approval;
+    // post-merge is gated on approval alone since --no-verify doesn't skip it
+    let skip_pre_merge = !verify;
     let verify = if !approved {
         crate::output::print(info_message("Commands declined, continuing merge"))?;
         false
@@ -121,77 +135,111 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         verify
     };
 
-    // Handle uncommitted changes (skip if --no-commit) - track whether commit occurred
-    let committed = if commit && repo.is_dirty()? {
-        if squash_enabled {
-            false // Squash path handles staging and committing
-        } else {
-            let ctx = env.context(yes);
-            let mut options = CommitOptions::new(&ctx);
-            options.target_branch = Some(&target_branch);
-            options.no_verify = !verify;
-            options.stage_mode = stage_mode;
-            options.warn_about_untracked = stage_mode == super::commit::StageMode::All;
-            options.show_no_squash_note = true;
-
-            options.commit()?;
-            true // Committed directly
-        }
+    let stash = if stashing {
+        let stash = repo.stash_push("wt merge --autostash")?;
+        crate::output::print(info_message("Stashed uncommitted changes"))?;
+        stash
     } else {
-        false // No dirty changes or --no-commit
+        None
     };
 
-    // Squash commits if enabled - track whether squashing occurred
-    let squashed = if squash_enabled {
-        matches!(
-            super::step_commands::handle_squash(
-                Some(&target_branch),
-                yes,
-                !verify, // skip_pre_commit when !verify
-                stage_mode
-            )?,
-            super::step_commands::SquashResult::Squashed
-        )
-    } else {
-        false
-    };
+    // Restore stashed changes whether or not the merge succeeds
+    let merged = (|| -> anyhow::Result<()> {
+        // Remember where the branch started so `wt merge --abort` can return to it
+        record_merge_start(repo, &current_branch)?;
+
+        // Handle uncommitted changes (skip if --no-commit) - track whether commit occurred
+        let committed = if commit && repo.is_dirty()? {
+            if squash_enabled {
+                false // Squash path handles staging and committing
+            } else {
+                let ctx = env.context(yes);
+                let mut options = CommitOptions::new(&ctx);
+                options.target_branch = Some(&target_branch);
+                options.no_verify = !verify;
+                options.stage_mode = stage_mode;
+                options.warn_about_untracked = stage_mode == super::commit::StageMode::All;
+                options.show_no_squash_note = true;
+
+                options.commit()?;
+                true // Committed directly
+            }
+        } else {
+            false // No dirty changes or --no-commit
+        };
 
-    // Rebase onto target - track whether rebasing occurred
-    let rebased = if rebase {
-        // Auto-rebase onto target
-        matches!(
-            super::step_commands::handle_rebase(Some(&target_branch))?,
-            super::step_commands::RebaseResult::Rebased
-        )
-    } else {
-        // --no-rebase: verify already rebased, fail if not
-        if !repo.is_rebased_onto(&target_branch)? {
-            return Err(worktrunk::git::GitError::NotRebased {
-                target_branch: target_branch.clone(),
+        // Squash commits if enabled - track whether squashing occurred
+        let squashed = if squash_enabled {
+            matches!(
+                super::step_commands::handle_squash(
+                    Some(&target_branch),
+                    yes,
+                    !verify, // skip_pre_commit when !verify
+                    stage_mode
+                )?,
+                super::step_commands::SquashResult::Squashed
+            )
+        } else {
+            false
+        };
+
+        if committed || squashed {
+            record_merge_commit(repo, &current_branch)?;
+        }
+
+        // Rebase onto target - track whether rebasing occurred
+        let rebased = if rebase {
+            // Auto-rebase onto target
+            matches!(
+                super::step_commands::handle_rebase(Some(&target_branch))?,
+                super::step_commands::RebaseResult::Rebased
+            )
+        } else {
+            // --no-rebase: verify already rebased, fail if not
+            if !repo.is_rebased_onto(&target_branch)? {
+                return Err(worktrunk::git::GitError::NotRebased {
+                    target_branch: target_branch.clone(),
+                }
+                .into());
             }
-            .into());
+            false // Already rebased, no rebase occurred
+        };
+
+        // Run pre-merge checks unless --no-verify was specified
+        // Do this after commit/squash/rebase to validate the final state that will be pushed
+        if verify {
+            let ctx = env.context(yes);
+            let project_config = repo.load_project_config()?.unwrap_or_default();
+            run_pre_merge_commands(&project_config, &ctx, &target_branch, None, &[])?;
+        } else if skip_pre_merge && has_pre_merge_commands(repo, config)? {
+            crate::output::print(warning_message(cformat!(
+                "Skipping pre-merge checks (<bright-black>--no-verify</>)"
+            )))?;
         }
-        false // Already rebased, no rebase occurred
-    };
 
-    // Run pre-merge checks unless --no-verify was specified
-    // Do this after commit/squash/rebase to validate the final state that will be pushed
-    if verify {
-        let ctx = env.context(yes);
-        let project_config = repo.load_project_config()?.unwrap_or_default();
-        run_pre_merge_commands(&project_config, &ctx, &target_branch, None, &[])?;
+        // Fast-forward push to target branch with commit/squash/rebase info for consolidated message
+        handle_push(
+            Some(&target_branch),
+            "Merged to",
+            Some(MergeOperations {
+                committed,
+                squashed,
+                rebased,
+            }),
+        )?;
+        // The target now has the branch, so there's nothing left to abort
+        clear_merge_state(repo, &current_branch)?;
+        Ok(())
+    })();
+
+    if merged.is_err() {
+        // Lets `wt merge --abort` notice commits made after this point
+        record_merge_end(repo, &current_branch)?;
     }
-
-    // Fast-forward push to target branch with commit/squash/rebase info for consolidated message
-    handle_push(
-        Some(&target_branch),
-        "Merged to",
-        Some(MergeOperations {
-            committed,
-            squashed,
-            rebased,
-        }),
-    )?;
+    if let Some(stash) = &stash {
+        restore_stash(repo, stash)?;
+    }
+    merged?;
 
     // Destination: prefer the target branch's worktree; fall back to home path.
     let destination_path = match target_worktree_path {
@@ -237,6 +285,8 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
             "Worktree preserved (main worktree)"
         } else if on_target {
             "Worktree preserved (already on target branch)"
+        } else if stashing {
+            "Worktree preserved (--autostash)"
         } else {
             "Worktree preserved (--no-remove)"
         };
@@ -244,7 +294,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         crate::output::flush()?;
     }
 
-    if verify {
+    if approved {
         // Execute post-merge commands in the destination worktree
         // This runs after cleanup so the context is clear to the user
         // Create a fresh Repository instance at the destination (the old repo may be invalid)
@@ -273,6 +323,192 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Pop the stash made by `--autostash`, leaving it in place if that isn't safe.
+///
+/// Skipped while a rebase or merge is unfinished; a conflicting pop keeps the
+/// stash entry. Either way the user is told which entry holds their changes.
+fn restore_stash(repo: &Repository, stash: &str) -> anyhow::Result<()> {
+    let in_progress = repo.is_rebasing()? || repo.is_merging()?;
+    let popped = !in_progress && repo.stash_pop(stash).is_ok();
+    if popped {
+        crate::output::print(success_message("Restored stashed changes"))?;
+        return Ok(());
+    }
+
+    let entry = repo.stash_ref(stash)?.unwrap_or_else(|| stash.to_string());
+    let reason = if in_progress {
+        "the merge is unfinished"
+    } else {
+        "they conflict"
+    };
+    crate::output::print(warning_message(cformat!(
+        "Stashed changes not restored because {reason}; they're kept in <bold>{entry}</>"
+    )))?;
+    crate::output::print(hint_message(cformat!(
+        "To restore them, run <bright-black>git stash pop {entry}</>"
+    )))?;
+    Ok(())
+}
+
+/// Escape `branch` into a single ref path component
+///
+/// Without this, the refs for `feature` and `feature/x` would collide: git
+/// can't hold both `refs/wt-merge/head/feature` and `refs/wt-merge/head/feature/x`.
+fn merge_ref_key(branch: &str) -> String {
+    branch.replace('%', "%25").replace('/', "%2F")
+}
+
+/// Ref holding where `branch` pointed when `wt merge` started
+fn merge_start_ref(branch: &str) -> String {
+    format!("refs/wt-merge/head/{}", merge_ref_key(branch))
+}
+
+/// Ref holding the commit `wt merge` created on `branch` by committing or squashing
+fn merge_commit_ref(branch: &str) -> String {
+    format!("refs/wt-merge/commit/{}", merge_ref_key(branch))
+}
+
+/// Ref holding where `branch` pointed when `wt merge` stopped on a failure
+fn merge_end_ref(branch: &str) -> String {
+    format!("refs/wt-merge/end/{}", merge_ref_key(branch))
+}
+
+fn record_merge_start(repo: &Repository, branch: &str) -> anyhow::Result<()> {
+    // A commit ref left by an earlier, failed run doesn't belong to this one
+    clear_merge_state(repo, branch)?;
+    repo.run_command(&["update-ref", &merge_start_ref(branch), "HEAD"])
+        .context("Failed to record merge start")?;
+    Ok(())
+}
+
+fn record_merge_commit(repo: &Repository, branch: &str) -> anyhow::Result<()> {
+    repo.run_command(&["update-ref", &merge_commit_ref(branch), "HEAD"])
+        .context("Failed to record merge commit")?;
+    Ok(())
+}
+
+/// Record where a failed `wt merge` left the branch
+///
+/// Uses the branch ref rather than `HEAD`, which a conflicted rebase leaves detached.
+fn record_merge_end(repo: &Repository, branch: &str) -> anyhow::Result<()> {
+    repo.run_command(&[
+        "update-ref",
+        &merge_end_ref(branch),
+        &format!("refs/heads/{branch}"),
+    ])
+    .context("Failed to record where the merge stopped")?;
+    Ok(())
+}
+
+fn clear_merge_state(repo: &Repository, branch: &str) -> anyhow::Result<()> {
+    for ref_name in [
+        merge_start_ref(branch),
+        merge_commit_ref(branch),
+        merge_end_ref(branch),
+    ] {
+        repo.run_command(&["update-ref", "-d", &ref_name])?;
+    }
+    Ok(())
+}
+
+fn resolve_ref(repo: &Repository, ref_name: &str) -> Option<String> {
+    repo.run_command(&["rev-parse", "-q", "--verify", ref_name])
+        .ok()
+        .map(|sha| sha.trim().to_string())
+}
+
+/// Handle `wt merge --abort`
+///
+/// Aborts a git merge or rebase left in progress, then moves the branch back
+/// to where it was when `wt merge` started. If `wt merge` committed or
+/// squashed, the changes in that commit come back as uncommitted changes.
+///
+/// Refuses if the branch has moved since `wt merge` stopped, so commits made
+/// afterwards are never discarded.
+pub fn handle_merge_abort() -> anyhow::Result<()> {
+    let repo = Repository::current();
+
+    // A conflicted rebase leaves HEAD detached, so this comes before finding the branch
+    let mut aborted = false;
+    if repo.is_rebasing()? {
+        repo.run_command(&["rebase", "--abort"])
+            .context("Failed to abort rebase")?;
+        crate::output::print(success_message("Aborted in-progress rebase"))?;
+        aborted = true;
+    }
+    if repo.is_merging()? {
+        repo.run_command(&["merge", "--abort"])
+            .context("Failed to abort merge")?;
+        crate::output::print(success_message("Aborted in-progress merge"))?;
+        aborted = true;
+    }
+
+    let branch = repo.require_current_branch("abort merge")?;
+    let Some(start) = resolve_ref(&repo, &merge_start_ref(&branch)) else {
+        if !aborted {
+            crate::output::print(info_message(cformat!(
+                "Nothing to abort: no unfinished <bright-black>wt merge</> on <bold>{branch}</>"
+            )))?;
+        }
+        return Ok(());
+    };
+
+    repo.ensure_clean_working_tree("abort merge", Some(&branch))?;
+
+    let commit = resolve_ref(&repo, &merge_commit_ref(&branch));
+    let moved = match resolve_ref(&repo, &merge_end_ref(&branch)) {
+        Some(end) => resolve_ref(&repo, "HEAD").as_deref() != Some(end.as_str()),
+        // No record of where it stopped (e.g., the process was killed): anything
+        // `wt merge` didn't create counts as new
+        None => {
+            let mut args = vec!["rev-list", "--count", "HEAD", "--not", start.as_str()];
+            args.extend(commit.as_deref());
+            repo.run_command(&args)?.trim() != "0"
+        }
+    };
+    if moved {
+        let short = &start[..7.min(start.len())];
+        return Err(worktrunk::git::GitError::Other {
+            message: cformat!(
+                "Cannot abort merge: <bold>{branch}</> has changed since <bright-black>wt merge</> stopped. To discard those changes too, run <bright-black>git reset --hard {short}</>"
+            ),
+        }
+        .into());
+    }
+
+    let restored_changes = match commit {
+        Some(commit) => {
+            // Check out the commit's tree, then move the branch alone back to the
+            // start, leaving that tree as uncommitted changes
+            repo.run_command(&["reset", "--hard", &commit])?;
+            repo.run_command(&["reset", "--quiet", &start])?;
+            true
+        }
+        None => {
+            repo.run_command(&["reset", "--hard", &start])?;
+            false
+        }
+    };
+    clear_merge_state(&repo, &branch)?;
+
+    let short = &start[..7.min(start.len())];
+    let message = if restored_changes {
+        cformat!("Restored <bold>{branch}</> to <dim>{short}</>, with merged changes uncommitted")
+    } else {
+        cformat!("Restored <bold>{branch}</> to <dim>{short}</>")
+    };
+    crate::output::print(success_message(message))?;
+    Ok(())
+}
+
+/// Whether user or project config defines any pre-merge commands
+fn has_pre_merge_commands(repo: &Repository, config: &WorktrunkConfig) -> anyhow::Result<bool> {
+    let project_has = repo
+        .load_project_config()?
+        .is_some_and(|cfg| cfg.hooks.pre_merge.is_some());
+    Ok(project_has || config.hooks.pre_merge.is_some())
+}
+
 /// Run pre-merge commands sequentially (blocking, fail-fast)
 ///
 /// Runs user hooks first, then project hooks.
@@ -348,6 +584,7 @@ pub fn execute_pre_remove_commands(
     name_filter: Option<&str>,
     display_path: Option<&Path>,
     extra_vars: &[(&str, &str)],
+    failure_strategy: HookFailureStrategy,
 ) -> anyhow::Result<()> {
     let project_config = ctx.repo.load_project_config()?;
 
@@ -359,7 +596,7 @@ pub fn execute_pre_remove_commands(
             .and_then(|c| c.hooks.pre_remove.as_ref()),
         HookType::PreRemove,
         extra_vars,
-        HookFailureStrategy::FailFast,
+        failure_strategy,
         name_filter,
         display_path,
     )
@@ -1,47 +1,68 @@
 pub mod branch_deletion;
+mod clone;
 pub mod command_approval;
 pub mod command_executor;
 pub mod commit;
 pub mod config;
 pub mod configure_shell;
 pub mod context;
+mod doctor;
 mod for_each;
 mod hook_commands;
 mod hook_filter;
 mod hooks;
 pub mod init;
 pub mod list;
+mod log;
 pub mod merge;
+mod move_worktree;
+mod pick;
 pub mod process;
 pub mod project_config;
+mod prune;
+mod rename;
 pub mod repository_ext;
 #[cfg(unix)]
 pub mod select;
+mod status;
 pub mod statusline;
 pub mod step_commands;
 pub mod worktree;
 
-pub use command_approval::approve_hooks;
+pub use clone::handle_clone;
+pub use command_approval::{approve_hooks, approve_switch_hooks};
 pub use config::{
-    handle_config_create, handle_config_show, handle_hints_clear, handle_hints_get,
-    handle_state_clear, handle_state_clear_all, handle_state_get, handle_state_set,
-    handle_state_show,
+    handle_config_create, handle_config_get, handle_config_print, handle_config_set,
+    handle_config_show, handle_hints_clear, handle_hints_get, handle_state_clear,
+    handle_state_clear_all, handle_state_get, handle_state_set, handle_state_show,
 };
-pub use configure_shell::{handle_configure_shell, handle_show_theme, handle_unconfigure_shell};
+pub use configure_shell::{
+    ConfigAction, ShellCmds, handle_configure_shell, handle_show_theme, handle_unconfigure_shell,
+    install_report_json, show_install_preview,
+};
+pub use doctor::handle_doctor;
 pub use for_each::step_for_each;
-pub use hook_commands::{add_approvals, clear_approvals, handle_hook_show, run_hook};
+pub use hook_commands::{HookRunMode, add_approvals, clear_approvals, handle_hook_show, run_hook};
+pub use hooks::HookFailureStrategy;
 pub use init::handle_init;
 pub use list::handle_list;
-pub use merge::{MergeOptions, execute_pre_remove_commands, handle_merge};
+pub use log::handle_log;
+pub use merge::{MergeOptions, execute_pre_remove_commands, handle_merge, handle_merge_abort};
+pub use move_worktree::handle_move;
+pub use pick::pick_worktree_branch;
+pub use prune::{handle_prune, handle_remove_all_merged};
+pub use rename::handle_rename;
 #[cfg(unix)]
 pub use select::handle_select;
+pub use status::handle_status;
 pub use step_commands::{
-    RebaseResult, SquashResult, handle_rebase, handle_squash, step_commit, step_copy_ignored,
-    step_show_squash_prompt,
+    RebaseResult, SquashResult, handle_rebase, handle_squash, step_amend, step_commit,
+    step_copy_ignored, step_show_squash_prompt,
 };
 pub use worktree::{
     ResolutionContext, compute_worktree_path, handle_remove, handle_remove_current, handle_switch,
-    is_worktree_at_expected_path_with, resolve_worktree_arg, worktree_display_name,
+    handle_switch_detach, is_worktree_at_expected_path_with, print_worktree_path,
+    resolve_fuzzy_branch, resolve_worktree_arg, validate_branch_from, worktree_display_name,
 };
 
 // Re-export Shell from the canonical location
@@ -0,0 +1,74 @@
+//! Move a worktree to a new directory.
+//!
+//! Wraps `git worktree move` so the shell follows when run from inside the
+//! moved worktree. Worktrunk's own state (previous branch, markers, CI cache)
+//! is keyed by branch rather than path, so nothing else needs updating.
+
+use std::path::Path;
+
+use color_print::cformat;
+use normalize_path::NormalizePath;
+use worktrunk::git::{GitError, Repository, ResolvedWorktree};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::success_message;
+
+use super::worktree::current_dir_within;
+use crate::output;
+
+/// Handle `wt move`.
+///
+/// `name` accepts the same forms as `wt remove` (`@`, `-`, `^`, or a branch).
+/// The target must not exist; relative targets resolve against the current
+/// directory.
+pub fn handle_move(name: &str, new_path: &Path) -> anyhow::Result<()> {
+    let repo = Repository::current();
+
+    let (from, branch) = match repo.resolve_worktree(name)? {
+        ResolvedWorktree::Worktree { path, branch } => (path, branch),
+        ResolvedWorktree::BranchOnly { branch } => {
+            return Err(GitError::NoWorktreeFound { branch }.into());
+        }
+    };
+    let label = branch.unwrap_or_else(|| format_path_for_display(&from));
+
+    if !Repository::at(&from).is_in_worktree()? {
+        return Err(GitError::Other {
+            message: cformat!("Cannot move <bold>{label}</>: it's the main worktree"),
+        }
+        .into());
+    }
+
+    let to = std::env::current_dir()?.join(new_path).normalize();
+    if to.exists() {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Cannot move <bold>{label}</>: <bold>{}</> already exists",
+                format_path_for_display(&to)
+            ),
+        }
+        .into());
+    }
+
+    // Where we are inside the worktree, so the shell can follow it after the move
+    let cwd_in_worktree = current_dir_within(&from);
+
+    // Run from the repository root: the current directory may be the worktree being moved
+    let root_repo = Repository::at(repo.worktree_base()?);
+    root_repo.run_command(&[
+        "worktree",
+        "move",
+        &from.to_string_lossy(),
+        &to.to_string_lossy(),
+    ])?;
+
+    output::print(success_message(cformat!(
+        "Moved <bold>{label}</> to <bold>{}</>",
+        format_path_for_display(&to)
+    )))?;
+
+    if let Some(relative) = cwd_in_worktree {
+        output::change_directory(to.join(relative))?;
+    }
+
+    Ok(())
+}
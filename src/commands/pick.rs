@@ -0,0 +1,87 @@
+//! Worktree picker for `wt switch` without a branch (or with `--list`)
+//!
+//! A lightweight alternative to `wt select`: one line per worktree, no
+//! previews or status collection. Without a terminal (or on Windows), the
+//! worktrees are printed instead so scripts never block on a prompt.
+
+use std::io::IsTerminal;
+
+use worktrunk::git::{Repository, Worktree};
+use worktrunk::path::format_path_for_display;
+
+/// Let the user pick a worktree, returning its branch.
+///
+/// Returns `None` when nothing was picked: the picker was aborted, or there
+/// was no terminal and the list was printed to stdout instead. Detached
+/// worktrees are left out since `wt switch` targets branches.
+pub fn pick_worktree_branch(repo: &Repository) -> anyhow::Result<Option<String>> {
+    let worktrees: Vec<Worktree> = repo
+        .list_worktrees()?
+        .into_iter()
+        .filter(|wt| !wt.bare && wt.prunable.is_none() && wt.branch.is_some())
+        .collect();
+
+    let width = worktrees
+        .iter()
+        .filter_map(|wt| wt.branch.as_deref())
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+    let lines: Vec<String> = worktrees
+        .iter()
+        .map(|wt| {
+            let branch = wt.branch.as_deref().unwrap_or_default();
+            format!("{branch:<width$}  {}", format_path_for_display(&wt.path))
+        })
+        .collect();
+
+    if !std::io::stdin().is_terminal() || cfg!(not(unix)) {
+        for line in &lines {
+            crate::output::stdout(line)?;
+        }
+        return Ok(None);
+    }
+
+    #[cfg(unix)]
+    {
+        let Some(index) = run_picker(&lines)? else {
+            return Ok(None);
+        };
+        Ok(worktrees[index].branch.clone())
+    }
+    #[cfg(not(unix))]
+    unreachable!("non-unix returns after printing the list")
+}
+
+/// Run skim over `lines`, returning the index of the selected line
+#[cfg(unix)]
+fn run_picker(lines: &[String]) -> anyhow::Result<Option<usize>> {
+    use skim::prelude::*;
+
+    let options = SkimOptionsBuilder::default()
+        .height("40%".to_string())
+        .layout("reverse".to_string())
+        .multi(false)
+        .no_info(true)
+        .prompt("switch> ".to_string())
+        .build()
+        .map_err(|e| anyhow::anyhow!("Failed to build skim options: {}", e))?;
+
+    let (tx, rx): (SkimItemSender, SkimItemReceiver) = unbounded();
+    for line in lines {
+        tx.send(Arc::new(line.clone()) as Arc<dyn SkimItem>)
+            .map_err(|e| anyhow::anyhow!("Failed to send item to skim: {}", e))?;
+    }
+    drop(tx);
+
+    let Some(out) = Skim::run_with(&options, Some(rx)) else {
+        return Ok(None);
+    };
+    if out.is_abort {
+        return Ok(None);
+    }
+    Ok(out
+        .selected_items
+        .first()
+        .and_then(|selected| lines.iter().position(|line| *line == selected.text())))
+}
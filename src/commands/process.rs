@@ -32,6 +32,7 @@ fn posix_command_separator(command: &str) -> &'static str {
 /// * `branch` - Branch name for log organization
 /// * `name` - Operation identifier (e.g., "post-start-npm", "remove")
 /// * `context_json` - Optional JSON context to pipe to command's stdin
+/// * `env` - Extra environment variables for the command
 ///
 /// # Returns
 /// Path to the log file where output is being written
@@ -42,6 +43,7 @@ pub fn spawn_detached(
     branch: &str,
     name: &str,
     context_json: Option<&str>,
+    env: &[(&str, &str)],
 ) -> anyhow::Result<std::path::PathBuf> {
     // Create log directory in the common git directory
     let log_dir = repo.wt_logs_dir()?;
@@ -68,12 +70,12 @@ pub fn spawn_detached(
 
     #[cfg(unix)]
     {
-        spawn_detached_unix(worktree_path, command, log_file, context_json, name)?;
+        spawn_detached_unix(worktree_path, command, log_file, context_json, name, env)?;
     }
 
     #[cfg(windows)]
     {
-        spawn_detached_windows(worktree_path, command, log_file, context_json, name)?;
+        spawn_detached_windows(worktree_path, command, log_file, context_json, name, env)?;
     }
 
     Ok(log_path)
@@ -86,6 +88,7 @@ fn spawn_detached_unix(
     log_file: fs::File,
     context_json: Option<&str>,
     name: &str,
+    env: &[(&str, &str)],
 ) -> anyhow::Result<()> {
     use std::os::unix::process::CommandExt;
 
@@ -125,6 +128,7 @@ fn spawn_detached_unix(
                 .context("Failed to clone log file handle")?,
         ))
         .stderr(Stdio::from(log_file))
+        .envs(env.iter().copied())
         // Prevent hooks from writing to the directive file
         .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR)
         .process_group(0) // New process group, not in PTY's foreground group
@@ -146,6 +150,7 @@ fn spawn_detached_windows(
     log_file: fs::File,
     context_json: Option<&str>,
     name: &str,
+    env: &[(&str, &str)],
 ) -> anyhow::Result<()> {
     use std::os::windows::process::CommandExt;
     use worktrunk::shell_exec::ShellConfig;
@@ -203,6 +208,7 @@ fn spawn_detached_windows(
                 .context("Failed to clone log file handle")?,
         ))
         .stderr(Stdio::from(log_file))
+        .envs(env.iter().copied())
         // Prevent hooks from writing to the directive file
         .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR)
         .creation_flags(CREATE_NEW_PROCESS_GROUP | DETACHED_PROCESS)
@@ -91,6 +91,7 @@ pre-merge = "cargo test"
         let config = ProjectConfig::default();
         // All hooks should work even when empty
         let hooks = [
+            HookType::PreCreate,
             HookType::PostCreate,
             HookType::PostStart,
             HookType::PreCommit,
@@ -0,0 +1,321 @@
+//! Prune stale worktrees.
+//!
+//! Cleans up git's metadata for worktrees whose directories no longer exist
+//! (`git worktree prune`), then offers to remove worktrees whose branch's
+//! upstream has been deleted on the remote. `wt remove --all-merged` shares
+//! the confirmation flow for worktrees whose branch is merged locally.
+
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::HookType;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    PROMPT_SYMBOL, eprint, format_with_gutter, info_message, success_message, warning_message,
+};
+
+use super::command_approval::approve_hooks;
+use super::command_executor::CommandContext;
+use super::repository_ext::DirtyWorktree;
+use super::worktree::handle_remove;
+use crate::output::{self, handle_remove_output};
+
+/// A linked worktree whose branch's upstream was deleted on the remote
+struct PruneCandidate {
+    branch: String,
+    path: PathBuf,
+}
+
+/// Handle `wt prune`.
+///
+/// The current worktree and the main worktree are never candidates. Locked
+/// worktrees are skipped. Removal goes through the same path as `wt remove`,
+/// so branches are only deleted when merged and pre-remove hooks run.
+pub fn handle_prune(dry_run: bool, yes: bool, config: &WorktrunkConfig) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let worktrees = repo.list_worktrees()?;
+
+    // Worktrees whose directories are gone only need git's metadata cleaned up
+    let missing: Vec<String> = worktrees
+        .iter()
+        .filter(|wt| wt.is_prunable())
+        .map(|wt| format_path_for_display(&wt.path))
+        .collect();
+    if !missing.is_empty() {
+        let verb = if dry_run { "Would prune" } else { "Pruned" };
+        if !dry_run {
+            repo.run_command(&["worktree", "prune"])?;
+        }
+        output::print(success_message(format!(
+            "{verb} metadata for {} missing {}:\n{}",
+            missing.len(),
+            worktree_word(missing.len()),
+            format_with_gutter(&missing.join("\n"), None)
+        )))?;
+    }
+
+    let candidates = find_candidates(&repo, &worktrees)?;
+    if candidates.is_empty() {
+        if missing.is_empty() {
+            output::print(info_message("No stale worktrees"))?;
+        }
+        return Ok(());
+    }
+
+    let items: Vec<String> = candidates
+        .iter()
+        .map(|c| format!("{} @ {}", c.branch, format_path_for_display(&c.path)))
+        .collect();
+    let count = candidates.len();
+    let verb = if dry_run { "Would remove" } else { "Found" };
+    output::print(info_message(format!(
+        "{verb} {count} {} with deleted upstream:\n{}",
+        worktree_word(count),
+        format_with_gutter(&items.join("\n"), None)
+    )))?;
+
+    if dry_run {
+        return Ok(());
+    }
+
+    if !yes && !prompt_for_removal()? {
+        output::print(info_message("Prune cancelled"))?;
+        return Ok(());
+    }
+
+    // "Approve at the Gate": approve pre-remove hooks once for all removals
+    let worktree_path = std::env::current_dir().context("Failed to get current directory")?;
+    let repo_root = repo.worktree_base()?;
+    let ctx = CommandContext::new(
+        &repo,
+        config,
+        repo.current_branch()?,
+        &worktree_path,
+        &repo_root,
+        yes,
+    );
+    let verify = approve_hooks(&ctx, &[HookType::PreRemove])?;
+    if !verify {
+        output::print(info_message("Commands declined, continuing removal"))?;
+    }
+
+    let mut failed = false;
+    for candidate in &candidates {
+        match handle_remove(
+            &candidate.branch,
+            false,
+            false,
+            false,
+            DirtyWorktree::Refuse,
+            true,
+            config,
+        ) {
+            Ok(result) => handle_remove_output(&result, true, verify)?,
+            Err(e) => {
+                output::print(e.to_string())?;
+                failed = true;
+            }
+        }
+    }
+
+    // Errors already printed
+    if failed {
+        anyhow::bail!("");
+    }
+    Ok(())
+}
+
+/// Handle `wt remove --all-merged`.
+///
+/// Candidates are linked worktrees whose branch is an ancestor of the default
+/// branch (or its upstream, when that's ahead). The current worktree, the main
+/// worktree, and locked worktrees are never removed. Dirty worktrees are skipped
+/// unless `force`, which discards their changes.
+pub fn handle_remove_all_merged(
+    yes: bool,
+    force: bool,
+    no_delete_branch: bool,
+    verify: bool,
+    background: bool,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let worktrees = repo.list_worktrees()?;
+    let default_branch = repo.default_branch()?;
+    let target = repo.effective_integration_target(&default_branch);
+    let current = repo.worktree_root().ok().map(Path::to_path_buf);
+
+    let mut candidates = Vec::new();
+    let mut skipped = 0;
+    for wt in &worktrees {
+        let Some(branch) = wt.branch.as_ref().filter(|b| **b != default_branch) else {
+            continue;
+        };
+        if wt.is_prunable() || wt.locked.is_some() || Some(&wt.path) == current.as_ref() {
+            continue;
+        }
+        let wt_repo = Repository::at(&wt.path);
+        // Only linked worktrees can be removed; skip the main worktree
+        if !wt_repo.is_in_worktree()? || !repo.is_ancestor(branch, &target)? {
+            continue;
+        }
+        if !force && wt_repo.is_dirty()? {
+            output::print(warning_message(cformat!(
+                "Skipping <bold>{branch}</>: uncommitted changes (use <bright-black>--force</> to discard)"
+            )))?;
+            skipped += 1;
+            continue;
+        }
+        candidates.push(PruneCandidate {
+            branch: branch.clone(),
+            path: wt.path.clone(),
+        });
+    }
+
+    if candidates.is_empty() {
+        if skipped == 0 {
+            output::print(info_message(cformat!(
+                "No worktrees merged into <bold>{target}</>"
+            )))?;
+        }
+        return Ok(());
+    }
+
+    let items: Vec<String> = candidates
+        .iter()
+        .map(|c| format!("{} @ {}", c.branch, format_path_for_display(&c.path)))
+        .collect();
+    let count = candidates.len();
+    output::print(info_message(cformat!(
+        "Found {count} {} merged into <bold>{target}</>:\n{}",
+        worktree_word(count),
+        format_with_gutter(&items.join("\n"), None)
+    )))?;
+
+    if !yes && !prompt_for_removal()? {
+        output::print(info_message("Removal cancelled"))?;
+        return Ok(());
+    }
+
+    // "Approve at the Gate": approve pre-remove hooks once for all removals
+    let verify = verify && {
+        let worktree_path = std::env::current_dir().context("Failed to get current directory")?;
+        let repo_root = repo.worktree_base()?;
+        let ctx = CommandContext::new(
+            &repo,
+            config,
+            repo.current_branch()?,
+            &worktree_path,
+            &repo_root,
+            yes,
+        );
+        let approved = approve_hooks(&ctx, &[HookType::PreRemove])?;
+        if !approved {
+            output::print(info_message("Commands declined, continuing removal"))?;
+        }
+        approved
+    };
+
+    let dirty = if force {
+        DirtyWorktree::Discard
+    } else {
+        DirtyWorktree::Refuse
+    };
+    let mut removed = 0;
+    let mut failed = false;
+    for candidate in &candidates {
+        match handle_remove(
+            &candidate.branch,
+            no_delete_branch,
+            false,
+            force,
+            dirty,
+            background,
+            config,
+        ) {
+            Ok(result) => {
+                handle_remove_output(&result, background, verify)?;
+                removed += 1;
+            }
+            Err(e) => {
+                output::print(e.to_string())?;
+                skipped += 1;
+                failed = true;
+            }
+        }
+    }
+
+    output::print(success_message(format!(
+        "Removed {removed} merged {}, skipped {skipped}",
+        worktree_word(removed)
+    )))?;
+
+    // Errors already printed
+    if failed {
+        anyhow::bail!("");
+    }
+    Ok(())
+}
+
+/// Find linked worktrees (other than the current one) whose upstream is gone
+fn find_candidates(
+    repo: &Repository,
+    worktrees: &[worktrunk::git::Worktree],
+) -> anyhow::Result<Vec<PruneCandidate>> {
+    let gone = repo.list_branches_with_gone_upstream()?;
+    let current = repo.worktree_root().ok().map(Path::to_path_buf);
+
+    let mut candidates = Vec::new();
+    for wt in worktrees {
+        let Some(branch) = wt.branch.as_ref().filter(|b| gone.contains(b)) else {
+            continue;
+        };
+        if wt.is_prunable() || wt.locked.is_some() || Some(&wt.path) == current.as_ref() {
+            continue;
+        }
+        // Only linked worktrees can be removed; skip the main worktree
+        if !Repository::at(&wt.path).is_in_worktree()? {
+            continue;
+        }
+        candidates.push(PruneCandidate {
+            branch: branch.clone(),
+            path: wt.path.clone(),
+        });
+    }
+    Ok(candidates)
+}
+
+fn worktree_word(count: usize) -> &'static str {
+    if count == 1 { "worktree" } else { "worktrees" }
+}
+
+/// Prompt for confirmation before removing candidates
+fn prompt_for_removal() -> anyhow::Result<bool> {
+    // Fail fast rather than hang when there's no one to answer
+    if !io::stdin().is_terminal() {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Cannot prompt for confirmation in non-interactive environment; add <bright-black>--yes</> to remove without prompting"
+            ),
+        }
+        .into());
+    }
+
+    output::flush()?;
+    eprint!(
+        "{}",
+        cformat!("{PROMPT_SYMBOL} Remove them? <bold>[y/N]</> ")
+    );
+    io::stderr().flush()?;
+
+    let mut response = String::new();
+    io::stdin().read_line(&mut response)?;
+
+    output::blank()?;
+
+    Ok(response.trim().eq_ignore_ascii_case("y"))
+}
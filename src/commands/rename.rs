@@ -0,0 +1,141 @@
+//! Rename a branch together with its worktree.
+//!
+//! Renames the git branch, then moves the worktree directory to the path the
+//! new name would get from `worktree-path`, so the directory doesn't keep the
+//! old branch's name.
+
+use std::path::PathBuf;
+
+use color_print::cformat;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{hint_message, success_message};
+
+use super::worktree::{
+    compute_worktree_path, current_dir_within, is_worktree_at_expected_path_with,
+};
+use crate::output;
+
+/// Handle `wt rename`.
+///
+/// The worktree only moves when it's a linked worktree sitting at the path
+/// computed for `old`; worktrees placed elsewhere by hand keep their directory.
+/// When the branch tracked `<remote>/<old>` and `<remote>/<new>` exists, the
+/// upstream follows the rename.
+pub fn handle_rename(old: &str, new: &str, config: &WorktrunkConfig) -> anyhow::Result<()> {
+    let repo = Repository::current();
+
+    if !repo.local_branch_exists(old)? {
+        return Err(GitError::InvalidReference {
+            reference: old.to_string(),
+        }
+        .into());
+    }
+    let default_branch = repo.default_branch().unwrap_or_default();
+    if old == default_branch {
+        return Err(GitError::CannotRenameDefaultBranch {
+            branch: old.to_string(),
+        }
+        .into());
+    }
+    if repo.local_branch_exists(new)? {
+        return Err(GitError::RenameTargetExists {
+            from: old.to_string(),
+            to: new.to_string(),
+        }
+        .into());
+    }
+    if !repo.run_command_check(&["check-ref-format", "--branch", new])? {
+        return Err(GitError::Other {
+            message: cformat!("<bold>{new}</> is not a valid branch name"),
+        }
+        .into());
+    }
+
+    // Work out the move before touching anything, so a conflict leaves the repo as it was
+    let is_bare = repo.is_bare()?;
+    let worktree = repo
+        .list_worktrees()?
+        .into_iter()
+        .find(|wt| wt.branch.as_deref() == Some(old));
+    let mut move_paths: Option<(PathBuf, PathBuf)> = None;
+    if let Some(wt) = &worktree
+        && Repository::at(&wt.path).is_in_worktree()?
+        && is_worktree_at_expected_path_with(wt, &repo, config, &default_branch, is_bare)
+    {
+        let new_path = compute_worktree_path(&repo, new, config)?;
+        if new_path.exists() {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "Cannot move worktree for <bold>{old}</>: <bold>{}</> already exists",
+                    format_path_for_display(&new_path)
+                ),
+            }
+            .into());
+        }
+        move_paths = Some((wt.path.clone(), new_path));
+    }
+
+    // Where we are inside the worktree, so the shell can follow it after the move
+    let cwd_in_worktree = move_paths
+        .as_ref()
+        .and_then(|(from, _)| current_dir_within(from));
+
+    let upstream = repo.upstream_branch(old)?;
+    repo.run_command(&["branch", "-m", old, new])?;
+
+    // Run from the repository root: the current directory may be the worktree being moved
+    let root_repo = Repository::at(repo.worktree_base()?);
+    if let Some((from, to)) = &move_paths {
+        let result = root_repo.run_command(&[
+            "worktree",
+            "move",
+            &from.to_string_lossy(),
+            &to.to_string_lossy(),
+        ]);
+        if let Err(e) = result {
+            // Put the branch back so the rename is all-or-nothing
+            root_repo.run_command(&["branch", "-m", new, old])?;
+            return Err(e);
+        }
+    }
+
+    let mut hint = None;
+    if let Some(upstream) = upstream
+        && let Some((remote, remote_branch)) = upstream.split_once('/')
+        && remote_branch == old
+    {
+        let renamed = format!("{remote}/{new}");
+        if root_repo.run_command_check(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/remotes/{renamed}"),
+        ])? {
+            root_repo.run_command(&["branch", "--set-upstream-to", &renamed, new])?;
+        } else {
+            hint = Some(cformat!(
+                "Still tracking <bold>{upstream}</>; to push under the new name, run <bright-black>git push -u {remote} {new}</>"
+            ));
+        }
+    }
+
+    let message = match &move_paths {
+        Some((_, to)) => cformat!(
+            "Renamed <bold>{old}</> to <bold>{new}</> and moved worktree to <bold>{}</>",
+            format_path_for_display(to)
+        ),
+        None => cformat!("Renamed <bold>{old}</> to <bold>{new}</>"),
+    };
+    output::print(success_message(message))?;
+    if let Some(hint) = hint {
+        output::print(hint_message(hint))?;
+    }
+
+    if let (Some((_, to)), Some(relative)) = (&move_paths, cwd_in_worktree) {
+        output::change_directory(to.join(relative))?;
+    }
+
+    Ok(())
+}
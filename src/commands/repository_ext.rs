@@ -1,3 +1,4 @@
+use std::io::{self, IsTerminal, Write};
 use std::path::{Path, PathBuf};
 use std::process;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -10,7 +11,7 @@ use worktrunk::git::{
     GitError, IntegrationReason, Repository, parse_porcelain_z, parse_untracked_files,
 };
 use worktrunk::path::format_path_for_display;
-use worktrunk::styling::{format_with_gutter, progress_message, warning_message};
+use worktrunk::styling::{PROMPT_SYMBOL, format_with_gutter, progress_message, warning_message};
 
 /// Target for worktree removal.
 #[derive(Debug)]
@@ -21,6 +22,17 @@ pub enum RemoveTarget<'a> {
     Current,
 }
 
+/// How removal treats a worktree with uncommitted changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DirtyWorktree {
+    /// Fail with `GitError::UncommittedChanges`
+    Refuse,
+    /// Ask before discarding the changes; decline when there's no one to ask
+    Confirm,
+    /// Discard the changes without asking
+    Discard,
+}
+
 /// CLI-only helpers implemented on [`Repository`] via an extension trait so we can keep orphan
 /// implementations inside the binary crate.
 pub trait RepositoryCliExt {
@@ -33,12 +45,14 @@ pub trait RepositoryCliExt {
     /// removal is performed by the output handler.
     ///
     /// The `config` parameter is used to compute the expected worktree path
-    /// for path mismatch detection.
+    /// for path mismatch detection. `dirty` decides whether uncommitted
+    /// changes block the removal; discarding them implies `force_worktree`.
     fn prepare_worktree_removal(
         &self,
         target: RemoveTarget,
         deletion_mode: BranchDeletionMode,
         force_worktree: bool,
+        dirty: DirtyWorktree,
         config: &WorktrunkConfig,
     ) -> anyhow::Result<RemoveResult>;
 
@@ -74,6 +88,7 @@ impl RepositoryCliExt for Repository {
         target: RemoveTarget,
         deletion_mode: BranchDeletionMode,
         force_worktree: bool,
+        dirty: DirtyWorktree,
         config: &WorktrunkConfig,
     ) -> anyhow::Result<RemoveResult> {
         let current_path = self.worktree_root()?.to_path_buf();
@@ -163,8 +178,26 @@ impl RepositoryCliExt for Repository {
             return Err(GitError::CannotRemoveMainWorktree.into());
         }
 
-        // Ensure the working tree is clean
-        target_repo.ensure_clean_working_tree("remove worktree", branch_name.as_deref())?;
+        // Uncommitted changes block removal unless they're discarded, which
+        // needs `git worktree remove --force`
+        let mut force_worktree = force_worktree;
+        if target_repo.is_dirty()? {
+            let discard = match dirty {
+                DirtyWorktree::Refuse => false,
+                DirtyWorktree::Confirm => {
+                    confirm_dirty_removal(&target_repo, branch_name.as_deref())?
+                }
+                DirtyWorktree::Discard => true,
+            };
+            if !discard {
+                return Err(GitError::UncommittedChanges {
+                    action: Some("remove worktree".into()),
+                    branch: branch_name.clone(),
+                }
+                .into());
+            }
+            force_worktree = true;
+        }
 
         // Compute main_path and changed_directory based on whether we're removing current
         let (main_path, changed_directory) = if is_current {
@@ -346,6 +379,38 @@ fn compute_integration_reason(
     worktrunk::git::check_integration(&mut provider)
 }
 
+/// Ask whether to remove a worktree anyway, listing its uncommitted changes.
+///
+/// Declines without asking when stdin isn't a terminal, so scripts never lose
+/// changes.
+fn confirm_dirty_removal(repo: &Repository, branch: Option<&str>) -> anyhow::Result<bool> {
+    if !io::stdin().is_terminal() {
+        return Ok(false);
+    }
+
+    let status = repo.run_command(&["status", "--porcelain"])?;
+    let name = match branch {
+        Some(branch) => cformat!("<bold>{branch}</>"),
+        None => "Current worktree".to_string(),
+    };
+    crate::output::print(warning_message(cformat!("{name} has uncommitted changes:")))?;
+    crate::output::print(format_with_gutter(status.trim_end(), None))?;
+
+    crate::output::flush()?;
+    eprint!(
+        "{}",
+        cformat!("{PROMPT_SYMBOL} Remove it and discard them? <bold>[y/N]</> ")
+    );
+    io::stderr().flush()?;
+
+    let mut response = String::new();
+    io::stdin().read_line(&mut response)?;
+
+    crate::output::blank()?;
+
+    Ok(response.trim().eq_ignore_ascii_case("y"))
+}
+
 /// Warn about untracked files that will be auto-staged.
 fn warn_about_untracked_files(status_output: &str) -> anyhow::Result<()> {
     let files = parse_untracked_files(status_output);
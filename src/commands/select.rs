@@ -867,6 +867,7 @@ pub fn handle_select() -> anyhow::Result<()> {
         collect::TaskKind::BranchDiff,
         collect::TaskKind::CiStatus,
         collect::TaskKind::MergeTreeConflicts,
+        collect::TaskKind::Staleness,
     ]
     .into_iter()
     .collect();
@@ -878,6 +879,7 @@ pub fn handle_select() -> anyhow::Result<()> {
         &skip_tasks,
         false, // show_progress (no progress bars)
         false, // render_table (select renders its own UI)
+        None,  // stale_days (no merged/stale markers)
         &config,
     )?
     else {
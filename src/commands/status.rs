@@ -0,0 +1,217 @@
+//! Summarize the current worktree.
+//!
+//! `wt status` is a one-shot overview: branch, upstream tracking, working tree
+//! state, and which hooks are configured or unset. Detached HEAD and branches without an
+//! upstream are reported rather than treated as errors.
+
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+use color_print::cformat;
+use worktrunk::HookType;
+use worktrunk::config::{HooksConfig, WorktrunkConfig};
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+
+use crate::cli::OutputFormat;
+use crate::output;
+
+/// Upstream tracking branch and how far the current branch has diverged from it
+struct Upstream {
+    name: String,
+    ahead: usize,
+    behind: usize,
+}
+
+/// Everything `wt status` reports about the current worktree
+struct WorktreeStatus {
+    path: PathBuf,
+    /// `None` when HEAD is detached
+    branch: Option<String>,
+    /// `None` before the first commit
+    head: Option<String>,
+    upstream: Option<Upstream>,
+    dirty: bool,
+    user_hooks: Vec<HookType>,
+    project_hooks: Vec<HookType>,
+}
+
+impl WorktreeStatus {
+    /// Whether a hook type has commands in either user or project config
+    fn is_configured(&self, hook: HookType) -> bool {
+        self.user_hooks.contains(&hook) || self.project_hooks.contains(&hook)
+    }
+}
+
+/// Handle `wt status`.
+pub fn handle_status(format: OutputFormat, config: &WorktrunkConfig) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let status = gather(&repo, config)?;
+
+    match format {
+        OutputFormat::Json => render_json(&status),
+        OutputFormat::Table => render_table(&status),
+    }
+}
+
+fn gather(repo: &Repository, config: &WorktrunkConfig) -> anyhow::Result<WorktreeStatus> {
+    let path = repo.worktree_root()?.to_path_buf();
+    let branch = repo.current_branch()?.map(str::to_string);
+    let head = repo
+        .run_command(&["rev-parse", "--short", "HEAD"])
+        .ok()
+        .map(|sha| sha.trim().to_string());
+
+    let upstream = match &branch {
+        Some(branch) => match repo.upstream_branch(branch)? {
+            Some(name) => {
+                let (ahead, behind) = repo.ahead_behind(&name, "HEAD")?;
+                Some(Upstream {
+                    name,
+                    ahead,
+                    behind,
+                })
+            }
+            None => None,
+        },
+        None => None,
+    };
+
+    let project_hooks = repo
+        .load_project_config()?
+        .map(|project| configured_hooks(&project.hooks))
+        .unwrap_or_default();
+
+    Ok(WorktreeStatus {
+        path,
+        branch,
+        head,
+        upstream,
+        dirty: repo.is_dirty()?,
+        user_hooks: configured_hooks(&config.hooks),
+        project_hooks,
+    })
+}
+
+/// Hook types that have at least one command configured
+fn configured_hooks(hooks: &HooksConfig) -> Vec<HookType> {
+    HookType::value_variants()
+        .iter()
+        .copied()
+        .filter(|&hook| hooks.get(hook).is_some())
+        .collect()
+}
+
+fn render_json(status: &WorktreeStatus) -> anyhow::Result<()> {
+    let hook_names =
+        |hooks: &[HookType]| -> Vec<String> { hooks.iter().map(ToString::to_string).collect() };
+    let upstream = status.upstream.as_ref().map(|upstream| {
+        serde_json::json!({
+            "name": upstream.name,
+            "ahead": upstream.ahead,
+            "behind": upstream.behind,
+        })
+    });
+
+    let hooks: serde_json::Map<String, serde_json::Value> = HookType::value_variants()
+        .iter()
+        .map(|hook| (hook.to_string(), status.is_configured(*hook).into()))
+        .collect();
+
+    let output = serde_json::json!({
+        "path": status.path,
+        "branch": status.branch,
+        "detached": status.branch.is_none(),
+        "head": status.head,
+        "upstream": upstream,
+        "dirty": status.dirty,
+        "hooks": hooks,
+        "hook_sources": {
+            "user": hook_names(&status.user_hooks),
+            "project": hook_names(&status.project_hooks),
+        },
+    });
+
+    output::stdout(serde_json::to_string_pretty(&output)?)?;
+    Ok(())
+}
+
+fn render_table(status: &WorktreeStatus) -> anyhow::Result<()> {
+    let head = status.head.as_deref().unwrap_or("no commits");
+    let path = format_path_for_display(&status.path);
+    let branch = match &status.branch {
+        Some(branch) => cformat!("<bold>{branch}</> <dim>@ {path}</>"),
+        None => cformat!("<yellow>detached at {head}</> <dim>@ {path}</>"),
+    };
+
+    let upstream = match &status.upstream {
+        Some(Upstream {
+            name,
+            ahead: 0,
+            behind: 0,
+        }) => cformat!("{name} <dim>(up to date)</>"),
+        Some(Upstream {
+            name,
+            ahead,
+            behind,
+        }) => {
+            let mut counts = Vec::new();
+            if *ahead > 0 {
+                counts.push(cformat!("<green>↑{ahead}</>"));
+            }
+            if *behind > 0 {
+                counts.push(cformat!("<red>↓{behind}</>"));
+            }
+            format!("{name} {}", counts.join(" "))
+        }
+        None if status.branch.is_none() => cformat!("<dim>(detached)</>"),
+        None => cformat!("<dim>(none)</>"),
+    };
+
+    let tree = if status.dirty {
+        cformat!("<yellow>uncommitted changes</>")
+    } else {
+        cformat!("<green>clean</>")
+    };
+
+    let hooks = [
+        ("user", &status.user_hooks),
+        ("project", &status.project_hooks),
+    ]
+    .into_iter()
+    .filter(|(_, hooks)| !hooks.is_empty())
+    .map(|(source, hooks)| {
+        let names: Vec<String> = hooks.iter().map(ToString::to_string).collect();
+        cformat!("{} <dim>({source})</>", names.join(", "))
+    })
+    .collect::<Vec<_>>();
+    let hooks = if hooks.is_empty() {
+        cformat!("<dim>(none)</>")
+    } else {
+        hooks.join("; ")
+    };
+
+    let unset: Vec<String> = HookType::value_variants()
+        .iter()
+        .filter(|&&hook| !status.is_configured(hook))
+        .map(ToString::to_string)
+        .collect();
+
+    let mut rows = vec![
+        ("Branch", branch),
+        ("Upstream", upstream),
+        ("Tree", tree),
+        ("Hooks", hooks),
+    ];
+    if !unset.is_empty() {
+        rows.push(("Unset", cformat!("<dim>{}</>", unset.join(", "))));
+    }
+    let report = rows
+        .iter()
+        .map(|(label, value)| cformat!("<dim>{label:<8}</> {value}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    output::stdout(report)?;
+    Ok(())
+}
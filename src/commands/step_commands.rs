@@ -2,6 +2,7 @@
 //!
 //! This module contains the individual steps that make up `wt merge`:
 //! - `step_commit` - Commit working tree changes
+//! - `step_amend` - Regenerate the message of the last commit
 //! - `handle_squash` - Squash commits into one
 //! - `step_show_squash_prompt` - Show squash prompt without executing
 //! - `handle_rebase` - Rebase onto target branch
@@ -13,36 +14,70 @@ use anyhow::Context;
 use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::WorktrunkConfig;
-use worktrunk::git::Repository;
+use worktrunk::git::{GitError, Repository};
 use worktrunk::styling::{
     format_with_gutter, hint_message, info_message, progress_message, success_message,
 };
 
 use super::commit::{CommitGenerator, CommitOptions};
-use super::context::CommandEnv;
+use super::context::{CommandEnv, apply_project_commit_generation};
 use super::hooks::{HookFailureStrategy, run_hook_with_filter};
 use super::repository_ext::RepositoryCliExt;
 
 /// Handle `wt step commit` command
+#[allow(clippy::too_many_arguments)]
 pub fn step_commit(
     yes: bool,
     no_verify: bool,
     stage_mode: super::commit::StageMode,
+    model: Option<String>,
+    template: Option<String>,
+    template_file: Option<String>,
     show_prompt: bool,
+    preview: bool,
+    since: Option<String>,
 ) -> anyhow::Result<()> {
     use super::command_approval::approve_hooks;
 
+    // Resolve --since up front so a bad ref fails before staging or hooks
+    let since = since
+        .map(|reference| super::worktree::resolve_commit(&Repository::current(), &reference))
+        .transpose()?;
+
+    // --template/--template-file replace both config options, so either wins over config
+    let override_template = |config: &mut WorktrunkConfig| {
+        if template.is_some() || template_file.is_some() {
+            config.commit_generation.template = template.clone();
+            config.commit_generation.template_file = template_file.clone();
+        }
+    };
+
     // Handle --show-prompt early: just build and output the prompt
     if show_prompt {
-        let config = WorktrunkConfig::load().context("Failed to load config")?;
-        let prompt = crate::llm::build_commit_prompt(&config.commit_generation)?;
+        let mut config = WorktrunkConfig::load().context("Failed to load config")?;
+        apply_project_commit_generation(&Repository::current(), &mut config)?;
+        override_template(&mut config);
+        let prompt = crate::llm::build_commit_prompt(&config.commit_generation, since.as_deref())?;
         crate::output::stdout(prompt)?;
         return Ok(());
     }
 
-    let env = CommandEnv::for_action("commit")?;
+    let mut env = CommandEnv::for_action("commit")?;
+    // --model takes precedence over commit-generation.model
+    if model.is_some() {
+        env.config.commit_generation.model = model;
+    }
+    override_template(&mut env.config);
     let ctx = env.context(yes);
 
+    if preview {
+        let mut options = CommitOptions::new(&ctx);
+        options.stage_mode = stage_mode;
+        options.warn_about_untracked = stage_mode == super::commit::StageMode::All;
+        options.since = since.as_deref();
+        return options.preview();
+    }
+
     // "Approve at the Gate": approve pre-commit hooks upfront (unless --no-verify)
     // Shadow no_verify: if user declines approval, skip hooks but continue commit
     let no_verify = if !no_verify {
@@ -65,10 +100,71 @@ pub fn step_commit(
     options.show_no_squash_note = false;
     // Only warn about untracked if we're staging all
     options.warn_about_untracked = stage_mode == super::commit::StageMode::All;
+    options.since = since.as_deref();
 
     options.commit()
 }
 
+/// Handle `wt step amend` command
+///
+/// Regenerates the message of `HEAD` from the changes it introduced, using the
+/// same prompt as `wt step commit`, and rewrites the commit with it.
+pub fn step_amend(yes: bool, force: bool, show_prompt: bool) -> anyhow::Result<()> {
+    if show_prompt {
+        let mut config = WorktrunkConfig::load().context("Failed to load config")?;
+        apply_project_commit_generation(&Repository::current(), &mut config)?;
+        let prompt = crate::llm::build_amend_prompt(&config.commit_generation)?;
+        crate::output::stdout(prompt)?;
+        return Ok(());
+    }
+
+    let env = CommandEnv::for_action_branchless()?;
+    let repo = &env.repo;
+    if !force {
+        check_amendable(repo, env.branch.as_deref())?;
+    }
+    let ctx = env.context(yes);
+    let generation = super::command_approval::approve_commit_generation(&ctx)?;
+    let generator =
+        CommitGenerator::new(&generation, env.config.commit.as_ref().and_then(|c| c.sign));
+
+    crate::output::print(progress_message("Generating commit message for HEAD..."))?;
+    let commit_message = crate::llm::generate_amend_message(&generation)?;
+
+    let formatted_message = generator.format_message_for_display(&commit_message);
+    crate::output::print(format_with_gutter(&formatted_message, None))?;
+
+    generator
+        .amend_commit(repo, &commit_message)
+        .context("Failed to amend commit")?;
+
+    let commit_hash = repo
+        .run_command(&["rev-parse", "--short", "HEAD"])?
+        .trim()
+        .to_string();
+    crate::output::print(success_message(cformat!("Amended @ <dim>{commit_hash}</>")))?;
+
+    Ok(())
+}
+
+/// Refuse to amend a merge commit, or a commit the branch's upstream already has
+fn check_amendable(repo: &Repository, branch: Option<&str>) -> anyhow::Result<()> {
+    // One line: the commit followed by its parents
+    let parents = repo.run_command(&["rev-list", "--parents", "-n", "1", "HEAD"])?;
+    if parents.split_whitespace().count() > 2 {
+        return Err(GitError::AmendMergeCommit.into());
+    }
+
+    if let Some(branch) = branch
+        && let Some(upstream) = repo.upstream_branch(branch)?
+        && repo.is_ancestor("HEAD", &upstream)?
+    {
+        return Err(GitError::AmendPushedCommit { upstream }.into());
+    }
+
+    Ok(())
+}
+
 /// Result of a squash operation
 #[derive(Debug, Clone)]
 pub enum SquashResult {
@@ -100,7 +196,9 @@ pub fn handle_squash(
     // Squash requires being on a branch (can't squash in detached HEAD)
     let current_branch = env.require_branch("squash")?.to_string();
     let ctx = env.context(yes);
-    let generator = CommitGenerator::new(&env.config.commit_generation);
+    let generation = super::command_approval::approve_commit_generation(&ctx)?;
+    let generator =
+        CommitGenerator::new(&generation, env.config.commit.as_ref().and_then(|c| c.sign));
 
     // Get target branch (default to default branch if not provided)
     let target_branch = repo.resolve_target_branch(target)?;
@@ -257,6 +355,7 @@ pub fn handle_squash(
     crate::output::print(format_with_gutter(&formatted_message, None))?;
 
     // Reset to merge base (soft reset stages all changes, including any already-staged uncommitted changes)
+    let original_head = repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string();
     repo.run_command(&["reset", "--soft", &merge_base])
         .context("Failed to reset to merge base")?;
 
@@ -268,9 +367,13 @@ pub fn handle_squash(
         return Ok(SquashResult::NoNetChanges);
     }
 
-    // Commit with the generated message
-    repo.run_command(&["commit", "-m", &commit_message])
-        .context("Failed to create squash commit")?;
+    // Commit with the generated message. If that fails (e.g. signing), put the
+    // branch back where it was so the commits aren't left uncommitted in the index
+    if let Err(err) = generator.create_commit(repo, &commit_message) {
+        repo.run_command(&["reset", "--soft", &original_head])
+            .context("Failed to restore branch after squash commit failed")?;
+        return Err(err.context("Failed to create squash commit"));
+    }
 
     // Get commit hash for display
     let commit_hash = repo
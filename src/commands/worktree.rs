@@ -19,6 +19,24 @@
 //! 3. wt binary writes commands like `cd '/path'` to that file
 //! 4. Shell wrapper sources the file after wt exits
 //!
+//! Lines of the form `__WORKTRUNK_ENV__KEY=VALUE` are env directives: the wrapper exports
+//! them (split on the first `=`) instead of sourcing them. `wt switch` emits these for the
+//! project's `[env]` table.
+//!
+//! Lines of the form `__WORKTRUNK_EXEC__<cmd>` are exec directives: the wrapper runs them
+//! last, after the `cd`, ignoring their exit code. `wt switch` emits one for the project's
+//! `open-editor-command`.
+//!
+//! When a command fails, pending directives are replaced by a single
+//! `__WORKTRUNK_ERR__<message>` line (the first line of the error). Wrappers apply no
+//! directives from such a file, so a failed command never changes directory or runs
+//! exec directives; the error itself is already on stderr.
+//!
+//! The tcsh/csh wrapper is an alias that can't parse lines, so with `WORKTRUNK_SHELL`
+//! set to `tcsh` or `csh` these are written as plain csh instead (`setenv KEY 'VALUE'`
+//! and the bare command), and the alias sources the whole file. On failure the file is
+//! emptied instead of carrying an error directive.
+//!
 //! ## Without Shell Integration (Direct Binary Call)
 //!
 //! ```bash
@@ -81,10 +99,15 @@
 use anyhow::Context;
 use color_print::cformat;
 use dunce::canonicalize;
+use indexmap::IndexMap;
 use normalize_path::NormalizePath;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use worktrunk::HookType;
-use worktrunk::config::WorktrunkConfig;
+use worktrunk::config::{
+    PostCreateFile, WorktrunkConfig, expand_template, is_contained_relative_path,
+    is_shell_control_var,
+};
 use worktrunk::git::{GitError, Repository, ResolvedWorktree};
 use worktrunk::styling::{
     format_with_gutter, hint_message, info_message, progress_message, success_message,
@@ -93,7 +116,7 @@ use worktrunk::styling::{
 
 use super::command_executor::CommandContext;
 use super::hooks::{HookFailureStrategy, prepare_hook_commands, spawn_hook_commands_background};
-use super::repository_ext::{RemoveTarget, RepositoryCliExt};
+use super::repository_ext::{DirtyWorktree, RemoveTarget, RepositoryCliExt};
 
 /// Generate a backup path for the given path with a timestamp suffix.
 ///
@@ -184,6 +207,108 @@ pub fn resolve_worktree_arg(
     Ok(ResolvedWorktree::BranchOnly { branch })
 }
 
+/// Handle `wt switch --print-path`: print an existing worktree's absolute path to stdout.
+///
+/// Resolves names like `wt switch` (shortcuts, then fuzzy matching unless `exact`),
+/// but never creates a worktree, runs hooks, or writes a `cd` directive.
+pub fn print_worktree_path(
+    name: &str,
+    exact: bool,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let name = if exact {
+        name.to_string()
+    } else {
+        resolve_fuzzy_branch(&repo, name)?
+    };
+    match resolve_worktree_arg(&repo, &name, config, ResolutionContext::Remove)? {
+        ResolvedWorktree::Worktree { path, .. } => {
+            crate::output::stdout(path.display().to_string())?;
+            Ok(())
+        }
+        ResolvedWorktree::BranchOnly { branch } => Err(GitError::NoWorktreeFound { branch }.into()),
+    }
+}
+
+/// Resolve `reference` (branch, tag, or SHA) to a full commit SHA
+pub fn resolve_commit(repo: &Repository, reference: &str) -> anyhow::Result<String> {
+    let commit = repo
+        .run_command(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{reference}^{{commit}}"),
+        ])
+        .map_err(|_| GitError::UnresolvableReference {
+            reference: reference.to_string(),
+        })?;
+    Ok(commit.trim().to_string())
+}
+
+/// Check `wt switch --branch-from` before any hooks run or worktrees are made.
+///
+/// Unlike `--base`, which is ignored with a warning when nothing is created,
+/// `--branch-from` is an error unless `--create` makes a new branch, and the
+/// reference must resolve to a commit.
+pub fn validate_branch_from(branch: &str, create: bool, reference: &str) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let branch = repo.resolve_worktree_name(branch)?;
+    let exists = repo.local_branch_exists(&branch)?;
+    if !create || exists {
+        return Err(GitError::BranchFromWithoutCreate {
+            branch,
+            reference: reference.to_string(),
+            exists,
+        }
+        .into());
+    }
+    resolve_commit(&repo, &repo.resolve_worktree_name(reference)?)?;
+    Ok(())
+}
+
+/// Handle `wt switch --detach`: enter a scratch worktree at `reference` in detached HEAD.
+///
+/// The scratch worktree is named after the commit (`detached-<short sha>`) and placed
+/// by the `worktree-path` template, so switching to the same commit again reuses it.
+/// Hooks don't run; there's no branch for them to describe.
+pub fn handle_switch_detach(reference: &str, config: &WorktrunkConfig) -> anyhow::Result<()> {
+    let repo = Repository::current();
+    let commit = resolve_commit(&repo, reference)?;
+    let short_commit = &commit[..7];
+    let scratch_name = format!("detached-{short_commit}");
+    let worktree_path = compute_worktree_path(&repo, &scratch_name, config)?;
+
+    let created = match repo.worktree_at_path(&worktree_path)? {
+        Some((existing_path, _)) if existing_path.exists() => false,
+        Some(_) => {
+            return Err(GitError::WorktreeMissing {
+                branch: scratch_name,
+            }
+            .into());
+        }
+        None => {
+            if worktree_path.exists() {
+                anyhow::bail!(
+                    "Directory already exists: {}",
+                    worktrunk::path::format_path_for_display(&worktree_path)
+                );
+            }
+            let worktree_path_str = worktree_path.to_string_lossy();
+            repo.run_command(&["worktree", "add", "--detach", &worktree_path_str, &commit])
+                .map_err(|e| GitError::WorktreeCreationFailed {
+                    branch: scratch_name.clone(),
+                    base_branch: None,
+                    error: e.to_string(),
+                })?;
+            true
+        }
+    };
+
+    let worktree_path = canonicalize(&worktree_path).unwrap_or(worktree_path);
+    crate::output::handle_detach_output(&worktree_path, short_commit, created)
+}
+
 /// Compute the expected worktree path for a branch name.
 ///
 /// For the default branch, returns the repo root (main worktree location).
@@ -228,6 +353,16 @@ fn paths_match(a: &std::path::Path, b: &std::path::Path) -> bool {
     a_canonical == b_canonical
 }
 
+/// Where the current directory sits inside `worktree`, relative to its root.
+///
+/// Returns `None` when the current directory is outside `worktree`. Used to
+/// keep the shell in the same subdirectory after the worktree moves.
+pub fn current_dir_within(worktree: &std::path::Path) -> Option<PathBuf> {
+    let cwd = std::env::current_dir().ok()?;
+    let worktree = canonicalize(worktree).unwrap_or_else(|_| worktree.to_path_buf());
+    cwd.strip_prefix(&worktree).ok().map(PathBuf::from)
+}
+
 /// Check if a worktree is at its expected path, with pre-computed values.
 ///
 /// Use this when `default_branch` and `is_bare` are already known (e.g., in list command)
@@ -444,6 +579,66 @@ pub enum RemoveResult {
     },
 }
 
+/// Resolve a `wt switch` name that isn't a branch by fuzzy-matching worktree branches
+///
+/// Substring matches take priority over subsequence matches. One match is switched
+/// to; several are an error listing them. The name is returned unchanged if it's a
+/// shortcut, names a branch, or matches nothing (so the usual errors apply).
+pub fn resolve_fuzzy_branch(repo: &Repository, name: &str) -> anyhow::Result<String> {
+    if matches!(name, "@" | "-" | "^")
+        || repo.local_branch_exists(name)?
+        || !repo.remotes_with_branch(name)?.is_empty()
+    {
+        return Ok(name.to_string());
+    }
+
+    let branches: Vec<String> = repo
+        .list_worktrees()?
+        .into_iter()
+        .filter_map(|wt| wt.branch)
+        .collect();
+    let mut matches = fuzzy_branch_matches(name, &branches);
+    match matches.len() {
+        0 => Ok(name.to_string()),
+        1 => {
+            let branch = matches.remove(0);
+            crate::output::print(info_message(cformat!(
+                "Matched <bold>{name}</> to <bold>{branch}</>"
+            )))?;
+            Ok(branch)
+        }
+        _ => Err(GitError::AmbiguousBranch {
+            query: name.to_string(),
+            candidates: matches,
+        }
+        .into()),
+    }
+}
+
+/// Branches containing `query` (case-insensitive), or failing that, containing its
+/// characters in order
+fn fuzzy_branch_matches(query: &str, branches: &[String]) -> Vec<String> {
+    let query = query.to_lowercase();
+    let substring: Vec<String> = branches
+        .iter()
+        .filter(|branch| branch.to_lowercase().contains(&query))
+        .cloned()
+        .collect();
+    if !substring.is_empty() {
+        return substring;
+    }
+
+    branches
+        .iter()
+        .filter(|branch| {
+            let branch = branch.to_lowercase();
+            let mut chars = branch.chars();
+            query.chars().all(|q| chars.any(|c| c == q))
+        })
+        .cloned()
+        .collect()
+}
+
 pub fn handle_switch(
     branch: &str,
     create: bool,
@@ -474,13 +669,14 @@ pub fn handle_switch(
         None
     };
 
-    // Check for conflicting conditions
-    if create && repo.local_branch_exists(&resolved_branch)? {
-        return Err(GitError::BranchAlreadyExists {
-            branch: resolved_branch.clone(),
-        }
-        .into());
+    // `--create` on a branch that already exists falls back to a plain switch
+    let branch_exists = create && repo.local_branch_exists(&resolved_branch)?;
+    if branch_exists && resolved_base.is_some() {
+        crate::output::print(warning_message(cformat!(
+            "Branch <bold>{resolved_branch}</> already exists, ignoring <bright-black>--base</>"
+        )))?;
     }
+    let create = create && !branch_exists;
 
     // Warn if --create would shadow a remote branch
     if create {
@@ -498,7 +694,7 @@ pub fn handle_switch(
     }
 
     // Check if base flag was provided without create flag
-    if resolved_base.is_some() && !create {
+    if resolved_base.is_some() && !create && !branch_exists {
         crate::output::print(warning_message(
             "--base flag is only used with --create, ignoring",
         ))?;
@@ -572,6 +768,24 @@ pub fn handle_switch(
     // No existing worktree for branch or at expected path - will create one
     let worktree_path = expected_path;
 
+    // Use the resolved base, or default to default branch if creating without a base.
+    // For bare repos with no branches yet (bootstrap case), allow None to create orphan branch.
+    let base_for_creation = if create {
+        match resolved_base {
+            Some(b) => Some(b),
+            None => {
+                // Try to use default branch as base, but only if it actually exists
+                // (has commits). For empty repos, the default branch is unborn and
+                // git will automatically create an orphan worktree.
+                repo.resolve_target_branch(None)
+                    .ok()
+                    .filter(|b| repo.local_branch_exists(b).unwrap_or(false))
+            }
+        }
+    } else {
+        None
+    };
+
     // If the target path already exists but is NOT a worktree (e.g., stale directory),
     // either move it to .bak (with --clobber) or surface a helpful error.
     if worktree_path.exists() {
@@ -611,29 +825,43 @@ pub fn handle_switch(
         }
     }
 
+    // Execute pre-create commands (sequential, blocking, fail-fast)
+    // The worktree doesn't exist yet, so hooks run from the repository root;
+    // `worktree_path` and `worktree_name` describe the worktree about to be created.
+    if !no_verify {
+        let repo_root = repo.worktree_base()?;
+        let ctx = CommandContext::new(
+            &repo,
+            config,
+            Some(&resolved_branch),
+            &repo_root,
+            &repo_root,
+            force,
+        );
+
+        let worktree_path_posix = worktrunk::path::to_posix_path(&worktree_path.to_string_lossy());
+        let worktree_name = worktree_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown");
+        let extra_vars: Vec<(&str, &str)> = [
+            Some(("worktree_path", worktree_path_posix.as_str())),
+            Some(("worktree_name", worktree_name)),
+            base_for_creation.as_ref().map(|b| ("base", b.as_str())),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        // Approval was handled at the gate
+        ctx.execute_pre_create_commands(&extra_vars)?;
+    }
+
     // Create the worktree
     // Build git worktree add command
     let worktree_path_str = worktree_path.to_string_lossy();
     let mut args = vec!["worktree", "add", worktree_path_str.as_ref()];
 
-    // Use the resolved base, or default to default branch if creating without a base.
-    // For bare repos with no branches yet (bootstrap case), allow None to create orphan branch.
-    let base_for_creation = if create {
-        match resolved_base {
-            Some(b) => Some(b),
-            None => {
-                // Try to use default branch as base, but only if it actually exists
-                // (has commits). For empty repos, the default branch is unborn and
-                // git will automatically create an orphan worktree.
-                repo.resolve_target_branch(None)
-                    .ok()
-                    .filter(|b| repo.local_branch_exists(b).unwrap_or(false))
-            }
-        }
-    } else {
-        None
-    };
-
     // Build args based on whether we're creating or checking out
     if create {
         args.push("-b");
@@ -754,6 +982,7 @@ pub fn handle_remove(
     no_delete_branch: bool,
     force_delete: bool,
     force_worktree: bool,
+    dirty: DirtyWorktree,
     background: bool,
     config: &WorktrunkConfig,
 ) -> anyhow::Result<RemoveResult> {
@@ -770,6 +999,7 @@ pub fn handle_remove(
         RemoveTarget::Branch(worktree_name),
         BranchDeletionMode::from_flags(no_delete_branch, force_delete),
         force_worktree,
+        dirty,
         config,
     )
 }
@@ -782,6 +1012,7 @@ pub fn handle_remove_current(
     no_delete_branch: bool,
     force_delete: bool,
     force_worktree: bool,
+    dirty: DirtyWorktree,
     background: bool,
     config: &WorktrunkConfig,
 ) -> anyhow::Result<RemoveResult> {
@@ -796,20 +1027,51 @@ pub fn handle_remove_current(
         RemoveTarget::Current,
         BranchDeletionMode::from_flags(no_delete_branch, force_delete),
         force_worktree,
+        dirty,
         config,
     )
 }
 
 impl<'a> CommandContext<'a> {
+    /// Execute pre-create commands sequentially (blocking, fail-fast)
+    ///
+    /// Runs user hooks first, then project hooks. A non-zero exit aborts worktree
+    /// creation before `git worktree add` runs, so nothing is left behind.
+    ///
+    /// `extra_vars`: Additional template variables (e.g., `worktree_path`, `base`).
+    pub fn execute_pre_create_commands(&self, extra_vars: &[(&str, &str)]) -> anyhow::Result<()> {
+        let project_config = self.repo.load_project_config()?;
+        super::hooks::run_hook_with_filter(
+            self,
+            self.config.hooks.pre_create.as_ref(),
+            project_config
+                .as_ref()
+                .and_then(|c| c.hooks.pre_create.as_ref()),
+            HookType::PreCreate,
+            extra_vars,
+            HookFailureStrategy::FailFast,
+            None,
+            None,
+        )
+        .map_err(worktrunk::git::add_hook_skip_hint)
+    }
+
     /// Execute post-create commands sequentially (blocking)
     ///
-    /// Runs user hooks first, then project hooks.
+    /// Writes the project's `[post-create-files]` first, then runs user hooks,
+    /// then project hooks.
     /// Shows path in hook announcements when shell integration isn't active (user's shell
     /// won't cd to the new worktree, so they need to know where hooks ran).
     ///
     /// `extra_vars`: Additional template variables (e.g., `base`, `base_worktree_path`).
     pub fn execute_post_create_commands(&self, extra_vars: &[(&str, &str)]) -> anyhow::Result<()> {
         let project_config = self.repo.load_project_config()?;
+        if let Some(files) = project_config
+            .as_ref()
+            .and_then(|c| c.post_create_files.as_ref())
+        {
+            self.write_post_create_files(files, extra_vars)?;
+        }
         super::hooks::run_hook_with_filter(
             self,
             self.config.hooks.post_create.as_ref(),
@@ -824,6 +1086,77 @@ impl<'a> CommandContext<'a> {
         )
     }
 
+    /// Write the project's `[post-create-files]` into the worktree
+    ///
+    /// The files are approved with the post-create hooks. Entries that can't be
+    /// written safely are skipped with a warning, like a failing post-create hook:
+    /// paths that would leave the worktree (including through a committed
+    /// symlink), and existing files unless the entry sets `overwrite`.
+    fn write_post_create_files(
+        &self,
+        files: &IndexMap<String, PostCreateFile>,
+        extra_vars: &[(&str, &str)],
+    ) -> anyhow::Result<()> {
+        let context = super::command_executor::build_hook_context(self, extra_vars);
+        let vars: HashMap<&str, &str> = context
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        let root = canonicalize(self.worktree_path)?;
+        let skip = |path: &str, reason: String| {
+            crate::output::print(warning_message(cformat!(
+                "Skipping post-create file <bold>{path}</>: {reason}"
+            )))
+        };
+        // Whether `dir` resolves (following symlinks) to somewhere in the worktree
+        let inside =
+            |dir: &std::path::Path| canonicalize(dir).is_ok_and(|dir| dir.starts_with(&root));
+
+        for (path, file) in files {
+            let target = root.join(path);
+            let parent = target.parent().unwrap_or(&root);
+            // Check the directories that already exist before creating the rest,
+            // so a symlinked component can't make us create directories elsewhere
+            let existing = parent.ancestors().find(|dir| dir.exists()).unwrap_or(&root);
+            if !is_contained_relative_path(path) || !inside(existing) {
+                skip(path, "path must be inside the worktree".to_string())?;
+                continue;
+            }
+            let metadata = std::fs::symlink_metadata(&target).ok();
+            if metadata
+                .as_ref()
+                .is_some_and(|m| m.file_type().is_symlink())
+            {
+                skip(path, "it's a symlink".to_string())?;
+                continue;
+            }
+            if metadata.is_some() && !file.overwrite() {
+                skip(
+                    path,
+                    cformat!("already exists (set <bold>overwrite = true</> to replace it)"),
+                )?;
+                continue;
+            }
+
+            let contents = match expand_template(file.template(), &vars, false) {
+                Ok(contents) => contents,
+                Err(e) => {
+                    skip(path, e.to_string())?;
+                    continue;
+                }
+            };
+            std::fs::create_dir_all(parent)?;
+            if !inside(parent) {
+                skip(path, "path must be inside the worktree".to_string())?;
+                continue;
+            }
+            std::fs::write(&target, contents)
+                .with_context(|| format!("Failed to write post-create file {path}"))?;
+            crate::output::print(success_message(cformat!("Wrote <bold>{path}</>")))?;
+        }
+        Ok(())
+    }
+
     /// Spawn post-start commands in parallel as background processes (non-blocking)
     ///
     /// `extra_vars`: Additional template variables (e.g., `base`, `base_worktree_path`).
@@ -879,6 +1212,102 @@ impl<'a> CommandContext<'a> {
 
         spawn_hook_commands_background(self, commands, HookType::PostSwitch)
     }
+
+    /// Export the project's `[env]` table into the user's shell
+    ///
+    /// Values are expanded with the same template variables as hooks. Only has an
+    /// effect with shell integration. Entries are approved with the switch hooks;
+    /// ones that can't be applied (variables the shell runs code from, invalid
+    /// names, multi-line values, template errors) are skipped with a warning,
+    /// since the worktree already exists by now.
+    pub fn export_project_env(&self) -> anyhow::Result<()> {
+        if !crate::output::is_shell_integration_active() {
+            return Ok(());
+        }
+        let Some(env) = self.repo.load_project_config()?.and_then(|c| c.env) else {
+            return Ok(());
+        };
+
+        let context = super::command_executor::build_hook_context(self, &[]);
+        let vars: HashMap<&str, &str> = context
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+
+        for (key, template) in &env {
+            if is_shell_control_var(key) {
+                crate::output::print(warning_message(cformat!(
+                    "Skipping env <bold>{key}</>: the shell uses it to find or run programs"
+                )))?;
+                continue;
+            }
+            let value = match expand_template(template, &vars, false) {
+                Ok(value) => value,
+                Err(e) => {
+                    crate::output::print(warning_message(cformat!(
+                        "Skipping env <bold>{key}</>: {e}"
+                    )))?;
+                    continue;
+                }
+            };
+            if let Err(e) = crate::output::set_env(key, &value) {
+                crate::output::print(warning_message(cformat!(
+                    "Skipping env <bold>{key}</>: {e}"
+                )))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Run the project's `open-editor-command` in the user's shell
+    ///
+    /// Emitted as an exec directive, so the wrapper runs it after the `cd` into the
+    /// worktree. Only has an effect with shell integration.
+    pub fn open_editor(&self) -> anyhow::Result<()> {
+        if !crate::output::is_shell_integration_active() {
+            return Ok(());
+        }
+        let Some(template) = self
+            .repo
+            .load_project_config()?
+            .and_then(|c| c.open_editor_command)
+        else {
+            return Ok(());
+        };
+        self.exec_template_in_shell("open-editor-command", &template)
+    }
+
+    /// Run the user's `post-switch-command` in their shell
+    ///
+    /// Like `open-editor-command`, it runs after the `cd`, in the user's shell
+    /// rather than a subprocess, so tools that hook the shell (direnv, nvm) act on
+    /// the new worktree. It comes from user config, so it needs no approval. Only
+    /// has an effect with shell integration.
+    pub fn run_post_switch_command(&self) -> anyhow::Result<()> {
+        if !crate::output::is_shell_integration_active() {
+            return Ok(());
+        }
+        let Some(template) = &self.config.post_switch_command else {
+            return Ok(());
+        };
+        self.exec_template_in_shell("post-switch-command", template)
+    }
+
+    /// Expand `template` with hook variables and emit it as an exec directive
+    fn exec_template_in_shell(&self, name: &str, template: &str) -> anyhow::Result<()> {
+        let context = super::command_executor::build_hook_context(self, &[]);
+        let vars: HashMap<&str, &str> = context
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+
+        let command = expand_template(template, &vars, true)
+            .map_err(|e| anyhow::anyhow!("Failed to expand {name}: {e}"))?;
+        if let Err(e) = crate::output::exec_in_shell(&command) {
+            crate::output::print(warning_message(format!("Skipping {name}: {e}")))?;
+        }
+        Ok(())
+    }
 }
 
 /// Push changes to target branch
@@ -1284,4 +1713,24 @@ mod tests {
         let backup = super::generate_backup_path(&path, "20250101-000000");
         assert_eq!(backup, PathBuf::from("/tmp/mydir.bak.20250101-000000"));
     }
+
+    #[test]
+    fn test_fuzzy_branch_matches() {
+        let branches: Vec<String> = ["main", "feature/login", "feature/logout", "fix-flaky"]
+            .map(String::from)
+            .to_vec();
+
+        // Substring, case-insensitive
+        assert_eq!(fuzzy_branch_matches("LOGIN", &branches), ["feature/login"]);
+        assert_eq!(
+            fuzzy_branch_matches("feat", &branches),
+            ["feature/login", "feature/logout"]
+        );
+        // Subsequence only when nothing contains the query
+        assert_eq!(fuzzy_branch_matches("flgn", &branches), ["feature/login"]);
+        // A substring match shadows subsequence matches ("fix-flaky" contains "fl",
+        // while the feature branches only have it as a subsequence)
+        assert_eq!(fuzzy_branch_matches("fl", &branches), ["fix-flaky"]);
+        assert!(fuzzy_branch_matches("xyz", &branches).is_empty());
+    }
 }
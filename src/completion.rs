@@ -39,7 +39,10 @@ pub fn maybe_handle_env_completion() -> bool {
     if args.is_empty() {
         // Use CompleteEnv for registration script generation
         let all_args: Vec<OsString> = std::env::args_os().collect();
-        let _ = CompleteEnv::with_factory(completion_command)
+        // Name the command after what the shell calls it, so the generated
+        // completer functions match the ones the init script looks for
+        let bin_name = completion_bin_name();
+        let _ = CompleteEnv::with_factory(|| completion_command().name(bin_name.clone()))
             .try_complete(all_args, current_dir.as_deref());
         CONTEXT.with(|ctx| ctx.borrow_mut().take());
         return true;
@@ -209,6 +212,7 @@ fn complete_hook_commands() -> Vec<CompletionCandidate> {
         ctx.borrow().as_ref().and_then(|ctx| {
             // Look for the hook subcommand in the args
             for hook in &[
+                "pre-create",
                 "post-create",
                 "post-start",
                 "pre-commit",
@@ -365,6 +369,18 @@ thread_local! {
     static CONTEXT: RefCell<Option<CompletionContext>> = const { RefCell::new(None) };
 }
 
+/// Command name the registration script registers completions for.
+///
+/// Shell init passes its `--cmd` via `WORKTRUNK_COMPLETE_CMD`, since the binary it
+/// calls (e.g., through `WORKTRUNK_BIN`) may be named differently; otherwise the
+/// name this binary was invoked as.
+fn completion_bin_name() -> String {
+    std::env::var("WORKTRUNK_COMPLETE_CMD")
+        .ok()
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(crate::binary_name)
+}
+
 fn completion_command() -> Command {
     let cmd = cli::build_command();
     hide_non_positional_options_for_completion(cmd)
@@ -19,6 +19,8 @@
 //!   {{ (repo ~ "-" ~ branch) | hash_port }} → 15839
 //!   ```
 
+use std::path::PathBuf;
+
 use minijinja::{Environment, Value};
 
 /// Known template variables available in hook commands.
@@ -26,7 +28,7 @@ use minijinja::{Environment, Value};
 /// These are populated by `build_hook_context()` in `command_executor.rs`.
 /// Some variables are conditional (e.g., `upstream` only exists if tracking is configured).
 ///
-/// This list is the single source of truth for `--var` validation in CLI.
+/// `--var` accepts these and any other key; unknown keys become extra template variables.
 pub const TEMPLATE_VARS: &[&str] = &[
     "repo",
     "branch",
@@ -78,6 +80,44 @@ pub fn sanitize_branch_name(branch: &str) -> String {
     branch.replace(['/', '\\'], "-")
 }
 
+/// Expand `~` and environment variables in a path from config.
+///
+/// `~` and `~/…` become the home directory; `~user` forms are left as is.
+/// `$VAR` and `${VAR}` are replaced with the variable's value, or with nothing
+/// (logging a warning) when it isn't set, so a typo doesn't leave a literal
+/// `$VAR` directory behind.
+///
+/// # Examples
+/// ```
+/// use worktrunk::config::expand_path;
+///
+/// assert_eq!(expand_path("/tmp/logs").to_str(), Some("/tmp/logs"));
+/// assert_eq!(expand_path("~other/logs").to_str(), Some("~other/logs"));
+/// ```
+pub fn expand_path(path: &str) -> PathBuf {
+    let home = crate::path::home_dir().map(|home| home.to_string_lossy().into_owned());
+    expand_path_with(path, home, |name| std::env::var(name).ok())
+}
+
+/// [`expand_path`] with the home directory and variable lookup supplied by the caller
+fn expand_path_with(
+    path: &str,
+    home: Option<String>,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> PathBuf {
+    let expanded = shellexpand::full_with_context_no_errors(
+        path,
+        || home,
+        |name| {
+            Some(lookup(name).unwrap_or_else(|| {
+                log::warn!("Environment variable ${name} in config path {path} is not set");
+                String::new()
+            }))
+        },
+    );
+    PathBuf::from(expanded.as_ref())
+}
+
 /// Expand a template with variable substitution.
 ///
 /// # Arguments
@@ -154,6 +194,34 @@ pub fn expand_template(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_expand_path() {
+        let home = || Some("/home/me".to_string());
+        let lookup = |name: &str| match name {
+            "HOME" => Some("/home/me".to_string()),
+            "PROJECT" => Some("app".to_string()),
+            _ => None,
+        };
+        let expand = |path| expand_path_with(path, home(), lookup);
+
+        assert_eq!(expand("~"), PathBuf::from("/home/me"));
+        assert_eq!(expand("~/logs"), PathBuf::from("/home/me/logs"));
+        assert_eq!(expand("${HOME}/x"), PathBuf::from("/home/me/x"));
+        assert_eq!(
+            expand("${HOME}/$PROJECT/x"),
+            PathBuf::from("/home/me/app/x")
+        );
+        // Unset variables expand to nothing rather than staying literal
+        assert_eq!(expand("/tmp/$UNSET/x"), PathBuf::from("/tmp//x"));
+        // Other users' home directories aren't resolved
+        assert_eq!(expand("~user/x"), PathBuf::from("~user/x"));
+        // Without a home directory, `~` is left as is
+        assert_eq!(
+            expand_path_with("~/logs", None, lookup),
+            PathBuf::from("~/logs")
+        );
+    }
+
     #[test]
     fn test_sanitize_branch_name() {
         let cases = [
@@ -166,6 +234,8 @@ mod tests {
             ("///", "---"),
             ("/feature", "-feature"),
             ("feature/", "feature-"),
+            // Unicode is valid in both ref names and paths, so it's kept
+            ("feat/über-日本", "feat-über-日本"),
         ];
         for (input, expected) in cases {
             assert_eq!(sanitize_branch_name(input), expected, "input: {input}");
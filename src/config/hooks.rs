@@ -7,6 +7,14 @@ use super::commands::CommandConfig;
 /// Shared hook configuration for user and project configs.
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct HooksConfig {
+    /// Commands to execute before worktree creation (blocking, fail-fast)
+    #[serde(
+        default,
+        rename = "pre-create",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pre_create: Option<CommandConfig>,
+
     /// Commands to execute after worktree creation (blocking)
     #[serde(
         default,
@@ -63,6 +71,7 @@ pub struct HooksConfig {
 impl HooksConfig {
     pub fn get(&self, hook: HookType) -> Option<&CommandConfig> {
         match hook {
+            HookType::PreCreate => self.pre_create.as_ref(),
             HookType::PostCreate => self.post_create.as_ref(),
             HookType::PostStart => self.post_start.as_ref(),
             HookType::PostSwitch => self.post_switch.as_ref(),
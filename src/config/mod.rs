@@ -29,15 +29,16 @@ pub use commands::{Command, CommandConfig};
 pub use deprecation::check_and_migrate as check_deprecated_vars;
 pub use deprecation::normalize_template_vars;
 pub use expansion::{
-    DEPRECATED_TEMPLATE_VARS, TEMPLATE_VARS, expand_template, sanitize_branch_name,
+    DEPRECATED_TEMPLATE_VARS, TEMPLATE_VARS, expand_path, expand_template, sanitize_branch_name,
 };
 pub use hooks::HooksConfig;
 pub use project::{
-    ProjectCiConfig, ProjectConfig, ProjectListConfig,
-    find_unknown_keys as find_unknown_project_keys,
+    PROJECT_CONFIG_FILES, PostCreateFile, ProjectCiConfig, ProjectCommitGenerationConfig,
+    ProjectConfig, ProjectListConfig, find_unknown_keys as find_unknown_project_keys,
+    is_contained_relative_path, is_shell_control_var,
 };
 pub use user::{
-    CommitGenerationConfig, StageMode, UserProjectConfig, WorktrunkConfig,
+    CommitGenerationConfig, PromptVia, StageMode, UserProjectConfig, WorktrunkConfig,
     find_unknown_keys as find_unknown_user_keys, get_config_path, set_config_path,
 };
 
@@ -102,6 +103,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_worktree_path_expands_home_not_branch() {
+        let Some(home) = crate::path::home_dir() else {
+            return;
+        };
+        let config = WorktrunkConfig {
+            worktree_path: Some("~/worktrees/{{ branch }}".to_string()),
+            ..Default::default()
+        };
+        // `$` in a branch name is kept: only the template itself is expanded
+        assert_eq!(
+            config.format_path("myproject", "fix-$HOME").unwrap(),
+            format!("{}/worktrees/fix-$HOME", home.to_string_lossy())
+        );
+    }
+
     #[test]
     fn test_format_worktree_path_only_branch() {
         let config = WorktrunkConfig {
@@ -664,9 +681,7 @@ squash-template-file = "~/file.txt"
             command: Some("llm".to_string()),
             args: vec!["-m".to_string(), "model".to_string()],
             template: Some("template content".to_string()),
-            template_file: None,
-            squash_template: None,
-            squash_template_file: None,
+            ..Default::default()
         };
 
         let toml = toml::to_string(&config).unwrap();
@@ -2,10 +2,15 @@
 //!
 //! Configuration that is checked into the repository and shared across all developers.
 
+use std::path::{Path, PathBuf};
+
 use config::ConfigError;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
-use super::HooksConfig;
+use super::{CommitGenerationConfig, HooksConfig};
+use crate::git::HookType;
+use clap::ValueEnum;
 
 /// Project-level configuration for `wt list` output.
 ///
@@ -52,6 +57,177 @@ pub struct ProjectCiConfig {
     pub platform: Option<String>,
 }
 
+/// Project-level overrides for the user's `[commit-generation]` settings.
+///
+/// Each field that is set replaces the user's value; unset fields are inherited.
+/// A project can share a prompt template while each developer keeps their own
+/// LLM command.
+///
+/// # Example
+///
+/// ```toml
+/// [commit-generation]
+/// template = "Write a conventional commit message for:\n{{ git_diff }}"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ProjectCommitGenerationConfig {
+    /// Command to invoke instead of the user's (requires approval, like hooks)
+    #[serde(default)]
+    pub command: Option<String>,
+
+    /// Arguments to pass to the command (requires approval, like hooks)
+    #[serde(default)]
+    pub args: Option<Vec<String>>,
+
+    /// Model to request from the command
+    #[serde(default)]
+    pub model: Option<String>,
+
+    /// Inline template for commit message prompt
+    #[serde(default)]
+    pub template: Option<String>,
+
+    /// Path to template file (mutually exclusive with template)
+    #[serde(default, rename = "template-file")]
+    pub template_file: Option<String>,
+
+    /// Inline template for squash commit message prompt
+    #[serde(default, rename = "squash-template")]
+    pub squash_template: Option<String>,
+
+    /// Path to squash template file (mutually exclusive with squash-template)
+    #[serde(default, rename = "squash-template-file")]
+    pub squash_template_file: Option<String>,
+}
+
+impl ProjectCommitGenerationConfig {
+    /// Returns true if the project replaces the command that gets run.
+    pub fn overrides_command(&self) -> bool {
+        self.command.is_some() || self.args.is_some()
+    }
+
+    /// Apply these overrides over the user's settings.
+    ///
+    /// A template and its file form are one setting, so a project `template`
+    /// also clears the user's `template-file` (and likewise for squash).
+    pub fn apply_to(&self, base: &mut CommitGenerationConfig) {
+        if let Some(command) = &self.command {
+            base.command = Some(command.clone());
+        }
+        if let Some(args) = &self.args {
+            base.args = args.clone();
+        }
+        if let Some(model) = &self.model {
+            base.model = Some(model.clone());
+        }
+        if self.template.is_some() || self.template_file.is_some() {
+            base.template = self.template.clone();
+            base.template_file = self.template_file.clone();
+        }
+        if self.squash_template.is_some() || self.squash_template_file.is_some() {
+            base.squash_template = self.squash_template.clone();
+            base.squash_template_file = self.squash_template_file.clone();
+        }
+    }
+}
+
+/// A `[post-create-files]` entry: a template, or a table with options
+///
+/// ```toml
+/// [post-create-files]
+/// ".env.local" = "DATABASE_URL=postgres://localhost/{{ branch | sanitize }}"
+/// ".vscode/settings.json" = { template = "{}", overwrite = true }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum PostCreateFile {
+    Template(String),
+    Options {
+        template: String,
+        /// Replace the file if the worktree already has it (default: false)
+        #[serde(default)]
+        overwrite: bool,
+    },
+}
+
+impl PostCreateFile {
+    /// Template for the file's contents
+    pub fn template(&self) -> &str {
+        match self {
+            Self::Template(template) | Self::Options { template, .. } => template,
+        }
+    }
+
+    /// Whether an existing file is replaced
+    pub fn overwrite(&self) -> bool {
+        matches!(
+            self,
+            Self::Options {
+                overwrite: true,
+                ..
+            }
+        )
+    }
+}
+
+/// Whether `[env]` is forbidden from setting `name`
+///
+/// These are variables the shell itself reads to find programs, run code, or
+/// parse commands (`PATH`, `PROMPT_COMMAND`, prompt strings like `PS1`, `IFS`,
+/// zsh hook arrays, `LD_PRELOAD`, ...), so a project could use them to run
+/// arbitrary code in the user's shell.
+pub fn is_shell_control_var(name: &str) -> bool {
+    matches!(
+        name,
+        "PATH"
+            | "CDPATH"
+            | "IFS"
+            | "GLOBIGNORE"
+            | "PROMPT_COMMAND"
+            | "BASH_ENV"
+            | "ENV"
+            | "SHELLOPTS"
+            | "BASHOPTS"
+            | "ZDOTDIR"
+            | "FPATH"
+            | "fpath"
+            | "PROMPT"
+            | "PROMPT2"
+            | "PROMPT3"
+            | "PROMPT4"
+            | "RPROMPT"
+            | "RPROMPT2"
+            | "RPS1"
+            | "RPS2"
+            | "precmd_functions"
+            | "preexec_functions"
+            | "chpwd_functions"
+            | "fish_function_path"
+            | "fish_complete_path"
+            | "fish_user_paths"
+    ) || name.starts_with("LD_")
+        || name.starts_with("DYLD_")
+        || name
+            .strip_prefix("PS")
+            .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Whether `path` names a file inside the directory it's relative to
+///
+/// Rejects absolute paths, `..` components, and anything under `.git`. This is
+/// only a lexical check: callers writing the file must still make sure no
+/// symlink leads outside the directory.
+pub fn is_contained_relative_path(path: &str) -> bool {
+    use std::path::Component;
+    let mut components = Path::new(path)
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .peekable();
+    components.peek().is_some()
+        && components
+            .all(|component| matches!(component, Component::Normal(name) if name != ".git"))
+}
+
 impl ProjectListConfig {
     /// Returns true if any list configuration is set.
     pub fn is_configured(&self) -> bool {
@@ -66,10 +242,13 @@ impl ProjectConfig {
     }
 }
 
+/// Project config file names, relative to a directory, in order of precedence
+pub const PROJECT_CONFIG_FILES: [&str; 3] = [".config/wt.toml", ".wt.toml", "wt.toml"];
+
 /// Project-specific configuration with hooks.
 ///
-/// This config is stored at `<repo>/.config/wt.toml` within the repository and
-/// IS checked into git. It defines project-specific hooks that run automatically
+/// This config is stored at `<repo>/.config/wt.toml` (or `.wt.toml` / `wt.toml`,
+/// see [`PROJECT_CONFIG_FILES`]) within the repository and IS checked into git. It defines project-specific hooks that run automatically
 /// during worktree operations. All developers working on the project share this config.
 ///
 /// # Template Variables
@@ -96,6 +275,15 @@ impl ProjectConfig {
 /// - `{{ branch | hash_port }}` - Hash string to deterministic port (10000-19999)
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct ProjectConfig {
+    /// Other config files merged underneath this one
+    ///
+    /// Paths are relative to the including file; `~` and environment variables
+    /// are expanded. Included files are merged in order, then this file on top:
+    /// later values win, and tables (like `[post-start]` or `[env]`) merge key by
+    /// key. Included files can include others.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+
     /// Project hooks (same keys as user hooks, flattened at top level)
     #[serde(flatten, default)]
     pub hooks: HooksConfig,
@@ -108,51 +296,320 @@ pub struct ProjectConfig {
     #[serde(default)]
     pub ci: Option<ProjectCiConfig>,
 
+    /// Maximum number of post-start commands to run at once with `--parallel`
+    ///
+    /// Defaults to the number of available CPUs.
+    #[serde(default, rename = "post-start-parallelism")]
+    pub post_start_parallelism: Option<usize>,
+
+    /// Environment variables exported into the shell after `wt switch`
+    ///
+    /// Values are templates with the same variables as hooks. Requires shell
+    /// integration, since only the shell wrapper can modify the parent shell.
+    /// Skipped along with hooks (`--no-verify`, or declined approval).
+    #[serde(default)]
+    pub env: Option<IndexMap<String, String>>,
+
+    /// Files written into a new worktree before `post-create` hooks run
+    ///
+    /// Keys are paths relative to the worktree; values are templates with the
+    /// same variables as hooks. Existing files are left alone unless the entry
+    /// sets `overwrite = true`. Skipped along with hooks.
+    #[serde(default, rename = "post-create-files")]
+    pub post_create_files: Option<IndexMap<String, PostCreateFile>>,
+
+    /// Command run in the user's shell after `wt switch`, from the new worktree
+    ///
+    /// Typically opens an editor (e.g., `code .`). Expanded with the same template
+    /// variables as hooks and approved alongside them. Requires shell integration,
+    /// and is skipped along with hooks (`--no-verify`, or declined approval).
+    #[serde(default, rename = "open-editor-command")]
+    pub open_editor_command: Option<String>,
+
+    /// Directory where foreground hook commands also log their output
+    ///
+    /// Each command writes `{branch}-{source}-{hook}-{name}-{timestamp}.log` while
+    /// still streaming to the terminal. Relative paths are resolved from the
+    /// repository root; `~` and environment variables are expanded.
+    #[serde(default, rename = "hook-log-dir")]
+    pub hook_log_dir: Option<String>,
+
+    /// Overrides for the user's `[commit-generation]` settings
+    #[serde(default, rename = "commit-generation")]
+    pub commit_generation: Option<ProjectCommitGenerationConfig>,
+
     /// Captures unknown fields for validation warnings
     #[serde(flatten, default, skip_serializing)]
     unknown: std::collections::HashMap<String, toml::Value>,
 }
 
 impl ProjectConfig {
-    /// Load project configuration from .config/wt.toml in the repository root
+    /// Load project configuration found by [`find_path`](Self::find_path)
     ///
     /// Set `write_hints` to true for normal usage. Set to false during completion
-    /// to avoid side effects (writing git config hints).
+    /// to avoid side effects (writing git config hints, warning about shadowed files).
     pub fn load(
         repo: &crate::git::Repository,
         write_hints: bool,
     ) -> Result<Option<Self>, ConfigError> {
-        let repo_root = repo
-            .worktree_root()
-            .map_err(|e| ConfigError::Message(format!("Failed to get worktree root: {}", e)))?;
-        let config_path = repo_root.join(".config").join("wt.toml");
-
-        if !config_path.exists() {
+        let found = Self::find_paths(repo)?;
+        let Some(config_path) = found.first() else {
             return Ok(None);
+        };
+        if write_hints {
+            for ignored in &found[1..] {
+                crate::styling::eprintln!(
+                    "{}",
+                    crate::styling::warning_message(color_print::cformat!(
+                        "Ignoring <bold>{}</>; <bold>{}</> takes precedence",
+                        crate::path::format_path_for_display(ignored),
+                        crate::path::format_path_for_display(config_path)
+                    ))
+                );
+            }
         }
 
         // Load directly with toml crate to preserve insertion order (with preserve_order feature)
-        let contents = std::fs::read_to_string(&config_path)
+        let contents = std::fs::read_to_string(config_path)
             .map_err(|e| ConfigError::Message(format!("Failed to read config file: {}", e)))?;
 
         // Check for deprecated template variables and create migration file if needed
         // Only write migration file in main worktree (where .git is a directory)
         // Linked worktrees have .git as a file pointing to the main worktree
-        let is_main_worktree = repo_root.join(".git").is_dir();
+        let config_dir = if config_path.ends_with(Path::new(".config").join("wt.toml")) {
+            config_path.parent().and_then(Path::parent)
+        } else {
+            config_path.parent()
+        };
+        let is_main_worktree = config_dir.is_some_and(|root| root.join(".git").is_dir());
         let repo_for_hints = if write_hints { Some(repo) } else { None };
         let _ = super::deprecation::check_and_migrate(
-            &config_path,
+            config_path,
             &contents,
             is_main_worktree,
             "Project config",
             repo_for_hints,
         );
 
-        let config: ProjectConfig = toml::from_str(&contents)
+        let mut table: toml::Table = toml::from_str(&contents)
+            .map_err(|e| ConfigError::Message(format!("Failed to parse TOML: {}", e)))?;
+        let canonical = dunce::canonicalize(config_path).unwrap_or_else(|_| config_path.clone());
+        merge_includes(&canonical, &mut table, &mut vec![canonical.clone()])?;
+        let config: ProjectConfig = table
+            .try_into()
             .map_err(|e| ConfigError::Message(format!("Failed to parse TOML: {}", e)))?;
 
         Ok(Some(config))
     }
+
+    /// Locate the project config file for the repository
+    ///
+    /// Searches the directory the repository was opened at and each parent up to
+    /// the worktree root, then the worktree base (the main worktree, or the bare
+    /// repository directory), so commands run from a nested subdirectory or a bare
+    /// setup still find the config. Returns the first file that exists; within a
+    /// directory, [`PROJECT_CONFIG_FILES`] order decides.
+    pub fn find_path(repo: &crate::git::Repository) -> Result<Option<PathBuf>, ConfigError> {
+        Ok(Self::find_paths(repo)?.into_iter().next())
+    }
+
+    /// All config files in the first directory that has any, in precedence order
+    fn find_paths(repo: &crate::git::Repository) -> Result<Vec<PathBuf>, ConfigError> {
+        let repo_root = repo
+            .worktree_root()
+            .map_err(|e| ConfigError::Message(format!("Failed to get worktree root: {}", e)))?;
+        let config_files = |dir: &Path| -> Vec<PathBuf> {
+            PROJECT_CONFIG_FILES
+                .iter()
+                .map(|name| dir.join(name))
+                .filter(|path| path.is_file())
+                .collect()
+        };
+
+        // Only walk up from directories inside this worktree
+        let start = dunce::canonicalize(repo.base_path()).unwrap_or_else(|_| repo_root.into());
+        let dirs: Vec<&Path> = if start.starts_with(repo_root) {
+            let depth = start
+                .ancestors()
+                .take_while(|dir| *dir != repo_root)
+                .count();
+            start.ancestors().take(depth + 1).collect()
+        } else {
+            vec![repo_root]
+        };
+        for dir in dirs {
+            let found = config_files(dir);
+            if !found.is_empty() {
+                return Ok(found);
+            }
+        }
+
+        Ok(repo
+            .worktree_base()
+            .map(|base| config_files(&base))
+            .unwrap_or_default())
+    }
+
+    /// Check the config for mistakes that parsing alone doesn't catch
+    ///
+    /// Returns every problem found (empty if the config is valid), so they can
+    /// all be reported at once rather than one per run.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        let mut unknown: Vec<_> = self.unknown.keys().collect();
+        unknown.sort();
+        for key in unknown {
+            errors.push(format!("Unknown key `{key}`"));
+        }
+
+        for &hook_type in HookType::value_variants() {
+            let Some(config) = self.hooks.get(hook_type) else {
+                continue;
+            };
+            for command in config.commands() {
+                if command.template.trim().is_empty() {
+                    errors.push(match &command.name {
+                        Some(name) => format!("`{hook_type}` command `{name}` is empty"),
+                        None => format!("`{hook_type}` command is empty"),
+                    });
+                }
+            }
+        }
+
+        if self.post_start_parallelism == Some(0) {
+            errors.push("`post-start-parallelism` must be at least 1".to_string());
+        }
+
+        if self
+            .hook_log_dir
+            .as_ref()
+            .is_some_and(|dir| dir.trim().is_empty())
+        {
+            errors.push("`hook-log-dir` is empty".to_string());
+        }
+
+        if let Some(generation) = &self.commit_generation {
+            if generation.template.is_some() && generation.template_file.is_some() {
+                errors.push(
+                    "`commit-generation` sets both `template` and `template-file`".to_string(),
+                );
+            }
+            if generation.squash_template.is_some() && generation.squash_template_file.is_some() {
+                errors.push(
+                    "`commit-generation` sets both `squash-template` and `squash-template-file`"
+                        .to_string(),
+                );
+            }
+        }
+
+        for key in self.env.iter().flat_map(|env| env.keys()) {
+            if is_shell_control_var(key) {
+                errors.push(format!(
+                    "`env` can't set `{key}`: the shell uses it to find or run programs"
+                ));
+            }
+        }
+
+        for path in self.post_create_files.iter().flat_map(|files| files.keys()) {
+            if !is_contained_relative_path(path) {
+                errors.push(format!(
+                    "`post-create-files` path `{path}` must be relative to the worktree, without `..` or `.git`"
+                ));
+            }
+        }
+
+        if let Some(command) = &self.open_editor_command {
+            if command.trim().is_empty() {
+                errors.push("`open-editor-command` is empty".to_string());
+            } else if command.contains(['\n', '\r']) {
+                errors.push("`open-editor-command` must be a single line".to_string());
+            }
+        }
+
+        errors
+    }
+}
+
+/// Merge the files listed in `table`'s `include` key underneath it
+///
+/// `path` is the file `table` was read from; `loading` is the chain of files
+/// being loaded, so an include that leads back to one of them is reported as a
+/// cycle (including the same file from two places is fine).
+fn merge_includes(
+    path: &Path,
+    table: &mut toml::Table,
+    loading: &mut Vec<PathBuf>,
+) -> Result<(), ConfigError> {
+    let display = |path: &Path| crate::path::format_path_for_display(path);
+    let Some(include) = table.get("include") else {
+        return Ok(());
+    };
+    let includes: Vec<String> = include.clone().try_into().map_err(|_| {
+        ConfigError::Message(format!(
+            "`include` in {} must be a list of paths",
+            display(path)
+        ))
+    })?;
+    let dir = path.parent().unwrap_or(Path::new("."));
+
+    let mut merged = toml::Table::new();
+    for include in includes {
+        let included = dir.join(super::expand_path(&include));
+        let included = dunce::canonicalize(&included).map_err(|e| {
+            ConfigError::Message(format!(
+                "Failed to read {} (included from {}): {}",
+                display(&included),
+                display(path),
+                e
+            ))
+        })?;
+        if loading.contains(&included) {
+            let chain: Vec<_> = loading
+                .iter()
+                .chain([&included])
+                .map(|path| display(path))
+                .collect();
+            return Err(ConfigError::Message(format!(
+                "Cyclic include in project config: {}",
+                chain.join(" → ")
+            )));
+        }
+
+        let contents = std::fs::read_to_string(&included).map_err(|e| {
+            ConfigError::Message(format!("Failed to read {}: {}", display(&included), e))
+        })?;
+        let mut included_table: toml::Table = toml::from_str(&contents).map_err(|e| {
+            ConfigError::Message(format!(
+                "Failed to parse TOML in {}: {}",
+                display(&included),
+                e
+            ))
+        })?;
+        loading.push(included.clone());
+        merge_includes(&included, &mut included_table, loading)?;
+        loading.pop();
+        included_table.remove("include");
+        merge_tables(&mut merged, included_table);
+    }
+
+    merge_tables(&mut merged, std::mem::take(table));
+    *table = merged;
+    Ok(())
+}
+
+/// Merge `overlay` into `base`: tables merge recursively, other values replace
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overlay)) => {
+                merge_tables(base, overlay)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
 }
 
 /// Find unknown keys in project config TOML content
@@ -172,6 +629,74 @@ pub fn find_unknown_keys(contents: &str) -> Vec<String> {
 mod tests {
     use super::*;
 
+    // ============================================================================
+    // Validation Tests
+    // ============================================================================
+
+    #[test]
+    fn test_validate_valid_config() {
+        let contents = r#"
+post-start-parallelism = 2
+
+[post-start]
+build = "cargo build"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert!(config.validate().is_empty());
+    }
+
+    #[test]
+    fn test_validate_reports_all_errors() {
+        let contents = r#"
+pre-merge = "  "
+post-start-parallelism = 0
+post-creat = "npm install"
+
+[post-start]
+build = "cargo build"
+server = ""
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert_eq!(
+            config.validate(),
+            [
+                "Unknown key `post-creat`",
+                "`post-start` command `server` is empty",
+                "`pre-merge` command is empty",
+                "`post-start-parallelism` must be at least 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_commit_generation_overrides_field_by_field() {
+        let contents = r#"
+[commit-generation]
+template = "Project template: {{ git_diff }}"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        let overrides = config.commit_generation.unwrap();
+        assert!(!overrides.overrides_command());
+
+        let mut generation = CommitGenerationConfig {
+            command: Some("llm".to_string()),
+            args: vec!["-m".to_string(), "haiku".to_string()],
+            template_file: Some("~/template.txt".to_string()),
+            squash_template: Some("User squash".to_string()),
+            ..Default::default()
+        };
+        overrides.apply_to(&mut generation);
+
+        assert_eq!(generation.command.as_deref(), Some("llm"));
+        assert_eq!(generation.args, ["-m", "haiku"]);
+        assert_eq!(
+            generation.template.as_deref(),
+            Some("Project template: {{ git_diff }}")
+        );
+        assert_eq!(generation.template_file, None);
+        assert_eq!(generation.squash_template.as_deref(), Some("User squash"));
+    }
+
     // ============================================================================
     // ProjectConfig Default Tests
     // ============================================================================
@@ -188,6 +713,10 @@ mod tests {
         assert!(config.hooks.pre_remove.is_none());
         assert!(config.list.is_none());
         assert!(config.ci.is_none());
+        assert!(config.env.is_none());
+        assert!(config.open_editor_command.is_none());
+        assert!(config.post_create_files.is_none());
+        assert!(config.post_start_parallelism.is_none());
     }
 
     // ============================================================================
@@ -353,6 +882,149 @@ platform = "gitlab"
         assert!(config.platform.is_none());
     }
 
+    #[test]
+    fn test_deserialize_post_start_parallelism() {
+        let contents = r#"
+post-start-parallelism = 2
+
+[post-start]
+db = "docker compose up db"
+web = "npm run dev"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert_eq!(config.post_start_parallelism, Some(2));
+        assert!(config.hooks.post_start.is_some());
+        assert!(find_unknown_keys(contents).is_empty());
+    }
+
+    // ============================================================================
+    // Env Tests
+    // ============================================================================
+
+    #[test]
+    fn test_deserialize_env_preserves_order() {
+        let contents = r#"
+[env]
+VIRTUAL_ENV = "{{ worktree_path }}/.venv"
+DATABASE_URL = "postgres://localhost/app?sslmode=disable"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        let env = config.env.unwrap();
+        let keys: Vec<_> = env.keys().map(String::as_str).collect();
+        assert_eq!(keys, ["VIRTUAL_ENV", "DATABASE_URL"]);
+        assert_eq!(
+            env["DATABASE_URL"],
+            "postgres://localhost/app?sslmode=disable"
+        );
+        assert!(find_unknown_keys(contents).is_empty());
+    }
+
+    #[test]
+    fn test_env_rejects_shell_control_vars() {
+        for name in [
+            "PATH",
+            "PROMPT_COMMAND",
+            "PS1",
+            "PS4",
+            "LD_PRELOAD",
+            "DYLD_INSERT_LIBRARIES",
+            "BASH_ENV",
+            "ENV",
+            "ZDOTDIR",
+            "IFS",
+            "CDPATH",
+            "GLOBIGNORE",
+            "SHELLOPTS",
+            "BASHOPTS",
+            "FPATH",
+            "fpath",
+            "PROMPT",
+            "PROMPT2",
+            "RPROMPT",
+            "RPS1",
+            "precmd_functions",
+            "preexec_functions",
+            "chpwd_functions",
+            "fish_function_path",
+            "fish_complete_path",
+            "fish_user_paths",
+        ] {
+            assert!(is_shell_control_var(name), "{name}");
+        }
+        for name in [
+            "VIRTUAL_ENV",
+            "PSQL_HOST",
+            "PS",
+            "DATABASE_URL",
+            "MY_PATH",
+            "PROMPT_THEME",
+        ] {
+            assert!(!is_shell_control_var(name), "{name}");
+        }
+
+        let config: ProjectConfig =
+            toml::from_str("[env]\nPATH = \"/tmp\"\nNODE_ENV = \"dev\"").unwrap();
+        assert_eq!(
+            config.validate(),
+            ["`env` can't set `PATH`: the shell uses it to find or run programs"]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_open_editor_command() {
+        let contents = r#"open-editor-command = "code {{ worktree_path }}""#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert_eq!(
+            config.open_editor_command.as_deref(),
+            Some("code {{ worktree_path }}")
+        );
+        assert!(config.validate().is_empty());
+
+        let config: ProjectConfig =
+            toml::from_str("open-editor-command = \"code .\\nrm -rf ~\"").unwrap();
+        assert_eq!(
+            config.validate(),
+            ["`open-editor-command` must be a single line"]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_post_create_files() {
+        let contents = r#"
+[post-create-files]
+".env.local" = "BRANCH={{ branch }}"
+"config/dev.json" = { template = "{}", overwrite = true }
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        let files = config.post_create_files.as_ref().unwrap();
+        let entries: Vec<_> = files
+            .iter()
+            .map(|(path, file)| (path.as_str(), file.template(), file.overwrite()))
+            .collect();
+        assert_eq!(
+            entries,
+            [
+                (".env.local", "BRANCH={{ branch }}", false),
+                ("config/dev.json", "{}", true)
+            ]
+        );
+        assert!(config.validate().is_empty());
+        assert!(find_unknown_keys(contents).is_empty());
+
+        let config: ProjectConfig = toml::from_str(
+            r#"post-create-files = { "../outside" = "x", "/etc/passwd" = "x", ".git/hooks/post-checkout" = "x", "./ok" = "x" }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.validate(),
+            [
+                "`post-create-files` path `../outside` must be relative to the worktree, without `..` or `.git`",
+                "`post-create-files` path `/etc/passwd` must be relative to the worktree, without `..` or `.git`",
+                "`post-create-files` path `.git/hooks/post-checkout` must be relative to the worktree, without `..` or `.git`",
+            ]
+        );
+    }
+
     // ============================================================================
     // find_unknown_keys Tests
     // ============================================================================
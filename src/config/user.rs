@@ -54,6 +54,12 @@ use etcetera::base_strategy::{BaseStrategy, choose_base_strategy};
 /// Override for user config path, set via --config CLI flag
 static CONFIG_PATH: OnceLock<PathBuf> = OnceLock::new();
 
+/// Environment variable naming a user config file that must exist
+///
+/// Unlike `WORKTRUNK_CONFIG_PATH`, which falls back to defaults when the file is
+/// missing, a missing file is an error.
+const CONFIG_ENV_VAR: &str = "WORKTRUNK_CONFIG";
+
 /// Set the user config path override (called from CLI --config flag)
 pub fn set_config_path(path: PathBuf) {
     CONFIG_PATH.set(path).ok();
@@ -84,6 +90,17 @@ pub enum StageMode {
     None,
 }
 
+/// How the commit generation prompt is passed to the LLM command
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PromptVia {
+    /// Write the prompt to the command's stdin
+    #[default]
+    Stdin,
+    /// Append the prompt as the final argument; stdin is closed
+    Arg,
+}
+
 /// User-level configuration for worktree path formatting and LLM integration.
 ///
 /// This config is stored at `~/.config/worktrunk/config.toml` (or platform equivalent)
@@ -163,6 +180,34 @@ pub struct WorktrunkConfig {
     #[serde(flatten, default)]
     pub hooks: HooksConfig,
 
+    /// Command run in the user's shell after every `wt switch` (shell integration only)
+    #[serde(
+        default,
+        rename = "post-switch-command",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub post_switch_command: Option<String>,
+
+    /// Shell that runs hook commands, as `<hook-shell> -c '<command>'`
+    ///
+    /// Unset uses the platform shell (`sh` on Unix; Git Bash, else PowerShell,
+    /// on Windows). Set to `bash` for hooks that rely on bash features.
+    #[serde(
+        default,
+        rename = "hook-shell",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hook_shell: Option<String>,
+
+    /// Command name each shell's integration was installed under, keyed by
+    /// shell (e.g. `zsh = "w"`). Recorded by `wt config shell install --cmd`.
+    #[serde(
+        default,
+        rename = "shell-cmd",
+        skip_serializing_if = "std::collections::BTreeMap::is_empty"
+    )]
+    pub shell_cmd: std::collections::BTreeMap<String, String>,
+
     /// Skip the first-run shell integration prompt
     #[serde(
         default,
@@ -188,13 +233,23 @@ pub struct CommitGenerationConfig {
     #[serde(default, deserialize_with = "deserialize_string_or_vec")]
     pub args: Vec<String>,
 
+    /// Model to request from the command (overridden by `--model` on `wt step commit`)
+    /// Substituted for `{model}` in `args`, or passed after `model-arg` if no arg has the placeholder
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    /// Flag that selects the model (e.g., `--model`), appended with the model when
+    /// no arg contains `{model}`. Only used when set, since not every command takes one.
+    #[serde(default, rename = "model-arg", skip_serializing_if = "Option::is_none")]
+    pub model_arg: Option<String>,
+
     /// Inline template for commit message prompt
     /// Available variables: {{ git_diff }}, {{ branch }}, {{ recent_commits }}, {{ repo }}
     #[serde(default)]
     pub template: Option<String>,
 
     /// Path to template file (mutually exclusive with template)
-    /// Supports tilde expansion (e.g., "~/.config/worktrunk/commit-template.txt")
+    /// Supports `~` and environment variables (e.g., "~/.config/worktrunk/commit-template.txt")
     #[serde(default, rename = "template-file")]
     pub template_file: Option<String>,
 
@@ -204,9 +259,108 @@ pub struct CommitGenerationConfig {
     pub squash_template: Option<String>,
 
     /// Path to squash template file (mutually exclusive with squash-template)
-    /// Supports tilde expansion (e.g., "~/.config/worktrunk/squash-template.txt")
+    /// Supports `~` and environment variables (e.g., "~/.config/worktrunk/squash-template.txt")
     #[serde(default, rename = "squash-template-file")]
     pub squash_template_file: Option<String>,
+
+    /// Times to retry the command if it fails to start or exits non-zero (default: 0)
+    /// Retries back off exponentially, starting at 500ms
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u32>,
+
+    /// Seconds to wait for the command before killing it (default: 0, no timeout)
+    #[serde(
+        default,
+        rename = "timeout-secs",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub timeout_secs: Option<u64>,
+
+    /// Largest diff to send, in bytes; files past the limit are dropped from the prompt
+    #[serde(
+        default,
+        rename = "max-diff-bytes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_diff_bytes: Option<usize>,
+
+    /// Regex matched against a failed command's error to detect a prompt that
+    /// was too long; on a match the prompt is rebuilt with half the diff and retried once
+    #[serde(
+        default,
+        rename = "context-error-pattern",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub context_error_pattern: Option<String>,
+
+    /// Regexes whose matches in the diff, diffstat and file list are replaced
+    /// with `[REDACTED]` before they reach the prompt
+    #[serde(
+        default,
+        rename = "redact-patterns",
+        deserialize_with = "deserialize_string_or_vec",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub redact_patterns: Vec<String>,
+
+    /// Also redact common credential formats (API keys, tokens, private keys) (default: true)
+    #[serde(
+        default,
+        rename = "redact-defaults",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub redact_defaults: Option<bool>,
+
+    /// Trailers appended to generated commit and squash messages (e.g., `Co-authored-by: ...`)
+    /// Added after generation, so they're never part of the prompt
+    #[serde(
+        default,
+        deserialize_with = "deserialize_string_or_vec",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub trailers: Vec<String>,
+
+    /// Commit message used when no command is configured, instead of the
+    /// default "Changes to <files>" summary
+    #[serde(
+        default,
+        rename = "fallback-message",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub fallback_message: Option<String>,
+
+    /// Template for the squash message used when no command is configured
+    /// (variables: `target_branch`, `commits`)
+    #[serde(
+        default,
+        rename = "fallback-squash-template",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub fallback_squash_template: Option<String>,
+
+    /// Recent commit subjects to include as style reference (default: 5, 0 to omit)
+    #[serde(
+        default,
+        rename = "style-commit-count",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub style_commit_count: Option<usize>,
+
+    /// Include commit bodies, not just subjects, in the style reference (default: false)
+    #[serde(
+        default,
+        rename = "style-include-bodies",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub style_include_bodies: Option<bool>,
+
+    /// How to pass the prompt to the command: `stdin` (default) or `arg`
+    #[serde(
+        default,
+        rename = "prompt-via",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub prompt_via: Option<PromptVia>,
 }
 
 impl CommitGenerationConfig {
@@ -217,6 +371,38 @@ impl CommitGenerationConfig {
             .map(|s| !s.trim().is_empty())
             .unwrap_or(false)
     }
+
+    /// Arguments with the model applied
+    ///
+    /// Every `{model}` placeholder in `args` is replaced with the model. When no
+    /// arg contains the placeholder, a set model is appended after `model-arg`.
+    /// Errors if a model can't be applied: `args` use the placeholder but no model
+    /// is set, or a model is set with neither the placeholder nor `model-arg`.
+    pub fn resolved_args(&self) -> Result<Vec<String>, String> {
+        const PLACEHOLDER: &str = "{model}";
+        let has_placeholder = self.args.iter().any(|arg| arg.contains(PLACEHOLDER));
+        match (&self.model, has_placeholder) {
+            (Some(model), true) => Ok(self
+                .args
+                .iter()
+                .map(|arg| arg.replace(PLACEHOLDER, model))
+                .collect()),
+            (Some(model), false) => {
+                let Some(model_arg) = &self.model_arg else {
+                    return Err(format!(
+                        "commit-generation.model is {model:?}, but args don't contain {{model}}; add {{model}} to args or set commit-generation.model-arg (e.g., \"--model\")"
+                    ));
+                };
+                let mut args = self.args.clone();
+                args.extend([model_arg.clone(), model.clone()]);
+                Ok(args)
+            }
+            (None, true) => Err(
+                "commit-generation args contain {model} but no model is set; pass --model or set commit-generation.model".into(),
+            ),
+            (None, false) => Ok(self.args.clone()),
+        }
+    }
 }
 
 /// Per-project user configuration
@@ -271,6 +457,10 @@ pub struct CommitConfig {
     /// Values: "all", "tracked", "none"
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stage: Option<StageMode>,
+
+    /// Sign commits wt creates (default: git's `commit.gpgsign`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sign: Option<bool>,
 }
 
 /// Configuration for the `wt merge` command
@@ -344,6 +534,18 @@ impl WorktrunkConfig {
         // Note: worktree-path has no default set here - it's handled by the getter
         // which returns the default when None. This allows us to distinguish
         // "user explicitly set this" from "using default".
+        let config_path = get_config_path();
+        if CONFIG_PATH.get().is_none()
+            && std::env::var_os(CONFIG_ENV_VAR).is_some()
+            && let Some(path) = &config_path
+            && !path.exists()
+        {
+            return Err(ConfigError::Message(format!(
+                "{CONFIG_ENV_VAR} points to a missing file: {}",
+                crate::path::format_path_for_display(path)
+            )));
+        }
+
         let mut builder = Config::builder()
             .set_default(
                 "commit-generation.command",
@@ -352,7 +554,7 @@ impl WorktrunkConfig {
             .set_default("commit-generation.args", defaults.commit_generation.args)?;
 
         // Add config file if it exists
-        if let Some(config_path) = get_config_path()
+        if let Some(config_path) = config_path
             && config_path.exists()
         {
             // Check for deprecated template variables and create migration file if needed
@@ -414,6 +616,18 @@ impl WorktrunkConfig {
             ));
         }
 
+        // A missing hook shell only matters once a hook runs, so warn rather than fail
+        if let Some(shell) = &config.hook_shell
+            && which::which(shell).is_err()
+        {
+            crate::styling::eprintln!(
+                "{}",
+                crate::styling::warning_message(color_print::cformat!(
+                    "hook-shell <bold>{shell}</> not found; hooks will fail to run"
+                ))
+            );
+        }
+
         Ok(config)
     }
 
@@ -438,7 +652,9 @@ impl WorktrunkConfig {
         vars.insert("main_worktree", main_worktree);
         vars.insert("repo", main_worktree);
         vars.insert("branch", branch);
-        expand_template(&self.worktree_path(), &vars, false)
+        // `~` and env vars expand before the template, so branch names are never expanded
+        let template = super::expand_path(&self.worktree_path());
+        expand_template(&template.to_string_lossy(), &vars, false)
     }
 
     /// Check if a command is approved for the given project.
@@ -525,6 +741,7 @@ impl WorktrunkConfig {
 
         // Replace in-memory projects with disk state (disk is authoritative)
         self.projects = disk_config.projects;
+        self.shell_cmd = disk_config.shell_cmd;
 
         Ok(())
     }
@@ -592,6 +809,30 @@ impl WorktrunkConfig {
         self.save_impl(config_path)
     }
 
+    /// Record the command name `shell`'s integration uses and save.
+    ///
+    /// `None` removes the entry, so the shell falls back to the binary name.
+    /// Pass `None` for default config path, or `Some(path)` for testing.
+    pub fn set_shell_cmd(
+        &mut self,
+        shell: &str,
+        cmd: Option<&str>,
+        config_path: Option<&std::path::Path>,
+    ) -> Result<(), ConfigError> {
+        // Reload from disk first to avoid clobbering concurrent changes
+        self.reload_projects_from(config_path)?;
+        let changed = match cmd {
+            Some(cmd) => {
+                self.shell_cmd.insert(shell.to_string(), cmd.to_string()) != Some(cmd.to_string())
+            }
+            None => self.shell_cmd.remove(shell).is_some(),
+        };
+        if changed {
+            self.save_impl(config_path)?;
+        }
+        Ok(())
+    }
+
     /// Save the current configuration to the default config file location
     pub fn save(&self) -> Result<(), ConfigError> {
         self.save_impl(None)
@@ -654,6 +895,21 @@ impl WorktrunkConfig {
                 doc.remove("skip-shell-integration-prompt");
             }
 
+            // Update the shell-cmd section
+            if self.shell_cmd.is_empty() {
+                doc.remove("shell-cmd");
+            } else {
+                if !doc.contains_key("shell-cmd") {
+                    doc["shell-cmd"] = toml_edit::Item::Table(toml_edit::Table::new());
+                }
+                if let Some(table) = doc["shell-cmd"].as_table_mut() {
+                    table.retain(|shell, _| self.shell_cmd.contains_key(shell));
+                    for (shell, cmd) in &self.shell_cmd {
+                        table[shell] = toml_edit::value(cmd);
+                    }
+                }
+            }
+
             // Update the projects section
             // Ensure projects table exists
             if !doc.contains_key("projects") {
@@ -697,6 +953,15 @@ impl WorktrunkConfig {
                 doc["skip-shell-integration-prompt"] = toml_edit::value(true);
             }
 
+            // shell-cmd section (only if any were recorded)
+            if !self.shell_cmd.is_empty() {
+                let mut table = toml_edit::Table::new();
+                for (shell, cmd) in &self.shell_cmd {
+                    table[shell] = toml_edit::value(cmd);
+                }
+                doc["shell-cmd"] = toml_edit::Item::Table(table);
+            }
+
             // commit-generation section
             doc["commit-generation"] = toml_edit::Item::Table(toml_edit::Table::new());
             let commit_args: toml_edit::Array = self.commit_generation.args.iter().collect();
@@ -735,8 +1000,10 @@ pub fn get_config_path() -> Option<PathBuf> {
         return Some(path.clone());
     }
 
-    // Priority 2: Environment variable (also used by tests)
-    if let Ok(path) = std::env::var("WORKTRUNK_CONFIG_PATH") {
+    // Priority 2: Environment variables (WORKTRUNK_CONFIG_PATH is also used by tests)
+    if let Ok(path) =
+        std::env::var(CONFIG_ENV_VAR).or_else(|_| std::env::var("WORKTRUNK_CONFIG_PATH"))
+    {
         return Some(PathBuf::from(path));
     }
 
@@ -871,6 +1138,56 @@ rename-tab = "echo 'switched'"
         assert!(!config.is_configured());
     }
 
+    #[test]
+    fn test_commit_generation_resolved_args_without_model() {
+        let config = CommitGenerationConfig {
+            args: vec!["-p".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(config.resolved_args().unwrap(), ["-p"]);
+    }
+
+    #[test]
+    fn test_commit_generation_resolved_args_appends_model() {
+        let config = CommitGenerationConfig {
+            args: vec!["-p".to_string()],
+            model: Some("haiku".to_string()),
+            model_arg: Some("--model".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.resolved_args().unwrap(), ["-p", "--model", "haiku"]);
+    }
+
+    #[test]
+    fn test_commit_generation_resolved_args_model_without_model_arg() {
+        // Not every command takes `--model`, so it's never assumed
+        let config = CommitGenerationConfig {
+            args: vec!["-p".to_string()],
+            model: Some("haiku".to_string()),
+            ..Default::default()
+        };
+        assert!(config.resolved_args().unwrap_err().contains("model-arg"));
+    }
+
+    #[test]
+    fn test_commit_generation_resolved_args_placeholder() {
+        let config = CommitGenerationConfig {
+            args: vec!["-m".to_string(), "{model}".to_string()],
+            model: Some("gpt-4o-mini".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.resolved_args().unwrap(), ["-m", "gpt-4o-mini"]);
+    }
+
+    #[test]
+    fn test_commit_generation_resolved_args_placeholder_without_model() {
+        let config = CommitGenerationConfig {
+            args: vec!["-m".to_string(), "{model}".to_string()],
+            ..Default::default()
+        };
+        assert!(config.resolved_args().unwrap_err().contains("{model}"));
+    }
+
     #[test]
     fn test_stage_mode_default() {
         assert_eq!(StageMode::default(), StageMode::All);
@@ -923,6 +1240,7 @@ rename-tab = "echo 'switched'"
     fn test_commit_config_default() {
         let config = CommitConfig::default();
         assert!(config.stage.is_none());
+        assert!(config.sign.is_none());
     }
 
     #[test]
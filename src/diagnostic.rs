@@ -259,7 +259,10 @@ fn get_config_show_output(repo: &Repository) -> Option<String> {
 
     // Project config
     if let Ok(root) = repo.worktree_root() {
-        let project_config_path = root.join(".config/wt.toml");
+        let project_config_path = worktrunk::config::ProjectConfig::find_path(repo)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| root.join(".config/wt.toml"));
         output.push_str(&format!(
             "\n{}",
             format_config_section(&project_config_path, "Project config")
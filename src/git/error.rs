@@ -11,6 +11,7 @@
 
 use std::borrow::Cow;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use color_print::{cformat, cwrite};
 use shell_escape::escape;
@@ -39,7 +40,8 @@ use crate::styling::{
 ///     println!("Branch {} exists", branch);
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
 pub enum GitError {
     // Git state errors
     DetachedHead {
@@ -53,9 +55,27 @@ pub enum GitError {
     BranchAlreadyExists {
         branch: String,
     },
+    CannotRenameDefaultBranch {
+        branch: String,
+    },
+    RenameTargetExists {
+        from: String,
+        to: String,
+    },
     InvalidReference {
         reference: String,
     },
+    /// A ref (branch, tag, SHA, ...) that doesn't name a commit
+    UnresolvableReference {
+        reference: String,
+    },
+    /// `--branch-from` given when no branch is being created
+    BranchFromWithoutCreate {
+        branch: String,
+        reference: String,
+        /// Whether `branch` already exists (as opposed to `--create` missing)
+        exists: bool,
+    },
 
     // Worktree errors
     NotInWorktree {
@@ -68,10 +88,18 @@ pub enum GitError {
     NoWorktreeFound {
         branch: String,
     },
+    /// None of the default-branch sources resolved; `tried` lists them in order
+    DefaultBranchNotFound {
+        tried: Vec<String>,
+    },
     RemoteOnlyBranch {
         branch: String,
         remote: String,
     },
+    AmbiguousBranch {
+        query: String,
+        candidates: Vec<String>,
+    },
     WorktreePathOccupied {
         branch: String,
         path: PathBuf,
@@ -123,7 +151,19 @@ pub enum GitError {
     },
 
     // Validation/other errors
+    NotInRepository,
+    /// `--repo` names a path that isn't inside a git repository
+    NotARepository {
+        path: PathBuf,
+    },
     NotInteractive,
+    NoStagedChanges,
+    /// `wt step amend` on a merge commit without `--force`
+    AmendMergeCommit,
+    /// `wt step amend` on a commit already in `upstream` without `--force`
+    AmendPushedCommit {
+        upstream: String,
+    },
     HookCommandNotFound {
         name: String,
         available: Vec<String>,
@@ -140,6 +180,15 @@ pub enum GitError {
     ProjectConfigNotFound {
         config_path: PathBuf,
     },
+    /// A git command killed by `Repository::run_command_with_timeout`
+    CommandTimedOut {
+        command: String,
+        timeout: Duration,
+    },
+    ProjectConfigInvalid {
+        config_path: PathBuf,
+        errors: Vec<String>,
+    },
     Other {
         message: String,
     },
@@ -198,6 +247,32 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::CannotRenameDefaultBranch { branch } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Cannot rename the default branch <bold>{branch}</>"
+                    )),
+                    hint_message(cformat!(
+                        "To rename it anyway, run <bright-black>git branch -m {branch} NEW_NAME</>"
+                    ))
+                )
+            }
+
+            GitError::RenameTargetExists { from, to } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Cannot rename <bold>{from}</>: branch <bold>{to}</> already exists"
+                    )),
+                    hint_message(cformat!(
+                        "To delete the existing branch, run <bright-black>git branch -d {to}</>"
+                    ))
+                )
+            }
+
             GitError::InvalidReference { reference } => {
                 let create_cmd = suggest_command("switch", &[reference], &["--create"]);
                 let list_cmd = suggest_command("list", &[], &["--branches", "--remotes"]);
@@ -226,6 +301,54 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::UnresolvableReference { reference } => {
+                let list_cmd = suggest_command("list", &[], &["--branches", "--remotes"]);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!("Cannot resolve <bold>{reference}</> to a commit")),
+                    hint_message(cformat!(
+                        "Pass a branch, tag, or commit SHA; to list branches, run <bright-black>{list_cmd}</>"
+                    ))
+                )
+            }
+
+            GitError::BranchFromWithoutCreate {
+                branch,
+                reference,
+                exists,
+            } => {
+                if *exists {
+                    let cmd = suggest_command("switch", &[branch], &[]);
+                    write!(
+                        f,
+                        "{}\n{}",
+                        error_message(cformat!(
+                            "Cannot branch <bold>{branch}</> from <bold>{reference}</>: it already exists"
+                        )),
+                        hint_message(cformat!(
+                            "<bright-black>--branch-from</> only applies when creating a branch; to switch to it, run <bright-black>{cmd}</>"
+                        ))
+                    )
+                } else {
+                    let cmd = suggest_command(
+                        "switch",
+                        &[branch],
+                        &["--create", &format!("--branch-from={reference}")],
+                    );
+                    write!(
+                        f,
+                        "{}\n{}",
+                        error_message(cformat!(
+                            "<bold>--branch-from</> only applies when creating a branch"
+                        )),
+                        hint_message(cformat!(
+                            "To create <bold>{branch}</> from <bold>{reference}</>, run <bright-black>{cmd}</>"
+                        ))
+                    )
+                }
+            }
+
             GitError::WorktreeMissing { branch } => {
                 write!(
                     f,
@@ -245,6 +368,20 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::DefaultBranchNotFound { tried } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Could not infer default branch (tried {})",
+                        tried.join(", ")
+                    )),
+                    hint_message(cformat!(
+                        "To set it explicitly, run <bright-black>wt config state default-branch set <<branch>></>"
+                    ))
+                )
+            }
+
             GitError::RemoteOnlyBranch { branch, remote } => {
                 let cmd = suggest_command("switch", &[branch], &[]);
                 cwrite!(
@@ -253,6 +390,18 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::AmbiguousBranch { query, candidates } => {
+                write!(
+                    f,
+                    "{}\n{}\n{}",
+                    error_message(cformat!("<bold>{query}</> matches several worktrees")),
+                    format_with_gutter(&candidates.join("\n"), None),
+                    hint_message(cformat!(
+                        "Use a longer name, or <bright-black>--exact</> to disable fuzzy matching"
+                    ))
+                )
+            }
+
             GitError::WorktreePathOccupied {
                 branch,
                 path,
@@ -475,6 +624,29 @@ impl std::fmt::Display for GitError {
                 write!(f, "{}", format_error_block(header, error))
             }
 
+            GitError::NotInRepository => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message("Not inside a git repository"),
+                    hint_message(cformat!(
+                        "Run from a repository or one of its worktrees, or pass <bright-black>-C <<path>></>"
+                    ))
+                )
+            }
+
+            GitError::NotARepository { path } => {
+                let path_display = format_path_for_display(path);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!("<bold>{path_display}</> is not a git repository")),
+                    hint_message(cformat!(
+                        "<bright-black>--repo</> takes a repository or one of its worktrees"
+                    ))
+                )
+            }
+
             GitError::NotInteractive => {
                 let approvals_cmd = suggest_command("hook", &["approvals", "add"], &[]);
                 write!(
@@ -487,6 +659,41 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::NoStagedChanges => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message("No staged changes to generate a commit message from"),
+                    hint_message(cformat!(
+                        "Stage changes with <bright-black>git add</> or use <bright-black>--stage=all</>"
+                    ))
+                )
+            }
+
+            GitError::AmendMergeCommit => {
+                let cmd = suggest_command("step", &["amend"], &["--force"]);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message("Cannot amend HEAD: it is a merge commit"),
+                    hint_message(cformat!("To reword it anyway, run <bright-black>{cmd}</>"))
+                )
+            }
+
+            GitError::AmendPushedCommit { upstream } => {
+                let cmd = suggest_command("step", &["amend"], &["--force"]);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Cannot amend HEAD: it is already pushed to <bold>{upstream}</>"
+                    )),
+                    hint_message(cformat!(
+                        "Amending rewrites published history; to amend anyway, run <bright-black>{cmd}</>"
+                    ))
+                )
+            }
+
             GitError::HookCommandNotFound { name, available } => {
                 if available.is_empty() {
                     write!(
@@ -531,13 +738,41 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::CommandTimedOut { command, timeout } => {
+                write!(
+                    f,
+                    "{}",
+                    error_message(cformat!("<bold>{command}</> timed out after {timeout:?}"))
+                )
+            }
+
             GitError::ProjectConfigNotFound { config_path } => {
                 let path_display = format_path_for_display(config_path);
                 write!(
                     f,
                     "{}\n{}",
                     error_message("No project configuration found"),
-                    hint_message(cformat!("Create a config file at: <bold>{path_display}</>"))
+                    hint_message(cformat!(
+                        "Create a config file at: <bold>{path_display}</> (searched {})",
+                        crate::config::PROJECT_CONFIG_FILES.join(", ")
+                    ))
+                )
+            }
+
+            GitError::ProjectConfigInvalid {
+                config_path,
+                errors,
+            } => {
+                let path_display = format_path_for_display(config_path);
+                let count = errors.len();
+                let noun = if count == 1 { "problem" } else { "problems" };
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Project config <bold>{path_display}</> has {count} {noun}"
+                    )),
+                    format_with_gutter(&errors.join("\n"), None)
                 )
             }
 
@@ -556,7 +791,8 @@ impl std::fmt::Display for GitError {
 ///
 /// Most errors use anyhow::bail! with formatted messages. This enum is only
 /// for cases that need exit code extraction or special handling.
-#[derive(Debug)]
+#[derive(Debug, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
 pub enum WorktrunkError {
     /// Child process exited with non-zero code (preserves exit code for signals)
     ChildProcessExited { code: i32, message: String },
@@ -566,6 +802,8 @@ pub enum WorktrunkError {
         command_name: Option<String>,
         error: String,
         exit_code: Option<i32>,
+        /// Where the command's output was logged (project `hook-log-dir`)
+        log_path: Option<PathBuf>,
     },
     /// Command was not approved by user (silent error)
     CommandNotApproved,
@@ -583,6 +821,7 @@ impl std::fmt::Display for WorktrunkError {
                 hook_type,
                 command_name,
                 error,
+                log_path,
                 ..
             } => {
                 // Note: Callers that support --no-verify should add the hint themselves
@@ -593,14 +832,25 @@ impl std::fmt::Display for WorktrunkError {
                         error_message(cformat!(
                             "{hook_type} command failed: <bold>{name}</>: {error}"
                         ))
-                    )
+                    )?;
                 } else {
                     write!(
                         f,
                         "{}",
                         error_message(format!("{hook_type} command failed: {error}"))
-                    )
+                    )?;
+                }
+                if let Some(path) = log_path {
+                    write!(
+                        f,
+                        "\n{}",
+                        hint_message(format!(
+                            "Output logged to {}",
+                            format_path_for_display(path)
+                        ))
+                    )?;
                 }
+                Ok(())
             }
             WorktrunkError::CommandNotApproved => {
                 Ok(()) // on_skip callback handles the printing
@@ -614,6 +864,26 @@ impl std::fmt::Display for WorktrunkError {
 
 impl std::error::Error for WorktrunkError {}
 
+/// Stable identifier for the kind of error, e.g. `branch-already-exists`
+///
+/// Derived from the [`GitError`] or [`WorktrunkError`] variant name; errors
+/// without a variant are `git-command-failed` (a failed git invocation) or
+/// `error`. Used by `--error-format json`.
+pub fn error_kind(err: &anyhow::Error) -> &'static str {
+    if let Some(wrapper) = err.downcast_ref::<HookErrorWithHint>() {
+        return error_kind(&wrapper.inner);
+    }
+    if let Some(err) = err.downcast_ref::<GitError>() {
+        err.into()
+    } else if let Some(err) = err.downcast_ref::<WorktrunkError>() {
+        err.into()
+    } else if err.chain().any(|cause| cause.is::<GitCommandError>()) {
+        "git-command-failed"
+    } else {
+        "error"
+    }
+}
+
 /// Extract exit code from WorktrunkError, if applicable
 pub fn exit_code(err: &anyhow::Error) -> Option<i32> {
     // Check for wrapped HookErrorWithHint first
@@ -660,6 +930,27 @@ pub fn add_hook_skip_hint(err: anyhow::Error) -> anyhow::Error {
     }
 }
 
+/// A git command that exited non-zero, from `Repository::run_command`.
+///
+/// Displays git's trimmed output (stderr, then stdout) unstyled, since callers
+/// usually embed it in their own messages. The command line is kept alongside
+/// for callers and logs that need to say which command failed.
+#[derive(Debug)]
+pub struct GitCommandError {
+    /// The full command line, e.g. `git rebase main`
+    pub command: String,
+    /// Trimmed stderr and stdout, joined by a newline when both are present
+    pub output: String,
+}
+
+impl std::fmt::Display for GitCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.output)
+    }
+}
+
+impl std::error::Error for GitCommandError {}
+
 /// Wrapper that displays a HookCommandFailed error with the --no-verify hint.
 /// Created by `add_hook_skip_hint()` for commands that support `--no-verify`.
 #[derive(Debug)]
@@ -798,6 +1089,7 @@ mod tests {
             command_name: Some("test".into()),
             error: "failed".into(),
             exit_code: Some(1),
+            log_path: None,
         }
         .into();
         assert_eq!(exit_code(&err), Some(1));
@@ -808,6 +1100,7 @@ mod tests {
             command_name: None,
             error: "failed".into(),
             exit_code: None,
+            log_path: None,
         }
         .into();
         assert_eq!(exit_code(&err), None);
@@ -829,6 +1122,7 @@ mod tests {
             command_name: Some("lint".into()),
             error: "failed".into(),
             exit_code: Some(7),
+            log_path: None,
         }
         .into();
         assert_eq!(exit_code(&add_hook_skip_hint(inner)), Some(7));
@@ -842,6 +1136,7 @@ mod tests {
             command_name: Some("test".into()),
             error: "failed".into(),
             exit_code: Some(1),
+            log_path: None,
         }
         .into();
         let display = format!("{}", add_hook_skip_hint(inner));
@@ -885,6 +1180,7 @@ mod tests {
             command_name: Some("lint".into()),
             error: "lint failed".into(),
             exit_code: Some(1),
+            log_path: None,
         };
         let display = format!("{err}");
         assert!(display.contains("pre-merge") && display.contains("lint"));
@@ -894,6 +1190,7 @@ mod tests {
             command_name: None,
             error: "setup failed".into(),
             exit_code: None,
+            log_path: None,
         };
         let display = format!("{err}");
         assert!(display.contains("post-create") && display.contains("setup failed"));
@@ -917,6 +1214,36 @@ mod tests {
         assert!(display.contains("--create"));
     }
 
+    #[test]
+    fn test_git_error_unresolvable_reference() {
+        let err = GitError::UnresolvableReference {
+            reference: "v9.9".into(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("v9.9"));
+        assert!(display.contains("to a commit"));
+    }
+
+    #[test]
+    fn test_git_error_branch_from_without_create() {
+        let err = GitError::BranchFromWithoutCreate {
+            branch: "feature".into(),
+            reference: "v1.0".into(),
+            exists: false,
+        };
+        let display = err.to_string();
+        assert!(display.contains("only applies when creating a branch"));
+        assert!(display.contains("--create"));
+
+        let err = GitError::BranchFromWithoutCreate {
+            branch: "feature".into(),
+            reference: "v1.0".into(),
+            exists: true,
+        };
+        let display = err.to_string();
+        assert!(display.contains("already exists"));
+    }
+
     #[test]
     fn test_git_error_not_in_worktree() {
         // With action
@@ -954,6 +1281,17 @@ mod tests {
         assert!(display.contains("feature"));
     }
 
+    #[test]
+    fn test_git_error_default_branch_not_found() {
+        let err = GitError::DefaultBranchNotFound {
+            tried: vec!["origin/HEAD".into(), "init.defaultBranch".into()],
+        };
+        let display = err.to_string();
+        assert!(display.contains("Could not infer default branch"));
+        assert!(display.contains("origin/HEAD, init.defaultBranch"));
+        assert!(display.contains("default-branch set"));
+    }
+
     #[test]
     fn test_git_error_remote_only_branch() {
         let err = GitError::RemoteOnlyBranch {
@@ -966,6 +1304,19 @@ mod tests {
         assert!(display.contains("origin"));
     }
 
+    #[test]
+    fn test_git_error_ambiguous_branch() {
+        let err = GitError::AmbiguousBranch {
+            query: "feat".into(),
+            candidates: vec!["feature/a".into(), "feature/b".into()],
+        };
+        let display = err.to_string();
+        assert!(display.contains("feat"));
+        assert!(display.contains("feature/a"));
+        assert!(display.contains("feature/b"));
+        assert!(display.contains("--exact"));
+    }
+
     #[test]
     fn test_git_error_worktree_path_occupied() {
         // With occupant branch
@@ -1148,6 +1499,45 @@ mod tests {
         assert!(display.contains("rejected"));
     }
 
+    #[test]
+    fn test_git_error_not_in_repository() {
+        let display = GitError::NotInRepository.to_string();
+        assert!(display.contains("Not inside a git repository"));
+        assert!(display.contains("-C"));
+    }
+
+    #[test]
+    fn test_git_error_not_a_repository() {
+        let err = GitError::NotARepository {
+            path: PathBuf::from("/tmp/not-a-repo"),
+        };
+        let display = err.to_string();
+        assert!(display.contains("/tmp/not-a-repo"));
+        assert!(display.contains("is not a git repository"));
+        assert!(display.contains("--repo"));
+    }
+
+    #[test]
+    fn test_git_error_kind() {
+        let err: anyhow::Error = GitError::BranchAlreadyExists {
+            branch: "feature".into(),
+        }
+        .into();
+        assert_eq!(error_kind(&err), "branch-already-exists");
+
+        let err: anyhow::Error = WorktrunkError::CommandNotApproved.into();
+        assert_eq!(error_kind(&err), "command-not-approved");
+
+        let err = anyhow::Error::new(GitCommandError {
+            command: "git rebase main".into(),
+            output: "conflict".into(),
+        })
+        .context("Failed to rebase");
+        assert_eq!(error_kind(&err), "git-command-failed");
+
+        assert_eq!(error_kind(&anyhow::anyhow!("something else")), "error");
+    }
+
     #[test]
     fn test_git_error_not_interactive() {
         let err = GitError::NotInteractive;
@@ -1156,6 +1546,27 @@ mod tests {
         assert!(display.contains("--yes"));
     }
 
+    #[test]
+    fn test_git_error_no_staged_changes() {
+        let display = GitError::NoStagedChanges.to_string();
+        assert!(display.contains("No staged changes to generate a commit message from"));
+        assert!(display.contains("git add"));
+    }
+
+    #[test]
+    fn test_git_error_amend_refused() {
+        let display = GitError::AmendMergeCommit.to_string();
+        assert!(display.contains("merge commit"));
+        assert!(display.contains("wt step amend --force"));
+
+        let display = GitError::AmendPushedCommit {
+            upstream: "origin/feature".into(),
+        }
+        .to_string();
+        assert!(display.contains("origin/feature"));
+        assert!(display.contains("wt step amend --force"));
+    }
+
     #[test]
     fn test_git_error_hook_command_not_found() {
         // With available commands
@@ -1208,6 +1619,39 @@ mod tests {
         assert!(display.contains(".worktrunk.toml"));
     }
 
+    #[test]
+    fn test_git_error_rename_errors() {
+        let display = GitError::CannotRenameDefaultBranch {
+            branch: "main".into(),
+        }
+        .to_string();
+        assert!(display.contains("Cannot rename the default branch"));
+        assert!(display.contains("main"));
+
+        let display = GitError::RenameTargetExists {
+            from: "old".into(),
+            to: "new".into(),
+        }
+        .to_string();
+        assert!(display.contains("already exists"));
+        assert!(display.contains("git branch -d new"));
+    }
+
+    #[test]
+    fn test_git_error_project_config_invalid() {
+        let err = GitError::ProjectConfigInvalid {
+            config_path: PathBuf::from("/repo/.config/wt.toml"),
+            errors: vec![
+                "Unknown key `foo`".into(),
+                "`post-start` command is empty".into(),
+            ],
+        };
+        let display = err.to_string();
+        assert!(display.contains("2 problems"));
+        assert!(display.contains("Unknown key `foo`"));
+        assert!(display.contains("`post-start` command is empty"));
+    }
+
     #[test]
     fn test_git_error_parse_error() {
         let err = GitError::ParseError {
@@ -1217,6 +1661,26 @@ mod tests {
         assert!(display.contains("invalid syntax"));
     }
 
+    #[test]
+    fn test_git_error_command_timed_out() {
+        let err = GitError::CommandTimedOut {
+            command: "git log --oneline".into(),
+            timeout: Duration::from_secs(5),
+        };
+        let display = err.to_string();
+        assert!(display.contains("git log --oneline"));
+        assert!(display.contains("timed out after 5s"));
+    }
+
+    #[test]
+    fn test_git_command_error_displays_output_only() {
+        let err = GitCommandError {
+            command: "git rebase main".into(),
+            output: "fatal: invalid upstream 'main'".into(),
+        };
+        assert_eq!(err.to_string(), "fatal: invalid upstream 'main'");
+    }
+
     #[test]
     fn test_git_error_other() {
         let err = GitError::Other {
@@ -1320,6 +1784,7 @@ mod tests {
             command_name: Some("test".into()),
             error: "Test failed".into(),
             exit_code: Some(1),
+            log_path: None,
         }
         .into();
 
@@ -1341,6 +1806,7 @@ mod tests {
             command_name: Some("build".into()),
             error: "Build failed".into(),
             exit_code: Some(1),
+            log_path: None,
         }
         .into();
 
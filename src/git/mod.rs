@@ -31,6 +31,8 @@ static HEAVY_OPS_SEMAPHORE: LazyLock<Semaphore> = LazyLock::new(|| Semaphore::ne
 // Re-exports from submodules
 pub use diff::{DiffStats, LineDiff, parse_numstat_line};
 pub use error::{
+    // Failed git command (Display produces git's plain output)
+    GitCommandError,
     // Typed error enum (Display produces styled output)
     GitError,
     // Special-handling error enum (Display produces styled output)
@@ -38,6 +40,7 @@ pub use error::{
     WorktrunkError,
     // Error inspection functions
     add_hook_skip_hint,
+    error_kind,
     exit_code,
 };
 pub use parse::{parse_porcelain_z, parse_untracked_files};
@@ -286,6 +289,7 @@ pub(crate) use parse::DefaultBranchName;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, strum::Display, strum::EnumString)]
 #[strum(serialize_all = "kebab-case")]
 pub enum HookType {
+    PreCreate,
     PostCreate,
     PostStart,
     PostSwitch,
@@ -499,6 +503,7 @@ mod tests {
     #[test]
     fn test_hook_type_display() {
         let cases = [
+            (HookType::PreCreate, "pre-create"),
             (HookType::PostCreate, "post-create"),
             (HookType::PostStart, "post-start"),
             (HookType::PreCommit, "pre-commit"),
@@ -379,6 +379,16 @@ mod tests {
         assert_eq!(worktrees.len(), 1);
     }
 
+    #[test]
+    fn test_parse_porcelain_list_extra_trailing_blank_lines() {
+        // Repeated blank lines must not produce phantom entries
+        let output = "worktree /path/main\nHEAD aaa\nbranch refs/heads/main\n\nworktree /path/feature\nHEAD bbb\ndetached\n\n\n\n";
+        let worktrees = Worktree::parse_porcelain_list(output).unwrap();
+        assert_eq!(worktrees.len(), 2);
+        assert_eq!(worktrees[0].branch, Some("main".to_string()));
+        assert!(worktrees[1].detached);
+    }
+
     #[test]
     fn test_parse_porcelain_list_missing_worktree_path() {
         let output = "worktree\nHEAD abc123\n\n";
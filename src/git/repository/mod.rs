@@ -1,6 +1,8 @@
+use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::OnceLock;
+use std::time::Duration;
 
 use once_cell::sync::OnceCell;
 
@@ -13,8 +15,8 @@ use crate::config::ProjectConfig;
 
 // Import types and functions from parent module (mod.rs)
 use super::{
-    BranchCategory, CompletionBranch, DefaultBranchName, DiffStats, GitError, GitRemoteUrl,
-    LineDiff, Worktree,
+    BranchCategory, CompletionBranch, DefaultBranchName, DiffStats, GitCommandError, GitError,
+    GitRemoteUrl, LineDiff, Worktree,
 };
 
 /// Result of resolving a worktree name.
@@ -84,6 +86,12 @@ struct RepoCache {
 /// Provides a more ergonomic API than the `*_in(path, ...)` functions by
 /// encapsulating the repository path.
 ///
+/// Queries that don't change during a run — `worktree_root()`,
+/// `current_branch()`, `default_branch()`, and others in `RepoCache` — spawn
+/// git once per instance and return the cached value afterwards. The cache is
+/// never invalidated; create a new `Repository` after changing what it caches
+/// (e.g. switching branches).
+///
 /// # Examples
 ///
 /// ```no_run
@@ -488,6 +496,9 @@ impl Repository {
     /// Used by `default_branch()` to populate the cache, and by
     /// `wt config state get default-branch --refresh` to force re-detection.
     pub fn detect_default_branch(&self) -> anyhow::Result<String> {
+        // Sources checked so far, reported if nothing resolves
+        let mut tried = Vec::new();
+
         // Try to get from the primary remote
         if let Ok(remote) = self.primary_remote() {
             // Try git's cache for this remote (e.g., origin/HEAD)
@@ -499,6 +510,12 @@ impl Repository {
             if let Ok(branch) = self.query_remote_default_branch(remote) {
                 return Ok(branch);
             }
+
+            // `primary_remote` falls back to "origin" even when no remote exists
+            if self.remote_has_url(remote) {
+                tried.push(format!("{remote}/HEAD"));
+                tried.push(format!("{remote}'s HEAD"));
+            }
         }
 
         // Fallback: No remote or remote query failed, try to infer locally
@@ -509,7 +526,7 @@ impl Repository {
         // Options: (1) Return info about whether fallback was used, let callers show message
         //          (2) Add messages in specific commands (merge.rs, worktree.rs)
         //          (3) Move output abstraction to lib crate
-        self.infer_default_branch_locally()
+        self.infer_default_branch_locally(tried)
     }
 
     /// Resolve a target branch from an optional override
@@ -528,8 +545,9 @@ impl Repository {
     /// 2. Check symbolic-ref HEAD (authoritative for bare repos, works before first commit)
     /// 3. Check user's git config init.defaultBranch (if branch exists)
     /// 4. Look for common branch names (main, master, develop, trunk)
-    /// 5. Fail if none of the above work
-    fn infer_default_branch_locally(&self) -> anyhow::Result<String> {
+    /// 5. Fail with [`GitError::DefaultBranchNotFound`], listing `tried` plus
+    ///    the local sources checked here
+    fn infer_default_branch_locally(&self, mut tried: Vec<String>) -> anyhow::Result<String> {
         // 1. If there's only one local branch, use it
         let branches = self.local_branches()?;
         if branches.len() == 1 {
@@ -558,20 +576,19 @@ impl Repository {
             }
         }
 
+        tried.push("init.defaultBranch".to_string());
+
         // 4. Look for common branch names
-        for name in ["main", "master", "develop", "trunk"] {
+        const COMMON_NAMES: [&str; 4] = ["main", "master", "develop", "trunk"];
+        for name in COMMON_NAMES {
             if branches.contains(&name.to_string()) {
                 return Ok(name.to_string());
             }
         }
+        tried.push(format!("branches named {}", COMMON_NAMES.join("/")));
 
         // 5. Give up — can't infer
-        Err(GitError::Other {
-            message:
-                "Could not infer default branch. Please specify target branch explicitly or set up a remote."
-                    .into(),
-        }
-        .into())
+        Err(GitError::DefaultBranchNotFound { tried }.into())
     }
 
     /// List all local branches.
@@ -698,12 +715,31 @@ impl Repository {
             .copied()
     }
 
+    /// Check if the path is inside a worktree's working tree.
+    ///
+    /// False outside any repository, and inside a bare repository or `.git` directory.
+    /// Errors only if git itself can't be run.
+    pub fn is_inside_worktree(&self) -> anyhow::Result<bool> {
+        // Outside a repository `--is-inside-work-tree` fails rather than printing false
+        if !self.run_command_check(&["rev-parse", "--git-dir"])? {
+            return Ok(false);
+        }
+        let stdout = self.run_command(&["rev-parse", "--is-inside-work-tree"])?;
+        Ok(stdout.trim() == "true")
+    }
+
     /// Check if the working tree has uncommitted changes.
     pub fn is_dirty(&self) -> anyhow::Result<bool> {
         let stdout = self.run_command(&["status", "--porcelain"])?;
         Ok(!stdout.trim().is_empty())
     }
 
+    /// Check for staged or unstaged changes to tracked files, ignoring untracked files.
+    pub fn has_tracked_changes(&self) -> anyhow::Result<bool> {
+        let stdout = self.run_command(&["status", "--porcelain", "--untracked-files=no"])?;
+        Ok(!stdout.trim().is_empty())
+    }
+
     /// Ensure the working tree is clean (no uncommitted changes).
     ///
     /// Returns an error if there are uncommitted changes.
@@ -768,6 +804,46 @@ impl Repository {
         Ok(git_dir.join("MERGE_HEAD").exists())
     }
 
+    /// Stash uncommitted changes, including untracked files.
+    ///
+    /// Returns the stash commit, or `None` when there was nothing to stash.
+    pub fn stash_push(&self, message: &str) -> anyhow::Result<Option<String>> {
+        if !self.is_dirty()? {
+            return Ok(None);
+        }
+        self.run_command(&[
+            "stash",
+            "push",
+            "--include-untracked",
+            "--quiet",
+            "-m",
+            message,
+        ])?;
+        let stash = self.run_command(&["rev-parse", "refs/stash"])?;
+        Ok(Some(stash.trim().to_string()))
+    }
+
+    /// The `stash@{n}` entry for a stash commit, if it's still in the stash list.
+    pub fn stash_ref(&self, stash: &str) -> anyhow::Result<Option<String>> {
+        let stashes = self.run_command(&["stash", "list", "--format=%H"])?;
+        Ok(stashes
+            .lines()
+            .position(|sha| sha == stash)
+            .map(|index| format!("stash@{{{index}}}")))
+    }
+
+    /// Apply a stash commit from [`stash_push`](Self::stash_push) and drop its entry.
+    ///
+    /// If applying conflicts, git keeps the entry in the stash list and this
+    /// returns an error.
+    pub fn stash_pop(&self, stash: &str) -> anyhow::Result<()> {
+        let entry = self
+            .stash_ref(stash)?
+            .ok_or_else(|| anyhow::anyhow!("Stash {stash} is no longer in the stash list"))?;
+        self.run_command(&["stash", "pop", "--quiet", &entry])?;
+        Ok(())
+    }
+
     /// Check if git's builtin fsmonitor daemon is enabled.
     ///
     /// Returns true only for `core.fsmonitor=true` (the builtin daemon).
@@ -1124,6 +1200,24 @@ impl Repository {
         Ok(upstreams)
     }
 
+    /// List local branches whose upstream has been deleted on the remote.
+    ///
+    /// Git reports these as `[gone]` once the remote-tracking ref has been pruned
+    /// (e.g., by `git fetch --prune`).
+    pub fn list_branches_with_gone_upstream(&self) -> anyhow::Result<Vec<String>> {
+        let output = self.run_command(&[
+            "for-each-ref",
+            "--format=%(refname:lstrip=2) %(upstream:track)",
+            "refs/heads/",
+        ])?;
+
+        Ok(output
+            .lines()
+            .filter_map(|line| line.strip_suffix(" [gone]"))
+            .map(str::to_string)
+            .collect())
+    }
+
     /// List remote branches that aren't tracked by any local branch.
     ///
     /// Returns (branch_name, commit_sha) pairs for remote branches that have no
@@ -1164,6 +1258,37 @@ impl Repository {
         })
     }
 
+    /// Get recent commit messages (subject and body) for style reference.
+    ///
+    /// Like [`Self::recent_commit_subjects`], but each entry is the full
+    /// message: the subject, then a blank line and the body when there is one.
+    pub fn recent_commit_messages(
+        &self,
+        start_ref: Option<&str>,
+        count: usize,
+    ) -> Option<Vec<String>> {
+        let count_str = count.to_string();
+        // Record separator between commits, so multi-line bodies split cleanly
+        let mut args = vec![
+            "log",
+            "--pretty=format:%B%x1e",
+            "-n",
+            &count_str,
+            "--no-merges",
+        ];
+        if let Some(ref_name) = start_ref {
+            args.push(ref_name);
+        }
+        let output = self.run_command(&args).ok()?;
+        let messages: Vec<String> = output
+            .split('\x1e')
+            .map(str::trim)
+            .filter(|message| !message.is_empty())
+            .map(String::from)
+            .collect();
+        (!messages.is_empty()).then_some(messages)
+    }
+
     /// Get line diff statistics for working tree changes (unstaged + staged).
     pub fn working_tree_diff_stats(&self) -> anyhow::Result<LineDiff> {
         // Limit concurrent diff operations to reduce mmap thrash on pack files
@@ -1498,7 +1623,7 @@ impl Repository {
         Ok(merge_tree != target_tree)
     }
 
-    /// Get commit subjects (first line of commit message) from a range.
+    /// Get commit subjects (first line of commit message) from a range, newest first.
     pub fn commit_subjects(&self, range: &str) -> anyhow::Result<Vec<String>> {
         let output = self.run_command(&["log", "--format=%s", range])?;
         Ok(output.lines().map(String::from).collect())
@@ -1779,7 +1904,8 @@ impl Repository {
     /// Run a git command in this repository's context.
     ///
     /// Executes the git command with this repository's path as the working directory
-    /// and returns the stdout output.
+    /// and returns the stdout output. A non-zero exit returns a [`GitCommandError`]
+    /// carrying the command line and git's trimmed output.
     ///
     /// # Examples
     /// ```no_run
@@ -1790,14 +1916,58 @@ impl Repository {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn run_command(&self, args: &[&str]) -> anyhow::Result<String> {
-        use crate::shell_exec::run;
+        self.run_git("git", args, None)
+    }
 
-        let mut cmd = Command::new("git");
+    /// Run a git command like [`Self::run_command`], killing it after `timeout`.
+    ///
+    /// A command that runs too long returns [`GitError::CommandTimedOut`], so a hung
+    /// git process (a stuck credential helper, a slow network filesystem) can't hang wt.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use worktrunk::git::Repository;
+    ///
+    /// let repo = Repository::current();
+    /// let diff = repo.run_command_with_timeout(&["diff", "--staged"], Duration::from_secs(30))?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn run_command_with_timeout(
+        &self,
+        args: &[&str],
+        timeout: Duration,
+    ) -> anyhow::Result<String> {
+        self.run_git("git", args, Some(timeout))
+    }
+
+    /// Run `git` (the program at `git`, normally found on `PATH`) with `args`.
+    fn run_git(
+        &self,
+        git: impl AsRef<OsStr>,
+        args: &[&str],
+        timeout: Option<Duration>,
+    ) -> anyhow::Result<String> {
+        use crate::shell_exec::run_with_timeout;
+
+        let command_line = format!("git {}", args.join(" "));
+        let mut cmd = Command::new(git);
         cmd.args(args);
         cmd.current_dir(&self.path);
 
-        let output = run(&mut cmd, Some(&self.logging_context()))
-            .with_context(|| format!("Failed to execute: git {}", args.join(" ")))?;
+        let output = match run_with_timeout(&mut cmd, Some(&self.logging_context()), timeout) {
+            Ok(output) => output,
+            Err(e) if e.kind() == std::io::ErrorKind::TimedOut => {
+                return Err(GitError::CommandTimedOut {
+                    command: command_line,
+                    timeout: timeout.unwrap_or_default(),
+                }
+                .into());
+            }
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to execute: {command_line}"));
+            }
+        };
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
@@ -1815,7 +1985,11 @@ impl Repository {
                 .filter(|s| !s.is_empty())
                 .collect::<Vec<_>>()
                 .join("\n");
-            bail!("{}", error_msg);
+            return Err(GitCommandError {
+                command: command_line,
+                output: error_msg,
+            }
+            .into());
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
@@ -404,3 +404,65 @@ fn test_default_branch_name_display() {
         assert_eq!(branch, expected);
     }
 }
+
+#[cfg(unix)]
+#[test]
+fn test_run_command_timeout_returns_typed_error() {
+    use std::os::unix::fs::PermissionsExt;
+    use std::time::{Duration, Instant};
+
+    use super::super::GitError;
+    use super::Repository;
+
+    // A fake `git` that hangs, standing in for a stuck credential helper or filesystem
+    let dir = tempfile::tempdir().unwrap();
+    let fake_git = dir.path().join("git");
+    std::fs::write(&fake_git, "#!/bin/sh\nexec sleep 10\n").unwrap();
+    std::fs::set_permissions(&fake_git, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let repo = Repository::at(dir.path());
+    let start = Instant::now();
+    let err = repo
+        .run_git(
+            &fake_git,
+            &["log", "--oneline"],
+            Some(Duration::from_millis(100)),
+        )
+        .unwrap_err();
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    match err.downcast_ref::<GitError>() {
+        Some(GitError::CommandTimedOut { command, timeout }) => {
+            assert_eq!(command, "git log --oneline");
+            assert_eq!(*timeout, Duration::from_millis(100));
+        }
+        other => panic!("expected CommandTimedOut, got {other:?}"),
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_command_failure_includes_command_line() {
+    use std::os::unix::fs::PermissionsExt;
+
+    use super::super::GitCommandError;
+    use super::Repository;
+
+    let dir = tempfile::tempdir().unwrap();
+    let fake_git = dir.path().join("git");
+    std::fs::write(
+        &fake_git,
+        "#!/bin/sh\necho '  fatal: bad revision  ' >&2\nexit 128\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&fake_git, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let repo = Repository::at(dir.path());
+    let err = repo.run_git(&fake_git, &["log", "nope"], None).unwrap_err();
+    let err = err
+        .downcast_ref::<GitCommandError>()
+        .expect("should be a GitCommandError");
+    assert_eq!(err.command, "git log nope");
+    assert_eq!(err.output, "fatal: bad revision");
+    assert_eq!(err.to_string(), "fatal: bad revision");
+}
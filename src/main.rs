@@ -6,7 +6,7 @@ use std::process;
 use worktrunk::config::{WorktrunkConfig, set_config_path};
 use worktrunk::git::{Repository, exit_code, set_base_path};
 use worktrunk::path::format_path_for_display;
-use worktrunk::shell::extract_filename_from_path;
+use worktrunk::shell::{InitSections, extract_filename_from_path};
 use worktrunk::styling::{
     error_message, format_with_gutter, hint_message, info_message, println, success_message,
     warning_message,
@@ -24,27 +24,31 @@ mod output;
 mod pager;
 mod verbose_log;
 
-pub use crate::cli::OutputFormat;
+pub use crate::cli::{ListFormat, OutputFormat};
 
 use commands::command_executor::CommandContext;
 #[cfg(unix)]
 use commands::handle_select;
+use commands::repository_ext::DirtyWorktree;
 use commands::worktree::{SwitchResult, handle_push};
 use commands::{
-    MergeOptions, RebaseResult, ResolutionContext, SquashResult, add_approvals, approve_hooks,
-    clear_approvals, compute_worktree_path, handle_config_create, handle_config_show,
-    handle_configure_shell, handle_hints_clear, handle_hints_get, handle_hook_show, handle_init,
-    handle_list, handle_merge, handle_rebase, handle_remove, handle_remove_current,
-    handle_show_theme, handle_squash, handle_state_clear, handle_state_clear_all, handle_state_get,
-    handle_state_set, handle_state_show, handle_switch, handle_unconfigure_shell,
-    resolve_worktree_arg, run_hook, step_commit, step_copy_ignored, step_for_each,
+    ConfigAction, HookRunMode, MergeOptions, RebaseResult, ResolutionContext, ShellCmds,
+    SquashResult, add_approvals, approve_hooks, approve_switch_hooks, clear_approvals,
+    compute_worktree_path, handle_config_create, handle_config_get, handle_config_print,
+    handle_config_set, handle_config_show, handle_configure_shell, handle_hints_clear,
+    handle_hints_get, handle_hook_show, handle_init, handle_list, handle_merge, handle_merge_abort,
+    handle_prune, handle_rebase, handle_remove, handle_remove_all_merged, handle_remove_current,
+    handle_rename, handle_show_theme, handle_squash, handle_state_clear, handle_state_clear_all,
+    handle_state_get, handle_state_set, handle_state_show, handle_status, handle_switch,
+    handle_unconfigure_shell, install_report_json, resolve_fuzzy_branch, resolve_worktree_arg,
+    run_hook, show_install_preview, step_amend, step_commit, step_copy_ignored, step_for_each,
 };
 use output::{execute_user_command, handle_remove_output, handle_switch_output};
 
 use cli::{
     ApprovalsCommand, CiStatusAction, Cli, Commands, ConfigCommand, ConfigShellCommand,
-    DefaultBranchAction, HintsAction, HookCommand, ListSubcommand, LogsAction, MarkerAction,
-    PreviousBranchAction, StateCommand, StepCommand,
+    DefaultBranchAction, ErrorFormat, HintsAction, HookCommand, ListSubcommand, LogFormat,
+    LogsAction, MarkerAction, PreviousBranchAction, StateCommand, StepCommand,
 };
 use worktrunk::HookType;
 
@@ -328,6 +332,18 @@ fn find_after_help_start(help: &str) -> Option<usize> {
     None
 }
 
+/// First line of an error as plain text, without the error symbol
+fn error_summary(e: &anyhow::Error) -> String {
+    let message = strip_ansi_codes(&e.to_string());
+    message
+        .lines()
+        .next()
+        .map(|line| line.trim_start_matches('✗').trim())
+        .filter(|line| !line.is_empty())
+        .unwrap_or("Command failed")
+        .to_string()
+}
+
 /// Strip ANSI escape codes from a string for pattern matching.
 fn strip_ansi_codes(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
@@ -682,32 +698,6 @@ fn expand_demo_placeholders(text: &str) -> String {
     result
 }
 
-/// Enhance clap errors with command-specific hints, then exit.
-///
-/// For `wt switch` missing the branch argument, adds hints about shortcuts.
-fn enhance_and_exit_error(err: clap::Error) -> ! {
-    use clap::error::ErrorKind;
-    use color_print::ceprintln;
-
-    // Enhance `wt switch` missing argument error with shortcut hints.
-    // Hints go to stderr, which is safe since stdout is reserved for data output.
-    // Check for both "wt switch" and "wt.exe switch" (Windows)
-    let err_str = format!("{err}");
-    let is_switch_missing_arg = err.kind() == ErrorKind::MissingRequiredArgument
-        && (err_str.contains("wt switch") || err_str.contains("wt.exe switch"));
-    if is_switch_missing_arg {
-        eprint!("{}", err.render().ansi());
-        eprintln!();
-        ceprintln!("<green,bold>Quick switches:</>");
-        ceprintln!("  <cyan,bold>wt switch ^</>    default branch's worktree");
-        ceprintln!("  <cyan,bold>wt switch -</>    previous worktree");
-        ceprintln!("  <cyan,bold>wt select</>      interactive picker");
-        process::exit(2);
-    }
-
-    err.exit()
-}
-
 fn main() {
     // Tell crossterm to always emit ANSI sequences
     crossterm::style::force_color_output(true);
@@ -727,9 +717,7 @@ fn main() {
     // When available, use built-in setting. Until then, could use try_parse() to intercept
     // MissingRequiredArgument errors and print custom messages with ValueEnum::value_variants().
     let cmd = cli::build_command();
-    let matches = cmd.try_get_matches().unwrap_or_else(|e| {
-        enhance_and_exit_error(e);
-    });
+    let matches = cmd.try_get_matches().unwrap_or_else(|e| e.exit());
     let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
 
     // Initialize base path from -C flag if provided
@@ -737,6 +725,19 @@ fn main() {
         set_base_path(path);
     }
 
+    // --repo works like -C, but is checked below; shell setup doesn't touch a repository
+    let repo_path = cli.repo.filter(|_| {
+        !matches!(
+            cli.command,
+            Some(Commands::Config {
+                action: ConfigCommand::Shell { .. }
+            })
+        )
+    });
+    if let Some(path) = &repo_path {
+        set_base_path(path.clone());
+    }
+
     // Initialize config path from --config flag if provided
     if let Some(path) = cli.config {
         set_config_path(path);
@@ -750,20 +751,31 @@ fn main() {
 
     // Capture verbose level and command line before cli is partially consumed
     let verbose_level = cli.verbose;
+    let log_format = cli.log_format;
+    let error_format = cli.error_format;
     let command_line = std::env::args().collect::<Vec<_>>().join(" ");
 
-    // --verbose takes precedence over RUST_LOG: use Builder::new() to ignore env var
+    output::set_quiet(cli.quiet);
+
+    // --verbose and --quiet take precedence over RUST_LOG: use Builder::new() to ignore env var
     // Otherwise, respect RUST_LOG (defaulting to off)
-    let mut builder = if cli.verbose >= 1 {
-        let mut b = env_logger::Builder::new();
-        b.filter_level(log::LevelFilter::Debug);
-        b
-    } else {
-        env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("off"))
+    let level = match cli.verbose {
+        _ if cli.quiet => Some(log::LevelFilter::Error),
+        0 => None,
+        1 => Some(log::LevelFilter::Debug),
+        _ => Some(log::LevelFilter::Trace),
+    };
+    let mut builder = match level {
+        Some(level) => {
+            let mut b = env_logger::Builder::new();
+            b.filter_level(level);
+            b
+        }
+        None => env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("off")),
     };
 
     builder
-        .format(|buf, record| {
+        .format(move |buf, record| {
             use std::io::Write;
 
             let msg = record.args().to_string();
@@ -790,6 +802,17 @@ fn main() {
             // Write plain text to log file (no ANSI codes)
             verbose_log::write_line(&format!("[{thread_num}] {msg}"));
 
+            // JSON lines for machine ingestion: no styling, message escaped by serde
+            if log_format == LogFormat::Json {
+                let line = serde_json::json!({
+                    "timestamp": worktrunk::utils::now_iso8601(),
+                    "level": record.level().as_str(),
+                    "target": record.target(),
+                    "message": msg,
+                });
+                return writeln!(buf, "{line}");
+            }
+
             // Commands start with $, make only the command bold (not $ or [worktree])
             if let Some(rest) = msg.strip_prefix("$ ") {
                 // Split: "git command [worktree]" -> ("git command", " [worktree]")
@@ -826,159 +849,49 @@ fn main() {
         return;
     };
 
+    // Fail up front rather than with whatever git error the command hits first
+    let precheck = match repo_path {
+        Some(path) => require_repository()
+            .map_err(|_| worktrunk::git::GitError::NotARepository { path }.into()),
+        None if requires_repository(&command) => require_repository(),
+        None => Ok(()),
+    };
     let result = match command {
+        _ if precheck.is_err() => precheck,
         Commands::Config { action } => match action {
             ConfigCommand::Shell { action } => {
                 match action {
-                    ConfigShellCommand::Init { shell, cmd } => {
+                    ConfigShellCommand::Init {
+                        shell,
+                        cmd,
+                        cache,
+                        // `--no-cache` only needs to override `--cache` (handled by clap)
+                        no_cache: _,
+                        integration_only,
+                        completions_only,
+                    } => {
                         // Generate shell code to stdout
                         let cmd = cmd.unwrap_or_else(binary_name);
-                        handle_init(shell, cmd).map_err(|e| anyhow::anyhow!("{}", e))
-                    }
-                    ConfigShellCommand::Install { shell, yes, cmd } => {
-                        // Auto-write to shell config files and completions
-                        let cmd = cmd.unwrap_or_else(binary_name);
-                        handle_configure_shell(shell, yes, cmd)
-                            .map_err(|e| anyhow::anyhow!("{}", e))
-                            .and_then(|scan_result| {
-                                // Exit with error if no shells configured
-                                // Show skipped shells first so user knows what was tried
-                                if scan_result.configured.is_empty() {
-                                    crate::output::print_skipped_shells(&scan_result.skipped)?;
-                                    return Err(worktrunk::git::GitError::Other {
-                                        message: "No shell config files found".into(),
-                                    }
-                                    .into());
-                                }
-                                crate::output::print_shell_install_result(&scan_result)
-                            })
-                    }
-                    ConfigShellCommand::Uninstall { shell, yes } => {
-                        let explicit_shell = shell.is_some();
-                        handle_unconfigure_shell(shell, yes, &binary_name())
+                        let sections = if integration_only {
+                            InitSections::Integration
+                        } else if completions_only {
+                            InitSections::Completions
+                        } else {
+                            InitSections::All
+                        };
+                        handle_init(shell, cmd, cache, sections)
                             .map_err(|e| anyhow::anyhow!("{}", e))
-                            .and_then(|scan_result| {
-                                let shell_count = scan_result.results.len();
-                                let completion_count = scan_result.completion_results.len();
-                                let total_changes = shell_count + completion_count;
-
-                                // Show shell extension results
-                                for result in &scan_result.results {
-                                    let shell = result.shell;
-                                    let path = format_path_for_display(&result.path);
-                                    // For bash/zsh, completions are inline in the init script
-                                    let what = if matches!(
-                                        shell,
-                                        worktrunk::shell::Shell::Bash
-                                            | worktrunk::shell::Shell::Zsh
-                                    ) {
-                                        "shell extension & completions"
-                                    } else {
-                                        "shell extension"
-                                    };
-
-                                    crate::output::print(success_message(cformat!(
-                                        "{} {what} for <bold>{shell}</> @ <bold>{path}</>",
-                                        result.action.description(),
-                                    )))?;
-                                }
-
-                                // Show completion results
-                                for result in &scan_result.completion_results {
-                                    let shell = result.shell;
-                                    let path = format_path_for_display(&result.path);
-
-                                    crate::output::print(success_message(cformat!(
-                                        "{} completions for <bold>{shell}</> @ <bold>{path}</>",
-                                        result.action.description(),
-                                    )))?;
-                                }
-
-                                // Show not found - warning if explicit shell, hint if auto-scan
-                                for (shell, path) in &scan_result.not_found {
-                                    let path = format_path_for_display(path);
-                                    // Use consistent terminology matching install/uninstall messages
-                                    let what = if matches!(
-                                        shell,
-                                        worktrunk::shell::Shell::Bash
-                                            | worktrunk::shell::Shell::Zsh
-                                    ) {
-                                        "shell extension & completions"
-                                    } else {
-                                        "shell extension"
-                                    };
-                                    if explicit_shell {
-                                        crate::output::print(warning_message(format!(
-                                            "No {what} found in {path}"
-                                        )))?;
-                                    } else {
-                                        crate::output::print(hint_message(cformat!(
-                                            "No <bright-black>{shell}</> {what} in {path}"
-                                        )))?;
-                                    }
-                                }
-
-                                // Show completion files not found (only fish has separate completion files)
-                                // Only show this if the shell extension was ALSO not found - if we removed
-                                // the shell extension, no need to warn about missing completions
-                                for (shell, path) in &scan_result.completion_not_found {
-                                    let shell_was_removed =
-                                        scan_result.results.iter().any(|r| r.shell == *shell);
-                                    if shell_was_removed {
-                                        continue; // Shell extension was removed, don't warn about completions
-                                    }
-                                    let path = format_path_for_display(path);
-                                    if explicit_shell {
-                                        crate::output::print(warning_message(format!(
-                                            "No completions found in {path}"
-                                        )))?;
-                                    } else {
-                                        crate::output::print(hint_message(cformat!(
-                                            "No <bright-black>{shell}</> completions in {path}"
-                                        )))?;
-                                    }
-                                }
-
-                                // Exit with info if nothing was found
-                                let all_not_found = scan_result.not_found.len()
-                                    + scan_result.completion_not_found.len();
-                                if total_changes == 0 {
-                                    if all_not_found == 0 {
-                                        crate::output::blank()?;
-                                        crate::output::print(hint_message(
-                                            "No shell integration found to remove",
-                                        ))?;
-                                    }
-                                    return Ok(());
-                                }
-
-                                // Summary
-                                crate::output::blank()?;
-                                let plural = if shell_count == 1 { "" } else { "s" };
-                                crate::output::print(success_message(format!(
-                                    "Removed integration from {shell_count} shell{plural}"
-                                )))?;
-
-                                // Hint about restarting shell (only if current shell was affected)
-                                let current_shell = std::env::var("SHELL")
-                                    .ok()
-                                    .and_then(|s| extract_filename_from_path(&s).map(String::from));
-
-                                let current_shell_affected =
-                                    current_shell.as_ref().is_some_and(|shell_name| {
-                                        scan_result.results.iter().any(|r| {
-                                            r.shell.to_string().eq_ignore_ascii_case(shell_name)
-                                        })
-                                    });
-
-                                if current_shell_affected {
-                                    crate::output::print(hint_message(
-                                        "Restart shell to complete uninstall",
-                                    ))?;
-                                }
-                                Ok(())
-                            })
                     }
+                    ConfigShellCommand::Install {
+                        shell,
+                        yes,
+                        dry_run,
+                        rc_file,
+                        bash_rc,
+                        format,
+                        cmd,
+                    } => handle_shell_install(shell, yes, dry_run, rc_file, bash_rc, format, cmd),
+                    ConfigShellCommand::Uninstall { shell, yes } => handle_shell_uninstall(shell, yes),
                     ConfigShellCommand::ShowTheme => {
                         handle_show_theme().map_err(|e| anyhow::anyhow!("{}", e))
                     }
@@ -986,6 +899,9 @@ fn main() {
             }
             ConfigCommand::Create { project } => handle_config_create(project),
             ConfigCommand::Show { full } => handle_config_show(full),
+            ConfigCommand::Print { format } => handle_config_print(format),
+            ConfigCommand::Get { key } => handle_config_get(&key),
+            ConfigCommand::Set { key, value } => handle_config_set(&key, &value),
             ConfigCommand::State { action } => match action {
                 StateCommand::DefaultBranch { action } => match action {
                     Some(DefaultBranchAction::Get { refresh }) => {
@@ -1046,15 +962,35 @@ fn main() {
                 yes,
                 verify,
                 stage,
+                model,
+                template,
+                template_file,
                 show_prompt,
+                preview,
+                since,
             } => WorktrunkConfig::load()
                 .context("Failed to load config")
                 .and_then(|config| {
                     let stage_final = stage
                         .or_else(|| config.commit.and_then(|c| c.stage))
                         .unwrap_or_default();
-                    step_commit(yes, !verify, stage_final, show_prompt)
+                    step_commit(
+                        yes,
+                        !verify,
+                        stage_final,
+                        model,
+                        template,
+                        template_file,
+                        show_prompt,
+                        preview,
+                        since,
+                    )
                 }),
+            StepCommand::Amend {
+                yes,
+                force,
+                show_prompt,
+            } => step_amend(yes, force, show_prompt),
             StepCommand::Squash {
                 target,
                 yes,
@@ -1063,13 +999,17 @@ fn main() {
                 show_prompt,
             } => WorktrunkConfig::load()
                 .context("Failed to load config")
-                .and_then(|config| {
+                .and_then(|mut config| {
                     let stage_final = stage
-                        .or_else(|| config.commit.and_then(|c| c.stage))
+                        .or_else(|| config.commit.as_ref().and_then(|c| c.stage))
                         .unwrap_or_default();
 
                     // Handle --show-prompt early: just build and output the prompt
                     if show_prompt {
+                        commands::context::apply_project_commit_generation(
+                            &Repository::current(),
+                            &mut config,
+                        )?;
                         return commands::step_show_squash_prompt(
                             target.as_deref(),
                             &config.commit_generation,
@@ -1131,27 +1071,60 @@ fn main() {
                 hook_type,
                 expanded,
             } => handle_hook_show(hook_type.as_deref(), expanded),
-            HookCommand::PostCreate { name, yes, vars } => {
-                run_hook(HookType::PostCreate, yes, None, name.as_deref(), &vars)
-            }
+            HookCommand::PreCreate {
+                name,
+                yes,
+                vars,
+                dry_run,
+            } => run_hook(
+                HookType::PreCreate,
+                yes,
+                None,
+                name.as_deref(),
+                &vars,
+                dry_run,
+            ),
+            HookCommand::PostCreate {
+                name,
+                yes,
+                vars,
+                dry_run,
+            } => run_hook(
+                HookType::PostCreate,
+                yes,
+                None,
+                name.as_deref(),
+                &vars,
+                dry_run,
+            ),
             HookCommand::PostStart {
                 name,
                 yes,
                 foreground,
+                parallel,
                 no_background,
                 vars,
+                dry_run,
             } => {
                 if no_background {
                     let _ = output::print(warning_message(
                         "--no-background is deprecated; use --foreground instead",
                     ));
                 }
+                let mode = if parallel {
+                    HookRunMode::Parallel
+                } else if foreground || no_background {
+                    HookRunMode::Foreground
+                } else {
+                    HookRunMode::Background
+                };
                 run_hook(
                     HookType::PostStart,
                     yes,
-                    Some(foreground || no_background),
+                    Some(mode),
                     name.as_deref(),
                     &vars,
+                    dry_run,
                 )
             }
             HookCommand::PostSwitch {
@@ -1160,32 +1133,79 @@ fn main() {
                 foreground,
                 no_background,
                 vars,
+                dry_run,
             } => {
                 if no_background {
                     let _ = output::print(warning_message(
                         "--no-background is deprecated; use --foreground instead",
                     ));
                 }
+                let mode = if foreground || no_background {
+                    HookRunMode::Foreground
+                } else {
+                    HookRunMode::Background
+                };
                 run_hook(
                     HookType::PostSwitch,
                     yes,
-                    Some(foreground || no_background),
+                    Some(mode),
                     name.as_deref(),
                     &vars,
+                    dry_run,
                 )
             }
-            HookCommand::PreCommit { name, yes, vars } => {
-                run_hook(HookType::PreCommit, yes, None, name.as_deref(), &vars)
-            }
-            HookCommand::PreMerge { name, yes, vars } => {
-                run_hook(HookType::PreMerge, yes, None, name.as_deref(), &vars)
-            }
-            HookCommand::PostMerge { name, yes, vars } => {
-                run_hook(HookType::PostMerge, yes, None, name.as_deref(), &vars)
-            }
-            HookCommand::PreRemove { name, yes, vars } => {
-                run_hook(HookType::PreRemove, yes, None, name.as_deref(), &vars)
-            }
+            HookCommand::PreCommit {
+                name,
+                yes,
+                vars,
+                dry_run,
+            } => run_hook(
+                HookType::PreCommit,
+                yes,
+                None,
+                name.as_deref(),
+                &vars,
+                dry_run,
+            ),
+            HookCommand::PreMerge {
+                name,
+                yes,
+                vars,
+                dry_run,
+            } => run_hook(
+                HookType::PreMerge,
+                yes,
+                None,
+                name.as_deref(),
+                &vars,
+                dry_run,
+            ),
+            HookCommand::PostMerge {
+                name,
+                yes,
+                vars,
+                dry_run,
+            } => run_hook(
+                HookType::PostMerge,
+                yes,
+                None,
+                name.as_deref(),
+                &vars,
+                dry_run,
+            ),
+            HookCommand::PreRemove {
+                name,
+                yes,
+                vars,
+                dry_run,
+            } => run_hook(
+                HookType::PreRemove,
+                yes,
+                None,
+                name.as_deref(),
+                &vars,
+                dry_run,
+            ),
             HookCommand::Approvals { action } => match action {
                 ApprovalsCommand::Add { all } => add_approvals(all),
                 ApprovalsCommand::Clear { global } => clear_approvals(global),
@@ -1207,6 +1227,7 @@ fn main() {
             branches,
             remotes,
             full,
+            stale,
             progressive,
             no_progressive,
         } => match subcommand {
@@ -1250,6 +1271,7 @@ fn main() {
                             show_branches,
                             show_remotes,
                             show_full,
+                            stale,
                             render_mode,
                             &config,
                         )
@@ -1258,16 +1280,74 @@ fn main() {
         },
         Commands::Switch {
             branch,
+            list,
             create,
             base,
+            branch_from,
+            detach,
             execute,
             execute_args,
             yes,
             clobber,
+            exact,
+            print_path,
+            porcelain,
             verify,
+            post_switch,
         } => WorktrunkConfig::load()
             .context("Failed to load config")
             .and_then(|mut config| {
+                output::set_porcelain(porcelain);
+
+                if let (true, Some(reference)) = (detach, branch.as_deref()) {
+                    return commands::handle_switch_detach(reference, &config);
+                }
+
+                // No branch (or --list): pick one; a picked branch needs no fuzzy matching
+                let (branch, exact) = match branch {
+                    Some(branch) if !list => (branch, exact),
+                    _ => match commands::pick_worktree_branch(&Repository::current())? {
+                        Some(branch) => (branch, true),
+                        None => return Ok(()),
+                    },
+                };
+
+                if print_path {
+                    return commands::print_worktree_path(&branch, exact, &config);
+                }
+
+                // Like `cd -` in a fresh shell, having nowhere to go back to isn't an error
+                if branch == "-" && Repository::current().get_switch_previous().is_none() {
+                    crate::output::print(info_message("No previous worktree to switch to"))?;
+                    crate::output::print(hint_message(cformat!(
+                        "<bright-black>wt switch -</> works after the first switch; use <bright-black>wt list</> to see worktrees"
+                    )))?;
+                    return Ok(());
+                }
+
+                // Resolve fuzzy names first so approval and hooks see the real branch
+                let branch = if create || exact {
+                    branch
+                } else {
+                    resolve_fuzzy_branch(&Repository::current(), &branch)?
+                };
+
+                if let Some(reference) = branch_from.as_deref() {
+                    commands::validate_branch_from(&branch, create, reference)?;
+                }
+                let base = base.or(branch_from);
+
+                // Any branch without a worktree gets one, with or without --create
+                // (e.g. switching to a branch that only exists on a remote)
+                let creates_worktree = {
+                    let repo = Repository::current();
+                    !matches!(
+                        repo.resolve_worktree_name(&branch)
+                            .and_then(|branch| repo.worktree_for_branch(&branch)),
+                        Ok(Some(_))
+                    )
+                };
+
                 // "Approve at the Gate": collect and approve hooks upfront
                 // This ensures approval happens once at the command entry point
                 // If user declines, skip hooks but continue with worktree operation
@@ -1285,10 +1365,12 @@ fn main() {
                         yes,
                     );
                     // Approve different hooks based on whether we're creating or switching
-                    if create {
-                        approve_hooks(
+                    // (plus the project's open-editor-command, which runs on every switch)
+                    if creates_worktree {
+                        approve_switch_hooks(
                             &ctx,
                             &[
+                                HookType::PreCreate,
                                 HookType::PostCreate,
                                 HookType::PostStart,
                                 HookType::PostSwitch,
@@ -1296,7 +1378,7 @@ fn main() {
                         )?
                     } else {
                         // When switching to existing, only post-switch needs approval
-                        approve_hooks(&ctx, &[HookType::PostSwitch])?
+                        approve_switch_hooks(&ctx, &[HookType::PostSwitch])?
                     }
                 } else {
                     true // --no-verify: skip all hooks
@@ -1307,7 +1389,7 @@ fn main() {
 
                 // Show message if user declined approval
                 if !approved {
-                    crate::output::print(info_message(if create {
+                    crate::output::print(info_message(if creates_worktree {
                         "Commands declined, continuing worktree creation"
                     } else {
                         "Commands declined"
@@ -1335,26 +1417,34 @@ fn main() {
                 // (only shows prompt/hint when shell integration isn't working)
                 // With --execute: show hints only (don't interrupt with prompt)
                 // Best-effort: don't fail switch if offer fails
-                if !output::is_shell_integration_active() {
+                if !output::is_shell_integration_active() && !porcelain {
                     let skip_prompt = execute.is_some();
                     let _ =
                         output::prompt_shell_integration(&mut config, &binary_name(), skip_prompt);
                 }
 
-                // Spawn background hooks after success message
+                // Export project env vars and spawn background hooks after success message
+                // - post-switch-command: the user's own, so it ignores --no-verify
+                // - env: exported into the user's shell (shell integration only)
+                // - open-editor-command: run by the shell wrapper after the cd (shell integration only)
                 // - post-switch: runs on ALL switches (shows "@ path" when shell won't be there)
                 // - post-start: runs only when creating a NEW worktree
+                let repo = Repository::current();
+                let repo_root = repo.worktree_base().context("Failed to switch worktree")?;
+                let ctx = CommandContext::new(
+                    &repo,
+                    &config,
+                    Some(&branch_info.branch),
+                    result.path(),
+                    &repo_root,
+                    yes,
+                );
+                if post_switch {
+                    ctx.run_post_switch_command()?;
+                }
                 if !skip_hooks {
-                    let repo = Repository::current();
-                    let repo_root = repo.worktree_base().context("Failed to switch worktree")?;
-                    let ctx = CommandContext::new(
-                        &repo,
-                        &config,
-                        Some(&branch_info.branch),
-                        result.path(),
-                        &repo_root,
-                        yes,
-                    );
+                    ctx.export_project_env()?;
+                    ctx.open_editor()?;
 
                     // Build extra vars for base branch context
                     // "base" is the branch we branched from when creating a new worktree.
@@ -1412,6 +1502,7 @@ fn main() {
             verify,
             yes,
             force,
+            all_merged,
         } => WorktrunkConfig::load()
             .context("Failed to load config")
             .and_then(|config| {
@@ -1422,6 +1513,12 @@ fn main() {
                     ))?;
                 }
                 let background = !(foreground || no_background);
+                // Uncommitted changes: ask unless --force or --yes already said to discard them
+                let dirty = if force || yes {
+                    DirtyWorktree::Discard
+                } else {
+                    DirtyWorktree::Confirm
+                };
 
                 // Validate conflicting flags
                 if !delete_branch && force_delete {
@@ -1431,6 +1528,17 @@ fn main() {
                     .into());
                 }
 
+                if all_merged {
+                    return handle_remove_all_merged(
+                        yes,
+                        force,
+                        !delete_branch,
+                        verify,
+                        background,
+                        &config,
+                    );
+                }
+
                 // "Approve at the Gate": collect and approve pre-remove hooks upfront
                 // This ensures approval happens once at the command entry point
                 //
@@ -1474,6 +1582,7 @@ fn main() {
                         !delete_branch,
                         force_delete,
                         force,
+                        dirty,
                         background,
                         &config,
                     )
@@ -1531,6 +1640,7 @@ fn main() {
                             !delete_branch,
                             force_delete,
                             force,
+                            dirty,
                             background,
                             &config,
                         ) {
@@ -1551,6 +1661,7 @@ fn main() {
                             !delete_branch,
                             force_delete,
                             force,
+                            dirty,
                             background,
                             &config,
                         ) {
@@ -1571,6 +1682,7 @@ fn main() {
                             !delete_branch,
                             force_delete,
                             force,
+                            dirty,
                             background,
                             &config,
                         ) {
@@ -1592,6 +1704,32 @@ fn main() {
                     Ok(())
                 }
             }),
+        Commands::Rename { old, new } => WorktrunkConfig::load()
+            .context("Failed to load config")
+            .and_then(|config| handle_rename(&old, &new, &config)),
+        Commands::Move { name, path } => commands::handle_move(&name, &path),
+        Commands::Clone {
+            url,
+            dir,
+            bare,
+            yes,
+            verify,
+        } => WorktrunkConfig::load()
+            .context("Failed to load config")
+            .and_then(|config| {
+                commands::handle_clone(&url, dir.as_deref(), bare, yes, verify, &config)
+            }),
+        Commands::Status { format } => WorktrunkConfig::load()
+            .context("Failed to load config")
+            .and_then(|config| handle_status(format, &config)),
+        Commands::Log { oneline, format } => commands::handle_log(oneline, format),
+        Commands::Doctor => WorktrunkConfig::load()
+            .context("Failed to load config")
+            .and_then(|config| commands::handle_doctor(&config)),
+        Commands::Prune { dry_run, yes } => WorktrunkConfig::load()
+            .context("Failed to load config")
+            .and_then(|config| handle_prune(dry_run, yes, &config)),
+        Commands::Merge { abort: true, .. } => handle_merge_abort(),
         Commands::Merge {
             target,
             squash,
@@ -1606,6 +1744,8 @@ fn main() {
             no_verify,
             yes,
             stage,
+            autostash,
+            abort: false,
         } => WorktrunkConfig::load()
             .context("Failed to load config")
             .and_then(|config| {
@@ -1647,13 +1787,22 @@ fn main() {
                     verify: verify_final,
                     yes,
                     stage_mode: stage_final,
+                    autostash,
                 })
             }),
     };
 
     if let Err(e) = result {
-        // GitError, WorktrunkError, and HookErrorWithHint produce styled output via Display
-        if let Some(err) = e.downcast_ref::<worktrunk::git::GitError>() {
+        if error_format == ErrorFormat::Json {
+            let error = serde_json::json!({
+                "error": {
+                    "kind": worktrunk::git::error_kind(&e),
+                    "message": error_summary(&e),
+                }
+            });
+            let _ = output::print(error.to_string());
+        } else if let Some(err) = e.downcast_ref::<worktrunk::git::GitError>() {
+            // GitError, WorktrunkError, and HookErrorWithHint produce styled output via Display
             let _ = output::print(err.to_string());
         } else if let Some(err) = e.downcast_ref::<worktrunk::git::WorktrunkError>() {
             let _ = output::print(err.to_string());
@@ -1689,6 +1838,9 @@ fn main() {
             }
         }
 
+        // Tell the shell wrapper the command failed, so it skips pending directives
+        let _ = output::report_error(&error_summary(&e));
+
         // Preserve exit code from child processes (especially for signals like SIGINT)
         let code = exit_code(&e).unwrap_or(1);
 
@@ -1707,6 +1859,240 @@ fn main() {
     let _ = output::terminate_output();
 }
 
+/// Load the user config for recorded shell command names.
+///
+/// A config that fails to load shouldn't block (un)installing shell integration,
+/// so this warns and returns `None`; callers then use the binary name.
+fn load_config_for_shell_cmds() -> Option<WorktrunkConfig> {
+    match WorktrunkConfig::load() {
+        Ok(config) => Some(config),
+        Err(e) => {
+            let _ = crate::output::print(warning_message(format!(
+                "Failed to load config, using command name {}: {e}",
+                binary_name()
+            )));
+            None
+        }
+    }
+}
+
+/// Handle `wt config shell install`: write shell integration to config files and
+/// completions. Without `--cmd`, each shell keeps the name it was installed under.
+fn handle_shell_install(
+    shell: Option<worktrunk::shell::Shell>,
+    yes: bool,
+    dry_run: bool,
+    rc_file: Option<PathBuf>,
+    bash_rc: Option<worktrunk::shell::BashRc>,
+    format: OutputFormat,
+    cmd: Option<String>,
+) -> anyhow::Result<()> {
+    let mut config = load_config_for_shell_cmds();
+    let cmds = match (&cmd, &config) {
+        (Some(cmd), _) => ShellCmds::uniform(cmd.clone()),
+        (None, Some(config)) => ShellCmds::recorded(config, binary_name()),
+        (None, None) => ShellCmds::uniform(binary_name()),
+    };
+    let scan_result =
+        handle_configure_shell(shell, yes, dry_run, rc_file.as_deref(), bash_rc, &cmds)
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+    // Remember --cmd so later installs regenerate the same names
+    if let Some(cmd) = cmd.as_deref()
+        && let Some(config) = config.as_mut()
+        && !dry_run
+    {
+        let recorded = (cmd != binary_name()).then_some(cmd);
+        for result in &scan_result.configured {
+            config.set_shell_cmd(&result.shell.to_string(), recorded, None)?;
+        }
+    }
+    if let OutputFormat::Json = format {
+        let report = install_report_json(&scan_result);
+        crate::output::stdout(serde_json::to_string_pretty(&report)?)?;
+        if scan_result.configured.is_empty() {
+            return Err(worktrunk::git::GitError::Other {
+                message: "No shell config files found".into(),
+            }
+            .into());
+        }
+        return Ok(());
+    }
+    // Exit with error if no shells configured
+    // Show skipped shells first so user knows what was tried
+    if scan_result.configured.is_empty() {
+        crate::output::print_skipped_shells(&scan_result.skipped)?;
+        return Err(worktrunk::git::GitError::Other {
+            message: "No shell config files found".into(),
+        }
+        .into());
+    }
+    let has_changes = scan_result
+        .configured
+        .iter()
+        .any(|r| !matches!(r.action, ConfigAction::AlreadyExists))
+        || scan_result
+            .completion_results
+            .iter()
+            .any(|r| !matches!(r.action, ConfigAction::AlreadyExists));
+    if dry_run && has_changes {
+        show_install_preview(
+            &scan_result.configured,
+            &scan_result.completion_results,
+            &cmds,
+        );
+        return crate::output::print_duplicate_integrations(&scan_result.duplicates);
+    }
+    crate::output::print_shell_install_result(&scan_result)
+}
+
+/// Handle `wt config shell uninstall`: remove shell integration from config files
+fn handle_shell_uninstall(shell: Option<worktrunk::shell::Shell>, yes: bool) -> anyhow::Result<()> {
+    let explicit_shell = shell.is_some();
+    let cmds = match load_config_for_shell_cmds() {
+        Some(config) => ShellCmds::recorded(&config, binary_name()),
+        None => ShellCmds::uniform(binary_name()),
+    };
+    let scan_result =
+        handle_unconfigure_shell(shell, yes, &cmds).map_err(|e| anyhow::anyhow!("{}", e))?;
+    let shell_count = scan_result.results.len();
+    let completion_count = scan_result.completion_results.len();
+    let total_changes = shell_count + completion_count;
+
+    // Show shell extension results
+    for result in &scan_result.results {
+        let shell = result.shell;
+        let path = format_path_for_display(&result.path);
+        // For bash/zsh, completions are inline in the init script
+        let what = if matches!(
+            shell,
+            worktrunk::shell::Shell::Bash | worktrunk::shell::Shell::Zsh
+        ) {
+            "shell extension & completions"
+        } else {
+            "shell extension"
+        };
+
+        crate::output::print(success_message(cformat!(
+            "{} {what} for <bold>{shell}</> @ <bold>{path}</>",
+            result.action.description(),
+        )))?;
+    }
+
+    // Show completion results
+    for result in &scan_result.completion_results {
+        let shell = result.shell;
+        let path = format_path_for_display(&result.path);
+
+        crate::output::print(success_message(cformat!(
+            "{} completions for <bold>{shell}</> @ <bold>{path}</>",
+            result.action.description(),
+        )))?;
+    }
+
+    // Show not found - warning if explicit shell, hint if auto-scan
+    for (shell, path) in &scan_result.not_found {
+        let path = format_path_for_display(path);
+        // Use consistent terminology matching install/uninstall messages
+        let what = if matches!(
+            shell,
+            worktrunk::shell::Shell::Bash | worktrunk::shell::Shell::Zsh
+        ) {
+            "shell extension & completions"
+        } else {
+            "shell extension"
+        };
+        if explicit_shell {
+            crate::output::print(warning_message(format!("No {what} found in {path}")))?;
+        } else {
+            crate::output::print(hint_message(cformat!(
+                "No <bright-black>{shell}</> {what} in {path}"
+            )))?;
+        }
+    }
+
+    // Show completion files not found (only fish has separate completion files)
+    // Only show this if the shell extension was ALSO not found - if we removed
+    // the shell extension, no need to warn about missing completions
+    for (shell, path) in &scan_result.completion_not_found {
+        let shell_was_removed = scan_result.results.iter().any(|r| r.shell == *shell);
+        if shell_was_removed {
+            continue; // Shell extension was removed, don't warn about completions
+        }
+        let path = format_path_for_display(path);
+        if explicit_shell {
+            crate::output::print(warning_message(format!("No completions found in {path}")))?;
+        } else {
+            crate::output::print(hint_message(cformat!(
+                "No <bright-black>{shell}</> completions in {path}"
+            )))?;
+        }
+    }
+
+    // Exit with info if nothing was found
+    let all_not_found = scan_result.not_found.len() + scan_result.completion_not_found.len();
+    if total_changes == 0 {
+        if all_not_found == 0 {
+            crate::output::blank()?;
+            crate::output::print(hint_message("No shell integration found to remove"))?;
+        }
+        return Ok(());
+    }
+
+    // Summary
+    crate::output::blank()?;
+    let plural = if shell_count == 1 { "" } else { "s" };
+    crate::output::print(success_message(format!(
+        "Removed integration from {shell_count} shell{plural}"
+    )))?;
+
+    // Hint about restarting shell (only if current shell was affected)
+    let current_shell = std::env::var("SHELL")
+        .ok()
+        .and_then(|s| extract_filename_from_path(&s).map(String::from));
+
+    let current_shell_affected = current_shell.as_ref().is_some_and(|shell_name| {
+        scan_result
+            .results
+            .iter()
+            .any(|r| r.shell.to_string().eq_ignore_ascii_case(shell_name))
+    });
+
+    if current_shell_affected {
+        crate::output::print(hint_message("Restart shell to complete uninstall"))?;
+    }
+    Ok(())
+}
+
+/// Whether `command` operates on a repository
+///
+/// Setup and diagnostic commands (`config`, `hook`, `doctor`, `clone`, `status`)
+/// stay usable elsewhere.
+fn requires_repository(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Switch { .. }
+            | Commands::Remove { .. }
+            | Commands::Merge { .. }
+            | Commands::Rename { .. }
+            | Commands::Move { .. }
+            | Commands::Prune { .. }
+            | Commands::Step { .. }
+            | Commands::List { .. }
+            | Commands::Log { .. }
+            | Commands::Select
+    )
+}
+
+/// Error unless we're in a worktree or a bare repository (which manages worktrees too)
+fn require_repository() -> anyhow::Result<()> {
+    let repo = worktrunk::git::Repository::current();
+    if repo.is_inside_worktree()? || repo.is_bare().unwrap_or(false) {
+        Ok(())
+    } else {
+        Err(worktrunk::git::GitError::NotInRepository.into())
+    }
+}
+
 /// Write diagnostic file when -vv is used.
 ///
 /// Called at the end of command execution. If verbose level is >= 2, writes
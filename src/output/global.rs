@@ -30,13 +30,14 @@ use std::path::Path;
 use std::path::PathBuf;
 #[cfg(unix)]
 use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, OnceLock};
 use worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR;
 #[cfg(unix)]
 use worktrunk::shell_exec::ShellConfig;
 #[cfg(not(unix))]
 use worktrunk::shell_exec::execute_streaming;
-use worktrunk::styling::{eprintln, stderr};
+use worktrunk::styling::{HINT_SYMBOL, INFO_SYMBOL, eprintln, stderr};
 
 /// Global output state, lazily initialized on first access.
 ///
@@ -85,6 +86,33 @@ fn has_directive_file() -> bool {
         .is_some()
 }
 
+/// Set by `--quiet`: info and hint messages are dropped
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Suppress info and hint messages for the rest of the process (`--quiet`)
+///
+/// Only affects [`print`]. Directives are written regardless, since the shell
+/// wrapper depends on them.
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Set by `wt switch --porcelain`: directory changes are also printed as versioned directives
+static PORCELAIN: AtomicBool = AtomicBool::new(false);
+
+/// Print versioned directives to stdout for the rest of the process (`--porcelain`)
+///
+/// Independent of the directive file: tools wrapping wt parse stdout instead of
+/// the shell wrapper's protocol, which may change between releases.
+pub fn set_porcelain(porcelain: bool) {
+    PORCELAIN.store(porcelain, Ordering::Relaxed);
+}
+
+/// Whether `--porcelain` is active (the caller changes directory itself)
+pub fn is_porcelain() -> bool {
+    PORCELAIN.load(Ordering::Relaxed)
+}
+
 /// Print a message to stderr (written as-is)
 ///
 /// Use with message formatting functions for semantic output:
@@ -94,8 +122,16 @@ fn has_directive_file() -> bool {
 /// output::print(success_message("Branch created"))?;
 /// output::print(hint_message("Use --force to override"))?;
 /// ```
+///
+/// With `--quiet`, info and hint messages are skipped.
 pub fn print(message: impl Into<String>) -> io::Result<()> {
-    eprintln!("{}", message.into());
+    let message = message.into();
+    if QUIET.load(Ordering::Relaxed)
+        && (message.starts_with(INFO_SYMBOL) || message.starts_with(HINT_SYMBOL))
+    {
+        return Ok(());
+    }
+    eprintln!("{message}");
     stderr().flush()
 }
 
@@ -149,6 +185,10 @@ pub fn change_directory(path: impl AsRef<Path>) -> io::Result<()> {
     // Store for execute() to use
     guard.target_dir = Some(path.to_path_buf());
 
+    if is_porcelain() {
+        stdout(porcelain_directive("cd", &path.to_string_lossy())?)?;
+    }
+
     // Write to directive file if set
     if guard.directive_file.is_some() {
         drop(guard); // Release lock before I/O
@@ -157,7 +197,7 @@ pub fn change_directory(path: impl AsRef<Path>) -> io::Result<()> {
         // Escape based on shell type. Both shell families use single-quoted strings
         // where contents are literal, but they escape embedded quotes differently:
         // - PowerShell: double the quote ('it''s')
-        // - POSIX (bash/zsh/fish): end quote, escaped quote, start quote ('it'\''s')
+        // - POSIX (bash/zsh/fish/csh): end quote, escaped quote, start quote ('it'\''s')
         let is_powershell = std::env::var("WORKTRUNK_SHELL")
             .map(|v| v.eq_ignore_ascii_case("powershell"))
             .unwrap_or(false);
@@ -172,6 +212,126 @@ pub fn change_directory(path: impl AsRef<Path>) -> io::Result<()> {
     Ok(())
 }
 
+/// Prefix of `--porcelain` directive lines, including the grammar version.
+///
+/// Lines are `wt-directive:<version> <verb> <argument>`, with the argument taken
+/// verbatim up to the end of the line. The format is stable within a version;
+/// an incompatible change bumps the version instead.
+const PORCELAIN_DIRECTIVE_PREFIX: &str = "wt-directive:1";
+
+/// Format a porcelain directive line, rejecting arguments that span lines.
+fn porcelain_directive(verb: &str, argument: &str) -> io::Result<String> {
+    if argument.contains(['\n', '\r']) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{verb} argument contains a newline"),
+        ));
+    }
+    Ok(format!("{PORCELAIN_DIRECTIVE_PREFIX} {verb} {argument}"))
+}
+
+/// Prefix of env directive lines; the shell wrapper exports `KEY=VALUE` from the rest of the line.
+const ENV_DIRECTIVE_PREFIX: &str = "__WORKTRUNK_ENV__";
+
+/// Format an env directive line, rejecting entries the wrappers can't parse.
+///
+/// Wrappers split on the first `=` (so values may contain `=`), and a newline
+/// ends the directive, so names must be identifiers and values single-line.
+fn env_directive(key: &str, value: &str) -> io::Result<String> {
+    let valid_key = key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid_key {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "not a valid variable name",
+        ));
+    }
+    if value.contains(['\n', '\r']) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "value contains a newline",
+        ));
+    }
+    Ok(format!("{ENV_DIRECTIVE_PREFIX}{key}={value}"))
+}
+
+/// Whether the wrapper sources the directive file as csh code
+///
+/// Csh can't parse directives in an alias, so for tcsh/csh env and exec
+/// directives are written as plain csh commands instead.
+fn is_csh_wrapper() -> bool {
+    std::env::var("WORKTRUNK_SHELL")
+        .is_ok_and(|v| v.eq_ignore_ascii_case("tcsh") || v.eq_ignore_ascii_case("csh"))
+}
+
+/// Request an environment variable export (for shell integration)
+///
+/// If shell integration is active (WORKTRUNK_DIRECTIVE_FILE set), writes an env directive
+/// that the shell wrapper applies with `export` / `set -gx` / `$env:` (or a `setenv`
+/// line for csh). No-op otherwise.
+pub fn set_env(key: &str, value: &str) -> io::Result<()> {
+    let directive = env_directive(key, value)?;
+    if is_csh_wrapper() {
+        return write_directive(&format!("setenv {key} '{}'", value.replace('\'', "'\\''")));
+    }
+    write_directive(&directive)
+}
+
+/// Prefix of exec directive lines; the shell wrapper runs the rest of the line after the `cd`.
+const EXEC_DIRECTIVE_PREFIX: &str = "__WORKTRUNK_EXEC__";
+
+/// Format an exec directive line, rejecting commands that span lines.
+fn exec_directive(command: &str) -> io::Result<String> {
+    if command.contains(['\n', '\r']) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "command contains a newline",
+        ));
+    }
+    Ok(format!("{EXEC_DIRECTIVE_PREFIX}{command}"))
+}
+
+/// Request a command to run in the user's shell once the wrapper has applied the `cd`
+///
+/// Unlike [`execute`], this never runs anything itself: without shell integration
+/// (no WORKTRUNK_DIRECTIVE_FILE) it's a no-op, so the command can't leak to a plain
+/// terminal. The wrapper ignores its exit code.
+pub fn exec_in_shell(command: &str) -> io::Result<()> {
+    let directive = exec_directive(command)?;
+    if is_csh_wrapper() {
+        // Written after the `cd`, and the alias saves the exit code before sourcing
+        return write_directive(command);
+    }
+    write_directive(&directive)
+}
+
+/// Prefix of error directive lines; the shell wrapper applies no other directives when it sees one.
+const ERROR_DIRECTIVE_PREFIX: &str = "__WORKTRUNK_ERR__";
+
+/// Report a failed command to the shell wrapper
+///
+/// If shell integration is active (WORKTRUNK_DIRECTIVE_FILE set), replaces any pending
+/// directives with a single `__WORKTRUNK_ERR__<message>` line, so the wrapper neither
+/// changes directory nor runs commands on behalf of a failed command. The csh alias
+/// sources the file verbatim, so for tcsh/csh the file is only emptied. No-op otherwise.
+pub fn report_error(message: &str) -> io::Result<()> {
+    let path = {
+        let guard = get_state().lock().expect("OUTPUT_STATE lock poisoned");
+        guard.directive_file.clone()
+    };
+
+    let Some(path) = path else {
+        return Ok(());
+    };
+
+    let mut file = OpenOptions::new().write(true).truncate(true).open(&path)?;
+    if !is_csh_wrapper() {
+        let message = message.lines().next().unwrap_or_default();
+        writeln!(file, "{ERROR_DIRECTIVE_PREFIX}{message}")?;
+    }
+    file.flush()
+}
+
 /// Request command execution
 ///
 /// In interactive mode (no directive file), executes the command directly (replacing process on Unix).
@@ -230,7 +390,7 @@ fn execute_command(command: String, target_dir: Option<&Path>) -> anyhow::Result
     // On non-Unix platforms, fall back to spawn-and-wait.
     // This uses the shell abstraction (Git Bash if available).
     let exec_dir = target_dir.unwrap_or_else(|| Path::new("."));
-    if let Err(err) = execute_streaming(&command, exec_dir, false, None, true, false) {
+    if let Err(err) = execute_streaming(&command, exec_dir, false, None, true, false, &[]) {
         // If the command failed with an exit code, just exit with that code.
         // This matches Unix behavior where exec() replaces the process and
         // the shell's exit code becomes the process exit code (no error message).
@@ -475,6 +635,47 @@ mod tests {
         assert_eq!(cd_cmd, "cd '/test/my path/here'");
     }
 
+    #[test]
+    fn test_env_directive_format() {
+        // Values are written verbatim; wrappers split on the first `=`
+        assert_eq!(
+            env_directive("DATABASE_URL", "postgres://h/db?a=b c").unwrap(),
+            "__WORKTRUNK_ENV__DATABASE_URL=postgres://h/db?a=b c"
+        );
+        assert_eq!(
+            env_directive("_EMPTY", "").unwrap(),
+            "__WORKTRUNK_ENV___EMPTY="
+        );
+    }
+
+    #[test]
+    fn test_env_directive_rejects_unparseable_entries() {
+        assert!(env_directive("1ABC", "x").is_err());
+        assert!(env_directive("MY-VAR", "x").is_err());
+        assert!(env_directive("", "x").is_err());
+        assert!(env_directive("A=B", "x").is_err());
+        assert!(env_directive("MULTI", "line1\nline2").is_err());
+    }
+
+    #[test]
+    fn test_exec_directive_format() {
+        assert_eq!(
+            exec_directive("code '/path/with space'").unwrap(),
+            "__WORKTRUNK_EXEC__code '/path/with space'"
+        );
+        assert!(exec_directive("code .\nrm -rf /").is_err());
+    }
+
+    #[test]
+    fn test_porcelain_directive_format() {
+        // Paths are written verbatim, without shell quoting
+        assert_eq!(
+            porcelain_directive("cd", "/path/it's with space").unwrap(),
+            "wt-directive:1 cd /path/it's with space"
+        );
+        assert!(porcelain_directive("cd", "/path\nrm -rf /").is_err());
+    }
+
     /// Test that anstyle formatting is preserved
     #[test]
     fn test_success_preserves_anstyle() {
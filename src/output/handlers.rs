@@ -7,9 +7,9 @@ use crate::commands::branch_deletion::{
     BranchDeletionOutcome, BranchDeletionResult, delete_branch_if_safe,
 };
 use crate::commands::command_executor::CommandContext;
-use crate::commands::execute_pre_remove_commands;
 use crate::commands::process::{build_remove_command, spawn_detached};
 use crate::commands::worktree::{BranchDeletionMode, RemoveResult, SwitchBranchInfo, SwitchResult};
+use crate::commands::{HookFailureStrategy, execute_pre_remove_commands};
 use worktrunk::config::WorktrunkConfig;
 use worktrunk::git::GitError;
 use worktrunk::git::IntegrationReason;
@@ -239,7 +239,21 @@ fn print_switch_message_if_changed(
         super::print(info_message(cformat!(
             "Switched to worktree for <bold>{dest_branch}</> @ <bold>{path_display}</>"
         )))?;
-    } else if crate::is_git_subcommand() {
+        Ok(())
+    } else {
+        print_cannot_change_directory()
+    }
+}
+
+/// Warn that the shell's directory won't change, with the specific reason
+///
+/// Only call this when shell integration is not active.
+fn print_cannot_change_directory() -> anyhow::Result<()> {
+    if super::is_porcelain() {
+        // The caller follows the `cd` directive on stdout
+        return Ok(());
+    }
+    if crate::is_git_subcommand() {
         // Running as `git wt` - explain why cd can't work
         super::print(warning_message(
             "Cannot change directory — ran git wt; running through git prevents cd",
@@ -256,6 +270,50 @@ fn print_switch_message_if_changed(
     Ok(())
 }
 
+/// Handle output for `wt clone`
+///
+/// Requests a cd into the initial worktree and, when the shell can't follow,
+/// warns the same way `wt switch` does.
+pub fn handle_clone_output(worktree_path: &Path, branch: &str) -> anyhow::Result<()> {
+    super::change_directory(worktree_path)?;
+
+    let path_display = format_path_for_display(worktree_path);
+    super::print(success_message(cformat!(
+        "Cloned <bold>{branch}</> @ <bold>{path_display}</>"
+    )))?;
+    if !super::is_shell_integration_active() {
+        print_cannot_change_directory()?;
+    }
+    Ok(())
+}
+
+/// Handle output for `wt switch --detach`
+///
+/// Requests a cd into the scratch worktree at `short_commit`; `created` is false
+/// when an earlier detach switch already made it.
+pub fn handle_detach_output(
+    worktree_path: &Path,
+    short_commit: &str,
+    created: bool,
+) -> anyhow::Result<()> {
+    super::change_directory(worktree_path)?;
+
+    let path_display = format_path_for_display(worktree_path);
+    if created {
+        super::print(success_message(cformat!(
+            "Created detached worktree at <bold>{short_commit}</> @ <bold>{path_display}</>"
+        )))?;
+    } else {
+        super::print(info_message(cformat!(
+            "Switched to detached worktree at <bold>{short_commit}</> @ <bold>{path_display}</>"
+        )))?;
+    }
+    if !super::is_shell_integration_active() {
+        print_cannot_change_directory()?;
+    }
+    Ok(())
+}
+
 /// Handle output for a switch operation
 ///
 /// # Shell Integration Warnings
@@ -297,8 +355,9 @@ pub fn handle_switch_output(
     let path_display = format_path_for_display(path);
     let branch = &branch_info.branch;
 
-    // Check if shell integration is active (directive file set)
-    let is_shell_integration_active = super::is_shell_integration_active();
+    // Check if shell integration is active (directive file set); with --porcelain
+    // the caller follows the `cd` directive itself, so treat it the same way
+    let is_shell_integration_active = super::is_shell_integration_active() || super::is_porcelain();
 
     // Compute shell warning reason once (only if we'll need it)
     // Git subcommand case is special — needs a hint after the warning
@@ -581,7 +640,8 @@ fn handle_removed_worktree_output(
     let repo = worktrunk::git::Repository::current();
 
     // Execute pre-remove hooks in the worktree being removed
-    // Non-zero exit aborts removal (FailFast strategy)
+    // Non-zero exit aborts removal (FailFast strategy), unless --force was passed,
+    // in which case failures are warnings so teardown problems can't block removal
     // For detached HEAD, {{ branch }} expands to "HEAD" in templates
     if verify && let Ok(config) = WorktrunkConfig::load() {
         let target_repo = Repository::at(worktree_path);
@@ -599,7 +659,12 @@ fn handle_removed_worktree_output(
         } else {
             Some(worktree_path) // Show path when user is elsewhere
         };
-        execute_pre_remove_commands(&ctx, None, display_path, &[])?;
+        let failure_strategy = if force_worktree {
+            HookFailureStrategy::Warn
+        } else {
+            HookFailureStrategy::FailFast
+        };
+        execute_pre_remove_commands(&ctx, None, display_path, &[], failure_strategy)?;
     }
 
     // Handle detached HEAD case (no branch known)
@@ -617,6 +682,7 @@ fn handle_removed_worktree_output(
                 "detached",
                 "remove",
                 None,
+                &[],
             )?;
         } else {
             let target_repo = worktrunk::git::Repository::at(worktree_path);
@@ -734,6 +800,7 @@ fn handle_removed_worktree_output(
             branch_name,
             "remove",
             None,
+            &[],
         )?;
 
         spawn_post_switch_after_remove(main_path, verify, changed_directory)?;
@@ -842,7 +909,7 @@ fn handle_removed_worktree_output(
 /// Per CLAUDE.md guidelines: child process output goes to stderr, worktrunk output goes to stdout.
 ///
 /// If `stdin_content` is provided, it will be piped to the command's stdin. This is used to pass
-/// hook context as JSON to hook commands.
+/// hook context as JSON to hook commands. `env` is added to the command's environment.
 ///
 /// ## Color Bleeding Prevention
 ///
@@ -863,6 +930,7 @@ pub fn execute_command_in_worktree(
     worktree_path: &std::path::Path,
     command: &str,
     stdin_content: Option<&str>,
+    env: &[(&str, &str)],
 ) -> anyhow::Result<()> {
     use std::io::Write;
     use worktrunk::shell_exec::execute_streaming;
@@ -880,7 +948,15 @@ pub fn execute_command_in_worktree(
 
     // Execute with stdout→stderr redirect for deterministic ordering
     // Hooks don't need stdin inheritance (inherit_stdin=false)
-    execute_streaming(command, worktree_path, true, stdin_content, false, true)?;
+    execute_streaming(
+        command,
+        worktree_path,
+        true,
+        stdin_content,
+        false,
+        true,
+        env,
+    )?;
 
     // Flush to ensure all output appears before we continue
     super::flush()?;
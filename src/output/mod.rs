@@ -18,8 +18,9 @@
 //! ## Shell Integration
 //!
 //! When `WORKTRUNK_DIRECTIVE_FILE` env var is set (by shell wrapper):
-//! - Shell commands (cd, exec) are written to that file
+//! - Shell commands (cd, exec) and env exports are written to that file
 //! - Shell wrapper sources the file after wt exits
+//! - A failed command replaces them with an error directive, so the wrapper applies none
 //! - This allows the parent shell to change directory
 //!
 //! When not set (direct binary call):
@@ -34,14 +35,17 @@ pub mod shell_integration;
 
 // Re-export the public API
 pub use global::{
-    blank, change_directory, execute, flush, is_shell_integration_active, post_hook_display_path,
-    pre_hook_display_path, print, stdout, terminate_output,
+    blank, change_directory, exec_in_shell, execute, flush, is_porcelain,
+    is_shell_integration_active, post_hook_display_path, pre_hook_display_path, print,
+    report_error, set_env, set_porcelain, set_quiet, stdout, terminate_output,
 };
 // Re-export output handlers
 pub use handlers::{
-    execute_command_in_worktree, execute_user_command, handle_remove_output, handle_switch_output,
+    execute_command_in_worktree, execute_user_command, handle_clone_output, handle_detach_output,
+    handle_remove_output, handle_switch_output,
 };
 // Re-export shell integration functions
 pub use shell_integration::{
-    print_shell_install_result, print_skipped_shells, prompt_shell_integration,
+    print_duplicate_integrations, print_shell_install_result, print_skipped_shells,
+    prompt_shell_integration,
 };
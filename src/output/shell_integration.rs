@@ -61,7 +61,7 @@ use color_print::cformat;
 use worktrunk::config::WorktrunkConfig;
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell::{Shell, extract_filename_from_path};
-use worktrunk::styling::hint_message;
+use worktrunk::styling::{hint_message, warning_message};
 
 /// Shell integration install hint message.
 // TODO(hints-count): After showing this hint 5+ times, suggest `wt config show` for diagnostics.
@@ -79,7 +79,7 @@ pub(crate) fn shell_restart_hint() -> &'static str {
 /// Shell integration hint for unknown/unsupported shell.
 fn shell_integration_unsupported_shell(shell_path: &str) -> String {
     // Extract shell name from path, handling both Unix and Windows paths
-    // e.g., "/bin/tcsh" -> "tcsh", "C:\...\tcsh.exe" -> "tcsh"
+    // e.g., "/bin/ksh" -> "ksh", "C:\...\ksh.exe" -> "ksh"
     let shell_name = extract_filename_from_path(shell_path).unwrap_or(shell_path);
     format!(
         "Shell integration not yet supported for {shell_name} (supports bash, zsh, fish, PowerShell)"
@@ -160,12 +160,36 @@ pub fn print_skipped_shells(
     Ok(())
 }
 
+/// Warn about shells whose integration line is in several config files.
+///
+/// Loading it twice is harmless but slows startup; listing the files lets the
+/// user pick which one to keep. Nothing is modified.
+pub fn print_duplicate_integrations(
+    duplicates: &[(worktrunk::shell::Shell, Vec<std::path::PathBuf>)],
+) -> anyhow::Result<()> {
+    for (shell, paths) in duplicates {
+        let paths = paths
+            .iter()
+            .map(|path| cformat!("<bold>{}</>", format_path_for_display(path)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        super::print(warning_message(cformat!(
+            "Shell integration for <bold>{shell}</> is in multiple files: {paths}"
+        )))?;
+        super::print(hint_message(
+            "Remove the line from all but one so it only loads once",
+        ))?;
+    }
+    Ok(())
+}
+
 /// Print the result of shell integration installation.
 ///
 /// Shows:
 /// - Configured shells with their paths
 /// - Completion results (for fish)
 /// - Skipped shells
+/// - Shells configured in more than one file
 /// - Summary count
 /// - Zsh compinit warning if needed
 /// - Restart hint for current shell
@@ -175,9 +199,7 @@ pub fn print_shell_install_result(
     scan_result: &crate::commands::configure_shell::ScanResult,
 ) -> anyhow::Result<()> {
     use crate::commands::configure_shell::ConfigAction;
-    use worktrunk::styling::{
-        format_bash_with_gutter, info_message, success_message, warning_message,
-    };
+    use worktrunk::styling::{format_bash_with_gutter, info_message, success_message};
 
     // Count shells that became (more) configured
     let shells_configured_count = scan_result
@@ -244,10 +266,17 @@ pub fn print_shell_install_result(
                 }
             }
         }
+
+        if !shell.supports_completion() {
+            super::print(hint_message(cformat!(
+                "Completions not yet supported for <bold>{shell}</>"
+            )))?;
+        }
     }
 
     // Show skipped shells
     print_skipped_shells(&scan_result.skipped)?;
+    print_duplicate_integrations(&scan_result.duplicates)?;
 
     // Summary
     if shells_configured_count > 0 {
@@ -306,7 +335,7 @@ pub fn prompt_shell_integration(
     skip_prompt: bool,
 ) -> anyhow::Result<bool> {
     use crate::commands::configure_shell::{
-        ConfigAction, handle_configure_shell, prompt_for_install, scan_shell_configs,
+        ConfigAction, ShellCmds, handle_configure_shell, prompt_for_install, scan_shell_configs,
     };
     use std::io::IsTerminal;
     use worktrunk::shell::current_shell;
@@ -336,7 +365,9 @@ pub fn prompt_shell_integration(
 
     // Scan ALL shells (same as `wt config shell install`)
     // Only includes shells where config files already exist
-    let scan = scan_shell_configs(None, true, binary_name)
+    // Each shell keeps the command name it was last installed under
+    let cmds = ShellCmds::recorded(config, binary_name.to_string());
+    let scan = scan_shell_configs(None, None, true, &cmds)
         .map_err(|e| anyhow::anyhow!("Failed to scan shell configs: {e}"))?;
 
     // No config files exist - show install hint
@@ -374,7 +405,7 @@ pub fn prompt_shell_integration(
     let confirmed = prompt_for_install(
         &scan.configured,
         &scan.completion_results,
-        binary_name,
+        &cmds,
         "Install shell integration?",
     )
     .map_err(|e| anyhow::anyhow!("{e}"))?;
@@ -387,7 +418,7 @@ pub fn prompt_shell_integration(
     }
 
     // Install for all shells with config files (same as `wt config shell install`)
-    let install_result = handle_configure_shell(None, true, binary_name.to_string())
+    let install_result = handle_configure_shell(None, true, false, None, None, &cmds)
         .map_err(|e| anyhow::anyhow!("Failed to configure shell integration: {e}"))?;
 
     print_shell_install_result(&install_result)?;
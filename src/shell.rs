@@ -31,6 +31,27 @@ fn powershell_profile_paths(home: &std::path::Path) -> Vec<PathBuf> {
     }
 }
 
+/// Get the nushell vendor autoload directory (`$nu.data-dir/vendor/autoload`).
+///
+/// Nushell sources every `.nu` file in this directory at startup. `$nu.data-dir`
+/// follows XDG_DATA_HOME and defaults to `~/.local/share/nushell`.
+fn nu_autoload_dir(home: &std::path::Path) -> PathBuf {
+    let data_home = choose_base_strategy()
+        .map(|s| s.data_dir())
+        .unwrap_or_else(|_| home.join(".local").join("share"));
+    data_home.join("nushell").join("vendor").join("autoload")
+}
+
+/// Get fish's config directory (`$__fish_config_dir`).
+///
+/// Fish follows XDG_CONFIG_HOME and defaults to `~/.config/fish`.
+fn fish_config_dir(home: &std::path::Path) -> PathBuf {
+    let config_home = choose_base_strategy()
+        .map(|s| s.config_dir())
+        .unwrap_or_else(|_| home.join(".config"));
+    config_home.join("fish")
+}
+
 /// Get the user's home directory or return an error
 fn home_dir_required() -> Result<PathBuf, std::io::Error> {
     home_dir().ok_or_else(|| {
@@ -90,6 +111,8 @@ fn home_dir_required() -> Result<PathBuf, std::io::Error> {
 ///
 /// - Quoted command names: `eval "$('wt' config shell init bash)"` - rare but matched
 /// - Comment lines: `# eval "$(wt config shell init bash)"` - skipped
+/// - PowerShell block comments: `<# iex (wt config shell init powershell) #>` - skipped
+/// - PowerShell `iex` alias: `iex (& wt config shell init powershell | Out-String)` - matched
 /// - Partial matches: `newt config shell init` - not matched (word boundary)
 ///
 /// # Usage
@@ -118,7 +141,7 @@ pub fn is_shell_integration_line(line: &str, cmd: &str) -> bool {
     let trimmed = line.trim();
 
     // Skip comments (# for POSIX shells, <# #> for PowerShell)
-    if trimmed.starts_with('#') {
+    if trimmed.starts_with('#') || trimmed.starts_with("<#") {
         return false;
     }
 
@@ -172,10 +195,13 @@ fn has_init_pattern_with_prefix_check(line: &str, cmd: &str) -> bool {
 
             // Check what precedes the match
             if is_valid_command_position(line, absolute_pos, &cmd_in_line) {
-                // Must be in an execution context
+                // Must be in an execution context (`iex` is PowerShell's
+                // built-in alias for Invoke-Expression)
                 if line.contains("eval")
+                    || line.contains("execx")
                     || line.contains("source")
                     || line.contains("Invoke-Expression")
+                    || contains_cmd_at_word_boundary(line, "iex")
                     || line.contains("if ")
                 {
                     return true;
@@ -308,7 +334,7 @@ fn scan_file(path: &std::path::Path, cmd: &str) -> Option<FileDetectionResult> {
         let line_number = line_number + 1; // 1-based
         let trimmed = line.trim();
         // Skip empty lines and comments
-        if trimmed.is_empty() || trimmed.starts_with('#') {
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with("<#") {
             continue;
         }
 
@@ -446,7 +472,16 @@ pub fn scan_for_detection_details(cmd: &str) -> Result<Vec<FileDetectionResult>,
             .unwrap_or_else(|_| home.clone())
             .join(".zshrc"),
         // Fish conf.d
-        home.join(".config/fish/conf.d").join(format!("{cmd}.fish")),
+        fish_config_dir(&home)
+            .join("conf.d")
+            .join(format!("{cmd}.fish")),
+        // Elvish
+        home.join(".config/elvish/rc.elv"),
+        // Xonsh
+        home.join(".config/xonsh/rc.xsh"),
+        // Tcsh/Csh (tcsh falls back to .cshrc when .tcshrc is missing)
+        home.join(".tcshrc"),
+        home.join(".cshrc"),
     ];
 
     // Add PowerShell profiles
@@ -468,7 +503,7 @@ pub fn scan_for_detection_details(cmd: &str) -> Result<Vec<FileDetectionResult>,
 
 /// Supported shells
 ///
-/// Currently supported: bash, fish, zsh, powershell
+/// Currently supported: bash, fish, zsh, nu, elvish, xonsh, tcsh, csh, powershell
 ///
 /// On Windows, Git Bash users should use `bash` for shell integration.
 /// PowerShell integration is available for native Windows users without Git Bash.
@@ -478,11 +513,40 @@ pub enum Shell {
     Bash,
     Fish,
     Zsh,
+    /// Nushell (`nu`); `nushell` is accepted as an alias.
+    #[strum(to_string = "nu", serialize = "nushell")]
+    #[clap(name = "nu", alias = "nushell")]
+    Nu,
+    Elvish,
+    Xonsh,
+    Tcsh,
+    Csh,
     #[strum(serialize = "powershell")]
     #[clap(name = "powershell")]
     PowerShell,
 }
 
+/// Bash startup file targeted by `wt config shell install`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum BashRc {
+    /// `~/.bashrc`, read by interactive non-login shells
+    #[clap(name = "bashrc")]
+    Bashrc,
+    /// `~/.bash_profile`, read by login shells (the macOS Terminal default)
+    #[clap(name = "bash_profile", alias = "bash-profile")]
+    BashProfile,
+}
+
+impl BashRc {
+    /// File name of this startup file, relative to the home directory
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Self::Bashrc => ".bashrc",
+            Self::BashProfile => ".bash_profile",
+        }
+    }
+}
+
 impl Shell {
     /// Returns the config file paths for this shell.
     ///
@@ -493,8 +557,9 @@ impl Shell {
 
         Ok(match self {
             Self::Bash => {
-                // Use .bashrc - sourced by interactive shells (login shells should source .bashrc)
-                vec![home.join(".bashrc")]
+                // Prefer .bashrc - sourced by interactive shells (login shells should source
+                // .bashrc). Fall back to .bash_profile for setups that only have a login file.
+                vec![home.join(".bashrc"), home.join(".bash_profile")]
             }
             Self::Zsh => {
                 let zdotdir = std::env::var("ZDOTDIR")
@@ -506,16 +571,57 @@ impl Shell {
                 // For fish, we write to conf.d/ which is auto-sourced
                 // Filename includes prefix to avoid conflicts (e.g., wt.fish, git-wt.fish)
                 vec![
-                    home.join(".config")
-                        .join("fish")
+                    fish_config_dir(&home)
                         .join("conf.d")
                         .join(format!("{}.fish", cmd)),
                 ]
             }
+            Self::Nu => {
+                // Nushell can't source generated code at startup, so the init script
+                // itself is written to a vendor autoload directory (like fish conf.d/)
+                vec![nu_autoload_dir(&home).join(format!("{}.nu", cmd))]
+            }
+            Self::Elvish => vec![home.join(".config").join("elvish").join("rc.elv")],
+            Self::Xonsh => vec![home.join(".config").join("xonsh").join("rc.xsh")],
+            Self::Tcsh => {
+                // Tcsh reads .cshrc only when .tcshrc is missing, so an existing
+                // .cshrc is used rather than creating a .tcshrc that would shadow it
+                vec![home.join(".tcshrc"), home.join(".cshrc")]
+            }
+            Self::Csh => vec![home.join(".cshrc")],
             Self::PowerShell => powershell_profile_paths(&home),
         })
     }
 
+    /// Returns the path for a cached copy of this shell's `wt config shell init` output.
+    ///
+    /// The filename includes the command name and worktrunk version, so upgrading
+    /// the binary never serves a stale script. Returns `None` for Nushell, whose
+    /// init output is saved to the autoload directory rather than evaluated at startup,
+    /// and for Elvish, which can only `eval` a string rather than source a file into scope.
+    pub fn init_cache_path(&self, cmd: &str) -> Result<Option<PathBuf>, std::io::Error> {
+        let extension = match self {
+            Self::Bash => "bash",
+            Self::Zsh => "zsh",
+            Self::Fish => "fish",
+            Self::Xonsh => "xsh",
+            Self::Tcsh => "tcsh",
+            Self::Csh => "csh",
+            Self::PowerShell => "ps1",
+            Self::Nu | Self::Elvish => return Ok(None),
+        };
+        let home = home_dir_required()?;
+        // XDG_CACHE_HOME defaults to ~/.cache
+        let cache_home = choose_base_strategy()
+            .ok()
+            .map(|s| s.cache_dir())
+            .unwrap_or_else(|| home.join(".cache"));
+        Ok(Some(cache_home.join("worktrunk").join(format!(
+            "init-{cmd}-{}.{extension}",
+            env!("CARGO_PKG_VERSION")
+        ))))
+    }
+
     /// Returns the path to the native completion directory for this shell.
     ///
     /// The `cmd` parameter affects the completion filename (e.g., `wt.fish` or `git-wt.fish`).
@@ -543,16 +649,28 @@ impl Shell {
                     .join(cmd)
             }
             Self::Zsh => home.join(".zfunc").join(format!("_{}", cmd)),
-            Self::Fish => {
-                // XDG_CONFIG_HOME defaults to ~/.config
-                let config_home = strategy
-                    .as_ref()
-                    .map(|s| s.config_dir())
-                    .unwrap_or_else(|| home.join(".config"));
-                config_home
-                    .join("fish")
-                    .join("completions")
-                    .join(format!("{}.fish", cmd))
+            Self::Fish => fish_config_dir(&home)
+                .join("completions")
+                .join(format!("{}.fish", cmd)),
+            Self::Nu => {
+                // Nushell completions are defined inline in the init script using a
+                // custom completer. Return a dummy path that won't be used
+                home.join(format!(".{}-nu-completions", cmd))
+            }
+            Self::Elvish => {
+                // Elvish completions aren't supported yet (see `supports_completion`)
+                // Return a dummy path that won't be used
+                home.join(format!(".{}-elvish-completions", cmd))
+            }
+            Self::Xonsh => {
+                // Xonsh completions aren't supported yet (see `supports_completion`)
+                // Return a dummy path that won't be used
+                home.join(format!(".{}-xonsh-completions", cmd))
+            }
+            Self::Tcsh | Self::Csh => {
+                // Csh completions aren't supported (see `supports_completion`)
+                // Return a dummy path that won't be used
+                home.join(format!(".{}-{}-completions", cmd, self))
             }
             Self::PowerShell => {
                 // PowerShell doesn't use a separate completion file - completions are
@@ -584,6 +702,25 @@ impl Shell {
                     self
                 )
             }
+            Self::Nu => {
+                // Nushell has no runtime eval; this regenerates the autoload file instead
+                format!(
+                    "{cmd} config shell init nu | save --force ($nu.data-dir | path join vendor autoload {cmd}.nu)"
+                )
+            }
+            Self::Elvish => {
+                format!(
+                    "if (has-external {cmd}) {{ eval (e:{cmd} config shell init elvish | slurp) }}"
+                )
+            }
+            Self::Xonsh => {
+                format!(
+                    "if __import__(\"shutil\").which(\"{cmd}\"): execx($({cmd} config shell init xonsh))"
+                )
+            }
+            Self::Tcsh | Self::Csh => {
+                format!("which {cmd} >& /dev/null && eval \"`{cmd} config shell init {self}`\"")
+            }
             Self::PowerShell => {
                 format!(
                     "if (Get-Command {cmd} -ErrorAction SilentlyContinue) {{ Invoke-Expression (& {cmd} config shell init powershell) }}",
@@ -592,6 +729,26 @@ impl Shell {
         }
     }
 
+    /// Whether worktrunk provides tab completions for this shell.
+    ///
+    /// clap_complete has no Elvish, Xonsh or csh backend, so those shells get
+    /// the shell wrapper only.
+    pub fn supports_completion(&self) -> bool {
+        !matches!(self, Self::Elvish | Self::Xonsh | Self::Tcsh | Self::Csh)
+    }
+
+    /// Best guess at the user's shell.
+    ///
+    /// Reads `$SHELL` like [`current_shell`]; when it isn't set, falls back to
+    /// the name of the parent process where that's available. Returns `None`
+    /// when the shell isn't one worktrunk supports.
+    pub fn detect() -> Option<Self> {
+        match std::env::var("SHELL").ok().filter(|path| !path.is_empty()) {
+            Some(path) => shell_from_name(extract_filename_from_path(&path)?),
+            None => parent_process_shell(),
+        }
+    }
+
     /// Check if shell integration is configured for the given command name.
     ///
     /// Returns the path to the first config file with integration if found.
@@ -609,15 +766,51 @@ impl Shell {
     }
 }
 
+/// Which parts of the init script to generate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InitSections {
+    /// The shell wrapper and completion registration
+    #[default]
+    All,
+    /// Only the shell wrapper that applies directives (`cd`, env, exec)
+    Integration,
+    /// Only completion registration
+    Completions,
+}
+
+impl InitSections {
+    pub fn integration(self) -> bool {
+        !matches!(self, Self::Completions)
+    }
+
+    pub fn completions(self) -> bool {
+        !matches!(self, Self::Integration)
+    }
+}
+
 /// Shell integration configuration
 pub struct ShellInit {
     pub shell: Shell,
     pub cmd: String,
+    pub sections: InitSections,
 }
 
 impl ShellInit {
     pub fn with_prefix(shell: Shell, cmd: String) -> Self {
-        Self { shell, cmd }
+        Self {
+            shell,
+            cmd,
+            sections: InitSections::All,
+        }
+    }
+
+    /// Generate only some sections of the script
+    ///
+    /// Fish completions live in a separate file and elvish, xonsh and csh have
+    /// none, so for those shells only the wrapper is affected.
+    pub fn with_sections(mut self, sections: InitSections) -> Self {
+        self.sections = sections;
+        self
     }
 
     /// Generate shell integration code
@@ -627,19 +820,52 @@ impl ShellInit {
                 let template = BashTemplate {
                     shell_name: self.shell.to_string(),
                     cmd: &self.cmd,
+                    integration: self.sections.integration(),
+                    completions: self.sections.completions(),
                 };
                 template.render()
             }
             Shell::Zsh => {
-                let template = ZshTemplate { cmd: &self.cmd };
+                let template = ZshTemplate {
+                    cmd: &self.cmd,
+                    integration: self.sections.integration(),
+                    completions: self.sections.completions(),
+                };
                 template.render()
             }
             Shell::Fish => {
                 let template = FishTemplate { cmd: &self.cmd };
                 template.render()
             }
+            Shell::Nu => {
+                let template = NuTemplate {
+                    cmd: &self.cmd,
+                    integration: self.sections.integration(),
+                    completions: self.sections.completions(),
+                };
+                template.render()
+            }
+            Shell::Elvish => {
+                let template = ElvishTemplate { cmd: &self.cmd };
+                template.render()
+            }
+            Shell::Xonsh => {
+                let template = XonshTemplate { cmd: &self.cmd };
+                template.render()
+            }
+            Shell::Tcsh | Shell::Csh => {
+                let template = CshTemplate {
+                    shell_name: self.shell.to_string(),
+                    cmd: &self.cmd,
+                };
+                template.render()
+            }
             Shell::PowerShell => {
-                let template = PowerShellTemplate { cmd: &self.cmd };
+                let template = PowerShellTemplate {
+                    cmd: &self.cmd,
+                    integration: self.sections.integration(),
+                    completions: self.sections.completions(),
+                };
                 template.render()
             }
         }
@@ -652,6 +878,8 @@ impl ShellInit {
 struct BashTemplate<'a> {
     shell_name: String,
     cmd: &'a str,
+    integration: bool,
+    completions: bool,
 }
 
 /// Zsh shell template
@@ -659,6 +887,8 @@ struct BashTemplate<'a> {
 #[template(path = "zsh.zsh", escape = "none")]
 struct ZshTemplate<'a> {
     cmd: &'a str,
+    integration: bool,
+    completions: bool,
 }
 
 /// Fish shell template
@@ -668,11 +898,44 @@ struct FishTemplate<'a> {
     cmd: &'a str,
 }
 
+/// Nushell template
+#[derive(Template)]
+#[template(path = "nu.nu", escape = "none")]
+struct NuTemplate<'a> {
+    cmd: &'a str,
+    integration: bool,
+    completions: bool,
+}
+
+/// Elvish template
+#[derive(Template)]
+#[template(path = "elvish.elv", escape = "none")]
+struct ElvishTemplate<'a> {
+    cmd: &'a str,
+}
+
+/// Xonsh template
+#[derive(Template)]
+#[template(path = "xonsh.xsh", escape = "none")]
+struct XonshTemplate<'a> {
+    cmd: &'a str,
+}
+
+/// Tcsh/Csh template
+#[derive(Template)]
+#[template(path = "csh.csh", escape = "none")]
+struct CshTemplate<'a> {
+    shell_name: String,
+    cmd: &'a str,
+}
+
 /// PowerShell template
 #[derive(Template)]
 #[template(path = "powershell.ps1", escape = "none")]
 struct PowerShellTemplate<'a> {
     cmd: &'a str,
+    integration: bool,
+    completions: bool,
 }
 
 /// Detect if user's zsh has compinit enabled by probing for the compdef function.
@@ -803,6 +1066,16 @@ fn shell_from_name(shell_name: &str) -> Option<Shell> {
         Some(Shell::Bash)
     } else if name_lower.starts_with("fish") {
         Some(Shell::Fish)
+    } else if name_lower == "nu" || name_lower.starts_with("nushell") {
+        Some(Shell::Nu)
+    } else if name_lower.starts_with("elvish") {
+        Some(Shell::Elvish)
+    } else if name_lower.starts_with("xonsh") {
+        Some(Shell::Xonsh)
+    } else if name_lower.starts_with("tcsh") {
+        Some(Shell::Tcsh)
+    } else if name_lower.starts_with("csh") {
+        Some(Shell::Csh)
     } else if name_lower.starts_with("pwsh") || name_lower.starts_with("powershell") {
         Some(Shell::PowerShell)
     } else {
@@ -825,6 +1098,34 @@ pub fn current_shell() -> Option<Shell> {
     shell_from_name(shell_name)
 }
 
+/// The shell that launched us, from the parent process name.
+///
+/// Reads `/proc` on Linux and asks `ps` on other Unix systems. Login shells
+/// report names like `-zsh`, so a leading dash is ignored.
+#[cfg(unix)]
+fn parent_process_shell() -> Option<Shell> {
+    let ppid = std::os::unix::process::parent_id();
+    let name = std::fs::read_to_string(format!("/proc/{ppid}/comm"))
+        .ok()
+        .or_else(|| {
+            let output = std::process::Command::new("ps")
+                .args(["-o", "comm=", "-p", &ppid.to_string()])
+                .output()
+                .ok()?;
+            output
+                .status
+                .success()
+                .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+        })?;
+    let name = extract_filename_from_path(name.trim())?;
+    shell_from_name(name.trim_start_matches('-'))
+}
+
+#[cfg(not(unix))]
+fn parent_process_shell() -> Option<Shell> {
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -836,6 +1137,13 @@ mod tests {
         assert!(matches!("BASH".parse::<Shell>(), Ok(Shell::Bash)));
         assert!(matches!("fish".parse::<Shell>(), Ok(Shell::Fish)));
         assert!(matches!("zsh".parse::<Shell>(), Ok(Shell::Zsh)));
+        assert!(matches!("nu".parse::<Shell>(), Ok(Shell::Nu)));
+        assert!(matches!("nushell".parse::<Shell>(), Ok(Shell::Nu)));
+        assert!(matches!("NuShell".parse::<Shell>(), Ok(Shell::Nu)));
+        assert!(matches!("elvish".parse::<Shell>(), Ok(Shell::Elvish)));
+        assert!(matches!("xonsh".parse::<Shell>(), Ok(Shell::Xonsh)));
+        assert!(matches!("tcsh".parse::<Shell>(), Ok(Shell::Tcsh)));
+        assert!(matches!("csh".parse::<Shell>(), Ok(Shell::Csh)));
         assert!(matches!(
             "powershell".parse::<Shell>(),
             Ok(Shell::PowerShell)
@@ -852,6 +1160,11 @@ mod tests {
         assert_eq!(Shell::Bash.to_string(), "bash");
         assert_eq!(Shell::Fish.to_string(), "fish");
         assert_eq!(Shell::Zsh.to_string(), "zsh");
+        assert_eq!(Shell::Nu.to_string(), "nu");
+        assert_eq!(Shell::Elvish.to_string(), "elvish");
+        assert_eq!(Shell::Xonsh.to_string(), "xonsh");
+        assert_eq!(Shell::Tcsh.to_string(), "tcsh");
+        assert_eq!(Shell::Csh.to_string(), "csh");
         assert_eq!(Shell::PowerShell.to_string(), "powershell");
     }
 
@@ -926,11 +1239,17 @@ mod tests {
     #[case::zsh("zsh", Some(Shell::Zsh))]
     #[case::zsh_versioned("zsh-5.9", Some(Shell::Zsh))]
     #[case::fish("fish", Some(Shell::Fish))]
+    #[case::nu("nu", Some(Shell::Nu))]
+    #[case::nushell("nushell", Some(Shell::Nu))]
+    #[case::elvish("elvish", Some(Shell::Elvish))]
+    #[case::xonsh("xonsh", Some(Shell::Xonsh))]
+    #[case::tcsh("tcsh", Some(Shell::Tcsh))]
+    #[case::csh("csh", Some(Shell::Csh))]
     #[case::powershell("powershell", Some(Shell::PowerShell))]
     #[case::pwsh("pwsh", Some(Shell::PowerShell))]
     #[case::pwsh_preview("pwsh-preview", Some(Shell::PowerShell))]
-    #[case::unknown("tcsh", None)]
-    #[case::unknown_csh("csh", None)]
+    #[case::unknown("ksh", None)]
+    #[case::unknown_dash("dash", None)]
     fn test_shell_from_name(#[case] name: &str, #[case] expected: Option<Shell>) {
         assert_eq!(shell_from_name(name), expected);
     }
@@ -940,12 +1259,26 @@ mod tests {
         insta::assert_snapshot!("config_line_bash", Shell::Bash.config_line("wt"));
         insta::assert_snapshot!("config_line_zsh", Shell::Zsh.config_line("wt"));
         insta::assert_snapshot!("config_line_fish", Shell::Fish.config_line("wt"));
+        insta::assert_snapshot!("config_line_nu", Shell::Nu.config_line("wt"));
+        insta::assert_snapshot!("config_line_elvish", Shell::Elvish.config_line("wt"));
+        insta::assert_snapshot!("config_line_xonsh", Shell::Xonsh.config_line("wt"));
+        insta::assert_snapshot!("config_line_tcsh", Shell::Tcsh.config_line("wt"));
         insta::assert_snapshot!(
             "config_line_powershell",
             Shell::PowerShell.config_line("wt")
         );
     }
 
+    #[test]
+    fn test_supports_completion() {
+        assert!(Shell::Bash.supports_completion());
+        assert!(Shell::Nu.supports_completion());
+        assert!(!Shell::Elvish.supports_completion());
+        assert!(!Shell::Xonsh.supports_completion());
+        assert!(!Shell::Tcsh.supports_completion());
+        assert!(!Shell::Csh.supports_completion());
+    }
+
     #[test]
     fn test_config_line_uses_custom_prefix() {
         // When using a custom prefix, the generated shell config line must use that prefix
@@ -962,7 +1295,17 @@ mod tests {
 
     #[test]
     fn test_shell_init_generate() {
-        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+        for shell in [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::Nu,
+            Shell::Elvish,
+            Shell::Xonsh,
+            Shell::Tcsh,
+            Shell::Csh,
+            Shell::PowerShell,
+        ] {
             let init = ShellInit::with_prefix(shell, "wt".to_string());
             let output = init.generate().expect("Failed to generate");
             insta::assert_snapshot!(format!("init_{shell}"), output);
@@ -972,7 +1315,17 @@ mod tests {
     #[test]
     fn test_shell_config_paths_returns_paths() {
         // All shells should return at least one config path
-        let shells = [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
+        let shells = [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::Nu,
+            Shell::Elvish,
+            Shell::Xonsh,
+            Shell::Tcsh,
+            Shell::Csh,
+            Shell::PowerShell,
+        ];
         for shell in shells {
             let result = shell.config_paths("wt");
             assert!(result.is_ok(), "Failed to get config paths for {:?}", shell);
@@ -988,7 +1341,17 @@ mod tests {
     #[test]
     fn test_shell_completion_path_returns_path() {
         // All shells should return a completion path
-        let shells = [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
+        let shells = [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::Nu,
+            Shell::Elvish,
+            Shell::Xonsh,
+            Shell::Tcsh,
+            Shell::Csh,
+            Shell::PowerShell,
+        ];
         for shell in shells {
             let result = shell.completion_path("wt");
             assert!(
@@ -1020,6 +1383,16 @@ mod tests {
             "Fish config should include prefix in filename"
         );
 
+        // Nushell autoload path should include prefix in filename
+        let nu_paths = Shell::Nu.config_paths(prefix).unwrap();
+        assert!(
+            nu_paths[0]
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.contains("custom-wt.nu")),
+            "Nushell config should include prefix in filename"
+        );
+
         // Bash and Zsh config paths are fixed (not affected by prefix)
         let bash_paths = Shell::Bash.config_paths(prefix).unwrap();
         assert!(
@@ -1029,6 +1402,10 @@ mod tests {
                 .is_some_and(|n| n.contains(".bashrc")),
             "Bash config should be .bashrc"
         );
+        assert!(
+            bash_paths[1].ends_with(".bash_profile"),
+            "Bash should fall back to .bash_profile"
+        );
 
         let zsh_paths = Shell::Zsh.config_paths(prefix).unwrap();
         assert!(
@@ -1089,7 +1466,16 @@ mod tests {
     /// the .exe suffix on Windows (MSYS2/Git Bash handles the resolution).
     #[rstest]
     fn test_config_line_detected_by_is_shell_integration_line(
-        #[values(Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell)] shell: Shell,
+        #[values(
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::Xonsh,
+            Shell::Tcsh,
+            Shell::Csh,
+            Shell::PowerShell
+        )]
+        shell: Shell,
         #[values("wt", "git-wt")] prefix: &str,
     ) {
         let line = shell.config_line(prefix);
@@ -1109,6 +1495,8 @@ mod tests {
     #[case::with_command(r#"eval "$(command wt config shell init bash)""#)]
     #[case::source_process_sub(r#"source <(wt config shell init zsh)"#)]
     #[case::fish_source(r#"wt config shell init fish | source"#)]
+    #[case::xonsh_execx(r#"execx($(wt config shell init xonsh))"#)]
+    #[case::csh_backticks(r#"eval "`wt config shell init tcsh`""#)]
     #[case::with_if_check(
         r#"if command -v wt >/dev/null; then eval "$(wt config shell init bash)"; fi"#
     )]
@@ -1321,44 +1709,42 @@ mod tests {
     }
 
     // ------------------------------------------------------------------------
-    // FALSE NEGATIVE: PowerShell iex alias
+    // PowerShell iex alias
     // ------------------------------------------------------------------------
 
     /// iex is PowerShell's alias for Invoke-Expression
-    #[test]
-    fn test_fn_powershell_iex_alias() {
-        // Common in PowerShell profiles
-        assert_not_detects(
-            "iex (wt config shell init powershell)",
-            "wt",
-            "CONFIRMED FALSE NEGATIVE: PowerShell iex alias",
-        );
+    #[rstest]
+    #[case::iex("iex (wt config shell init powershell)")]
+    #[case::iex_with_ampersand("iex (& wt config shell init powershell)")]
+    #[case::iex_out_string("iex (& wt config shell init powershell | Out-String)")]
+    #[case::invoke_expression_out_string(
+        "Invoke-Expression (& wt config shell init powershell | Out-String)"
+    )]
+    fn test_powershell_profile_patterns(#[case] line: &str) {
+        assert_detects(line, "wt", "PowerShell profile pattern");
     }
 
     #[test]
-    fn test_fn_powershell_iex_with_ampersand() {
+    fn test_powershell_iex_requires_word_boundary() {
+        // "iex" inside another word is not an execution context
         assert_not_detects(
-            "iex (& wt config shell init powershell)",
+            "$complexity = wt config shell init powershell",
             "wt",
-            "CONFIRMED FALSE NEGATIVE: PowerShell iex with &",
+            "iex substring inside identifier",
         );
     }
 
     // ------------------------------------------------------------------------
-    // FALSE NEGATIVE: PowerShell block comments
-    // Note: This is actually a FALSE POSITIVE risk (comments matching)
+    // PowerShell block comments
     // ------------------------------------------------------------------------
 
     #[test]
-    fn test_fn_powershell_block_comment() {
+    fn test_powershell_block_comment() {
         // PowerShell block comments <# #> should NOT match
-        // But current code doesn't skip them
-        let line = "<# Invoke-Expression (wt config shell init powershell) #>";
-        let result = is_shell_integration_line(line, "wt");
-        // This DOES match (false positive) - documenting the behavior
-        assert!(
-            result,
-            "PowerShell block comment currently matches (false positive risk)"
+        assert_not_detects(
+            "<# Invoke-Expression (wt config shell init powershell) #>",
+            "wt",
+            "PowerShell block comment",
         );
     }
 
@@ -1499,7 +1885,7 @@ mod tests {
     }
 
     // ------------------------------------------------------------------------
-    // FALSE NEGATIVE: Nushell (unsupported but users might try)
+    // Nushell: users might try piping into `source` like fish
     // ------------------------------------------------------------------------
 
     #[test]
@@ -18,6 +18,7 @@
 use std::path::PathBuf;
 use std::process::Command;
 use std::sync::OnceLock;
+use std::time::Duration;
 
 use crate::sync::Semaphore;
 
@@ -220,6 +221,18 @@ pub const DIRECTIVE_FILE_ENV_VAR: &str = "WORKTRUNK_DIRECTIVE_FILE";
 /// The `context` parameter is typically the worktree name for git commands, or `None` for
 /// standalone CLI tools like `gh` and `glab`.
 pub fn run(cmd: &mut Command, context: Option<&str>) -> std::io::Result<std::process::Output> {
+    run_with_timeout(cmd, context, None)
+}
+
+/// Execute a command like [`run`], killing it if it runs longer than `timeout`.
+///
+/// A killed command returns an error of kind [`std::io::ErrorKind::TimedOut`].
+/// With `None`, this is exactly [`run`].
+pub fn run_with_timeout(
+    cmd: &mut Command,
+    context: Option<&str>,
+    timeout: Option<Duration>,
+) -> std::io::Result<std::process::Output> {
     use std::time::Instant;
 
     // Remove WORKTRUNK_DIRECTIVE_FILE to prevent hooks from writing to it
@@ -245,7 +258,10 @@ pub fn run(cmd: &mut Command, context: Option<&str>) -> std::io::Result<std::pro
     let _guard = get_semaphore().acquire();
 
     let t0 = Instant::now();
-    let result = cmd.output();
+    let result = match timeout {
+        Some(timeout) => output_with_timeout(cmd, timeout),
+        None => cmd.output(),
+    };
     let duration_ms = t0.elapsed().as_secs_f64() * 1000.0;
 
     // Log trace with timing
@@ -289,6 +305,60 @@ pub fn run(cmd: &mut Command, context: Option<&str>) -> std::io::Result<std::pro
     result
 }
 
+/// Like [`Command::output`], but kills the child once `timeout` elapses.
+///
+/// Output pipes are drained on background threads so a chatty child can't block
+/// on a full pipe while we poll it.
+fn output_with_timeout(
+    cmd: &mut Command,
+    timeout: Duration,
+) -> std::io::Result<std::process::Output> {
+    use std::io::Read;
+    use std::process::Stdio;
+    use std::time::Instant;
+
+    fn drain(pipe: Option<impl Read + Send + 'static>) -> std::thread::JoinHandle<Vec<u8>> {
+        std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut buf);
+            }
+            buf
+        })
+    }
+
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if start.elapsed() > timeout {
+            let _ = child.kill();
+            let _ = child.wait(); // Reap zombie process
+            // The readers are left to finish on their own: a grandchild may still hold the pipes
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!("timed out after {timeout:?}"),
+            ));
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    };
+
+    Ok(std::process::Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
 // ============================================================================
 // Streaming command execution with signal handling
 // ============================================================================
@@ -354,6 +424,8 @@ fn forward_signal_with_escalation(pgid: i32, sig: i32) {
 /// enabling interactive programs (like `claude`, `vim`, or `python -i`) to read user input.
 /// If false and `stdin_content` is None, stdin is set to null (appropriate for non-interactive hooks).
 ///
+/// `env` is added to the child's environment (used for hooks' `WT_*` variables).
+///
 /// Returns error if command exits with non-zero status.
 ///
 /// ## Cross-Platform Shell Execution
@@ -376,6 +448,7 @@ pub fn execute_streaming(
     stdin_content: Option<&str>,
     inherit_stdin: bool,
     forward_signals: bool,
+    env: &[(&str, &str)],
 ) -> anyhow::Result<()> {
     use crate::git::{GitError, WorktrunkError};
     use std::io::Write;
@@ -427,6 +500,7 @@ pub fn execute_streaming(
         .stdin(stdin_mode)
         .stdout(stdout_mode)
         .stderr(std::process::Stdio::inherit()) // Preserve TTY for errors
+        .envs(env.iter().copied())
         // Prevent vergen "overridden" warning in nested cargo builds when run via `cargo run`.
         // Add more VERGEN_* variables here if we expand build.rs and hit similar issues.
         .env_remove("VERGEN_GIT_DESCRIBE")
@@ -524,6 +598,111 @@ pub fn execute_streaming(
     Ok(())
 }
 
+/// Execute a command, passing each line of its stdout and stderr to `on_line`
+///
+/// Used to prefix the output of concurrent commands, or to copy output into a log.
+/// The two streams are read concurrently, so `on_line` may be called from two
+/// threads. Unlike [`execute_streaming`], output is piped (commands won't see a
+/// TTY) and signals are not forwarded. Returns the same `ChildProcessExited`
+/// errors on failure.
+pub fn execute_piped(
+    command: &str,
+    working_dir: &std::path::Path,
+    stdin_content: Option<&str>,
+    env: &[(&str, &str)],
+    on_line: &(dyn Fn(&str) + Sync),
+) -> anyhow::Result<()> {
+    use crate::git::{GitError, WorktrunkError};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::process::Stdio;
+
+    let shell = ShellConfig::get();
+    let mut child = shell
+        .command(command)
+        .current_dir(working_dir)
+        .stdin(if stdin_content.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .envs(env.iter().copied())
+        .env_remove("VERGEN_GIT_DESCRIBE")
+        // Prevent hooks from writing to the directive file
+        .env_remove(DIRECTIVE_FILE_ENV_VAR)
+        .spawn()
+        .map_err(|e| {
+            anyhow::Error::from(GitError::Other {
+                message: format!("Failed to execute command with {}: {}", shell.name, e),
+            })
+        })?;
+
+    // Lines that aren't valid UTF-8 are converted lossily rather than ending the
+    // read, which would close the pipe and kill the child with SIGPIPE
+    fn forward_lines(stream: impl Read, on_line: &(dyn Fn(&str) + Sync)) {
+        let mut reader = BufReader::new(stream);
+        let mut line = Vec::new();
+        while matches!(reader.read_until(b'\n', &mut line), Ok(n) if n > 0) {
+            if line.ends_with(b"\n") {
+                line.pop();
+                if line.ends_with(b"\r") {
+                    line.pop();
+                }
+            }
+            on_line(&String::from_utf8_lossy(&line));
+            line.clear();
+        }
+    }
+    let stdin = child.stdin.take();
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    std::thread::scope(|s| {
+        // Written on its own thread so a child that fills its output pipes before
+        // reading stdin can't deadlock against us.
+        // Ignore write errors: the child may not read stdin (see execute_streaming)
+        if let Some(content) = stdin_content
+            && let Some(mut stdin) = stdin
+        {
+            s.spawn(move || {
+                let _ = stdin.write_all(content.as_bytes());
+            });
+        }
+        if let Some(stdout) = stdout {
+            s.spawn(|| forward_lines(stdout, on_line));
+        }
+        if let Some(stderr) = stderr {
+            s.spawn(|| forward_lines(stderr, on_line));
+        }
+    });
+
+    let status = child.wait().map_err(|e| {
+        anyhow::Error::from(GitError::Other {
+            message: format!("Failed to wait for command: {}", e),
+        })
+    })?;
+
+    #[cfg(unix)]
+    if let Some(sig) = std::os::unix::process::ExitStatusExt::signal(&status) {
+        return Err(WorktrunkError::ChildProcessExited {
+            code: 128 + sig,
+            message: format!("terminated by signal {}", sig),
+        }
+        .into());
+    }
+
+    if !status.success() {
+        let code = status.code().unwrap_or(1);
+        return Err(WorktrunkError::ChildProcessExited {
+            code,
+            message: format!("exit status: {}", code),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -673,6 +852,29 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn test_execute_piped_survives_non_utf8_output() {
+        use std::sync::Mutex;
+
+        let lines = Mutex::new(Vec::new());
+        let dir = std::env::temp_dir();
+        // Enough output after the invalid byte to fill the pipe if reading stopped
+        execute_piped(
+            "printf 'ok\\377\\n'; seq 1 300000",
+            &dir,
+            Some("{}"),
+            &[],
+            &|line| lines.lock().unwrap().push(line.to_string()),
+        )
+        .unwrap();
+
+        let lines = lines.into_inner().unwrap();
+        assert_eq!(lines.len(), 300001);
+        assert_eq!(lines[0], "ok\u{FFFD}");
+        assert_eq!(lines.last().unwrap(), "300000");
+    }
+
     #[test]
     fn test_shell_config_debug() {
         let config = ShellConfig::get();
@@ -2,6 +2,7 @@
 
 # Only initialize if {{ cmd }} is available (in PATH or via WORKTRUNK_BIN)
 if command -v {{ cmd }} >/dev/null 2>&1 || [[ -n "${WORKTRUNK_BIN:-}" ]]; then
+{%- if integration %}
 
     # Override {{ cmd }} command with file-based directive passing.
     # Creates a temp file, passes path via WORKTRUNK_DIRECTIVE_FILE, sources it after.
@@ -33,15 +34,39 @@ if command -v {{ cmd }} >/dev/null 2>&1 || [[ -n "${WORKTRUNK_BIN:-}" ]]; then
         fi
 
         if [[ -s "$directive_file" ]]; then
+            # Export env directives (__WORKTRUNK_ENV__KEY=VALUE, split on the first `=`),
+            # then source the remaining lines. Exec directives (__WORKTRUNK_EXEC__<cmd>)
+            # run last, from the new directory, and don't affect the exit code. An error
+            # directive (__WORKTRUNK_ERR__<message>) means the command failed: apply nothing.
+            local line script="" exec_cmds=()
+            while IFS= read -r line || [[ -n "$line" ]]; do
+                if [[ "$line" == __WORKTRUNK_ERR__* ]]; then
+                    script="" exec_cmds=()
+                    break
+                elif [[ "$line" == __WORKTRUNK_ENV__* ]]; then
+                    line="${line#__WORKTRUNK_ENV__}"
+                    export "${line%%=*}=${line#*=}"
+                elif [[ "$line" == __WORKTRUNK_EXEC__* ]]; then
+                    exec_cmds+=("${line#__WORKTRUNK_EXEC__}")
+                else
+                    script+="$line"$'\n'
+                fi
+            done < "$directive_file"
+            printf '%s' "$script" > "$directive_file"
             source "$directive_file"
             if [[ $exit_code -eq 0 ]]; then
                 exit_code=$?
             fi
+            for line in "${exec_cmds[@]}"; do
+                eval "$line"
+            done
         fi
 
         rm -f "$directive_file"
         return "$exit_code"
     }
+{%- endif %}
+{%- if completions %}
 
     # Lazy completions - generate on first TAB, then delegate to clap's completer
     _{{ cmd }}_lazy_complete() {
@@ -50,10 +75,11 @@ if command -v {{ cmd }} >/dev/null 2>&1 || [[ -n "${WORKTRUNK_BIN:-}" ]]; then
             # Use `command` to bypass the shell function and call the binary directly.
             # Without this, `{{ cmd }}` would call the shell function which evals
             # the completion script internally but doesn't re-emit it.
-            eval "$(COMPLETE=bash command "${WORKTRUNK_BIN:-{{ cmd }}}" 2>/dev/null)" || return
+            eval "$(COMPLETE=bash WORKTRUNK_COMPLETE_CMD={{ cmd }} command "${WORKTRUNK_BIN:-{{ cmd }}}" 2>/dev/null)" || return
         fi
         _clap_complete_{{ cmd }} "$@"
     }
 
     complete -o nospace -o bashdefault -F _{{ cmd }}_lazy_complete {{ cmd }}
+{%- endif %}
 fi
@@ -7,9 +7,9 @@ if type -q {{ cmd }}; or test -n "$WORKTRUNK_BIN"
     # Creates a temp file, passes path via WORKTRUNK_DIRECTIVE_FILE, evals it after.
     # WORKTRUNK_BIN can override the binary path (for testing dev builds).
     #
-    # Note: We use `eval (cat ... | string collect)` instead of `source` because:
+    # Note: We use `eval (... | string collect)` instead of `source` because:
     # 1. fish's `source` doesn't propagate `exit` to the parent function
-    # 2. `eval (cat ...)` without `string collect` splits on newlines, breaking multiline directives
+    # 2. `eval (...)` without `string collect` splits on newlines, breaking multiline directives
     # With `string collect`, `exit 42` properly exits the function with code 42.
     function {{ cmd }}
         set -l use_source false
@@ -31,10 +31,33 @@ if type -q {{ cmd }}; or test -n "$WORKTRUNK_BIN"
         set -l exit_code $status
 
         if test -s "$directive_file"
-            eval (cat "$directive_file" | string collect)
+            # Export env directives (__WORKTRUNK_ENV__KEY=VALUE, split on the first `=`),
+            # then eval the remaining lines. Exec directives (__WORKTRUNK_EXEC__<cmd>)
+            # run last, from the new directory, and don't affect the exit code. An error
+            # directive (__WORKTRUNK_ERR__<message>) means the command failed: apply nothing.
+            set -l script
+            set -l exec_cmds
+            for line in (cat "$directive_file")
+                if string match -q '__WORKTRUNK_ERR__*' -- $line
+                    set script
+                    set exec_cmds
+                    break
+                else if string match -q '__WORKTRUNK_ENV__*' -- $line
+                    set -l kv (string split -m 1 = -- (string sub -s 18 -- $line))
+                    set -gx $kv[1] "$kv[2]"
+                else if string match -q '__WORKTRUNK_EXEC__*' -- $line
+                    set -a exec_cmds (string sub -s 19 -- $line)
+                else
+                    set -a script $line
+                end
+            end
+            eval (string join \n -- $script | string collect)
             if test $exit_code -eq 0
                 set exit_code $status
             end
+            for cmd in $exec_cmds
+                eval $cmd
+            end
         end
 
         rm -f "$directive_file"
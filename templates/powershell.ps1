@@ -7,6 +7,7 @@
 
 # Only initialize if wt is available
 if (Get-Command {{ cmd }} -ErrorAction SilentlyContinue) {
+{%- if integration %}
 
     # wt wrapper function - uses temp file for directives
     function {{ cmd }} {
@@ -32,10 +33,25 @@ if (Get-Command {{ cmd }} -ErrorAction SilentlyContinue) {
             Remove-Item Env:\WORKTRUNK_SHELL -ErrorAction SilentlyContinue
         }
 
-        # Execute the directive script if it has content
+        # Execute the directive script if it has content and wt didn't report an
+        # error directive (__WORKTRUNK_ERR__<message>), which means apply nothing
         try {
-            if ((Test-Path $directiveFile) -and (Get-Item $directiveFile).Length -gt 0) {
-                $script = Get-Content -Path $directiveFile -Raw
+            $hasDirectives = (Test-Path $directiveFile) -and (Get-Item $directiveFile).Length -gt 0
+            if ($hasDirectives -and -not (Select-String -Path $directiveFile -Pattern '^__WORKTRUNK_ERR__' -Quiet)) {
+                # Export env directives (__WORKTRUNK_ENV__KEY=VALUE, split on the first `=`),
+                # then run the remaining lines. Exec directives (__WORKTRUNK_EXEC__<cmd>)
+                # run last, from the new directory, and don't affect the exit code.
+                $execCommands = @()
+                $script = (Get-Content -Path $directiveFile | ForEach-Object {
+                    if ($_.StartsWith('__WORKTRUNK_ENV__')) {
+                        $key, $value = $_.Substring(17).Split('=', 2)
+                        [Environment]::SetEnvironmentVariable($key, $value)
+                    } elseif ($_.StartsWith('__WORKTRUNK_EXEC__')) {
+                        $execCommands += $_.Substring(18)
+                    } else {
+                        $_
+                    }
+                }) -join "`n"
                 if ($script.Trim()) {
                     Invoke-Expression $script
                     # If wt succeeded, use the directive script's exit code
@@ -43,6 +59,9 @@ if (Get-Command {{ cmd }} -ErrorAction SilentlyContinue) {
                         $exitCode = $LASTEXITCODE
                     }
                 }
+                foreach ($execCommand in $execCommands) {
+                    Invoke-Expression $execCommand
+                }
             }
         }
         finally {
@@ -58,14 +77,19 @@ if (Get-Command {{ cmd }} -ErrorAction SilentlyContinue) {
         }
         return $exitCode
     }
+{%- endif %}
+{%- if completions %}
 
     # Tab completion - generate clap's completer script and eval it
     # This registers Register-ArgumentCompleter with proper handling
     $env:COMPLETE = "powershell"
+    $env:WORKTRUNK_COMPLETE_CMD = "{{ cmd }}"
     try {
         & (Get-Command {{ cmd }} -CommandType Application) | Out-String | Invoke-Expression
     }
     finally {
         Remove-Item Env:\COMPLETE -ErrorAction SilentlyContinue
+        Remove-Item Env:\WORKTRUNK_COMPLETE_CMD -ErrorAction SilentlyContinue
     }
+{%- endif %}
 }
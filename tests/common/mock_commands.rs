@@ -161,6 +161,28 @@ Includes comprehensive test coverage and input validation."#,
     );
 }
 
+/// Create a mock gpg that records its arguments to `log` and emits a fake signature.
+///
+/// Point git at it with `gpg.program`. When `succeed` is false, it fails the way
+/// gpg does without a usable key.
+pub fn create_mock_gpg(bin_dir: &Path, log: &Path, succeed: bool) {
+    let body = if succeed {
+        r#"echo '[GNUPG:] BEGIN_SIGNING' >&2
+echo '[GNUPG:] SIG_CREATED D 1 8 00 1735776000 FAKE' >&2
+echo '-----BEGIN PGP SIGNATURE-----'
+echo 'fake'
+echo '-----END PGP SIGNATURE-----'"#
+    } else {
+        r#"echo 'gpg: signing failed: No secret key' >&2
+exit 2"#
+    };
+    let script = format!(
+        "#!/bin/sh\necho \"$@\" >> '{}'\ncat > /dev/null\n{body}\n",
+        escape_shell_string(&log.to_string_lossy())
+    );
+    write_mock_script(bin_dir, "gpg", &script);
+}
+
 /// Create a mock uv command for dependency sync and dev server.
 ///
 /// Handles: `uv sync` (1 arg) and `uv run dev` (2 args).
@@ -2397,6 +2397,25 @@ pub fn wait_for_file(path: &Path) {
     );
 }
 
+/// Wait for a path to disappear, polling with exponential backoff.
+/// Use this for background removals instead of fixed sleeps.
+pub fn wait_for_path_removed(path: &Path) {
+    let start = std::time::Instant::now();
+    let mut attempt = 0;
+    while start.elapsed() < BG_TIMEOUT {
+        if !path.exists() {
+            return;
+        }
+        exponential_sleep(attempt);
+        attempt += 1;
+    }
+    panic!(
+        "Path was not removed within {:?}: {}",
+        BG_TIMEOUT,
+        path.display()
+    );
+}
+
 /// Wait for a directory to contain at least `expected_count` files with a given extension.
 pub fn wait_for_file_count(dir: &Path, extension: &str, expected_count: usize) {
     let start = std::time::Instant::now();
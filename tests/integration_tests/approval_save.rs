@@ -631,6 +631,47 @@ fn test_skip_shell_integration_prompt_idempotent() {
     assert_eq!(count, 1, "Flag should appear exactly once");
 }
 
+#[test]
+fn test_set_shell_cmd_preserves_existing_content() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(
+        &config_path,
+        "# My config\nworktree-path = \"../{{ branch }}\"\n",
+    )
+    .unwrap();
+
+    let mut config = WorktrunkConfig::default();
+    config
+        .set_shell_cmd("zsh", Some("w"), Some(&config_path))
+        .unwrap();
+    config
+        .set_shell_cmd("fish", Some("git-wt"), Some(&config_path))
+        .unwrap();
+
+    let toml_content = fs::read_to_string(&config_path).unwrap();
+    assert_snapshot!(toml_content, @r#"
+    # My config
+    worktree-path = "../{{ branch }}"
+
+    [shell-cmd]
+    zsh = "w"
+    fish = "git-wt"
+
+    [projects]
+    "#);
+
+    // Removing the last entry drops the table
+    config
+        .set_shell_cmd("zsh", None, Some(&config_path))
+        .unwrap();
+    config
+        .set_shell_cmd("fish", None, Some(&config_path))
+        .unwrap();
+    let toml_content = fs::read_to_string(&config_path).unwrap();
+    assert!(!toml_content.contains("shell-cmd"), "{toml_content}");
+}
+
 ///
 /// When the config file is a symlink (e.g., user has config.toml -> dotfiles/worktrunk.toml),
 /// saving should write to the target file without destroying the symlink.
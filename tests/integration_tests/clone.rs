@@ -0,0 +1,116 @@
+use crate::common::{TestRepo, configure_directive_file, directive_file, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+use std::path::PathBuf;
+
+/// Sibling of the test repo to clone into, so paths render as `_REPO_.clone`
+fn clone_target(repo: &TestRepo) -> PathBuf {
+    repo.root_path().with_file_name("repo.clone")
+}
+
+#[rstest]
+fn test_clone_runs_post_create(repo: TestRepo) {
+    repo.write_project_config(r#"post-create = "echo created > marker.txt""#);
+    repo.commit("Add config");
+
+    let source = repo.root_path().to_str().unwrap();
+    let parent = repo.root_path().parent().unwrap();
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "clone",
+        &[source, "repo.clone", "--yes"],
+        Some(parent)
+    ));
+
+    let target = clone_target(&repo);
+    assert!(target.join("marker.txt").exists(), "post-create should run");
+    let remote = repo.git_output(&[
+        "-C",
+        target.to_str().unwrap(),
+        "remote",
+        "get-url",
+        "origin",
+    ]);
+    assert_eq!(remote.trim(), source);
+}
+
+#[rstest]
+fn test_clone_no_verify_skips_post_create(repo: TestRepo) {
+    repo.write_project_config(r#"post-create = "echo created > marker.txt""#);
+    repo.commit("Add config");
+
+    let output = repo
+        .wt_command()
+        .args(["clone", repo.root_path().to_str().unwrap(), "repo.clone"])
+        .arg("--no-verify")
+        .current_dir(repo.root_path().parent().unwrap())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let target = clone_target(&repo);
+    assert!(target.join(".config/wt.toml").exists());
+    assert!(
+        !target.join("marker.txt").exists(),
+        "post-create should be skipped"
+    );
+}
+
+#[rstest]
+fn test_clone_bare_layout(repo: TestRepo) {
+    let (directive_path, _guard) = directive_file();
+    let mut cmd = repo.wt_command();
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args([
+            "clone",
+            "--bare",
+            repo.root_path().to_str().unwrap(),
+            "repo.clone",
+        ])
+        .current_dir(repo.root_path().parent().unwrap())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let target = clone_target(&repo);
+    let worktree = target.join("main");
+    let is_bare = repo.git_output(&[
+        "-C",
+        target.join(".git").to_str().unwrap(),
+        "rev-parse",
+        "--is-bare-repository",
+    ]);
+    assert_eq!(is_bare.trim(), "true");
+    let upstream = repo.git_output(&[
+        "-C",
+        worktree.to_str().unwrap(),
+        "rev-parse",
+        "--abbrev-ref",
+        "main@{upstream}",
+    ]);
+    assert_eq!(upstream.trim(), "origin/main");
+
+    let directives = fs::read_to_string(&directive_path).unwrap();
+    assert!(
+        directives.contains(&format!("cd '{}'", worktree.display())),
+        "Should cd into the default branch worktree: {directives}"
+    );
+}
+
+#[rstest]
+fn test_clone_into_non_empty_dir_fails(repo: TestRepo) {
+    let target = clone_target(&repo);
+    fs::create_dir_all(&target).unwrap();
+    fs::write(target.join("existing.txt"), "content").unwrap();
+
+    let source = repo.root_path().to_str().unwrap();
+    let parent = repo.root_path().parent().unwrap();
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "clone",
+        &[source, "repo.clone"],
+        Some(parent)
+    ));
+}
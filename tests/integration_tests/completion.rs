@@ -588,6 +588,7 @@ fn test_complete_step_subcommands(repo: TestRepo) {
     let subcommands = value_suggestions(&stdout);
     // Git operations
     assert!(subcommands.contains(&"commit"), "Missing commit");
+    assert!(subcommands.contains(&"amend"), "Missing amend");
     assert!(subcommands.contains(&"squash"), "Missing squash");
     assert!(subcommands.contains(&"push"), "Missing push");
     assert!(subcommands.contains(&"rebase"), "Missing rebase");
@@ -598,8 +599,8 @@ fn test_complete_step_subcommands(repo: TestRepo) {
     assert!(subcommands.contains(&"for-each"), "Missing for-each");
     assert_eq!(
         subcommands.len(),
-        6,
-        "Should have exactly 6 step subcommands"
+        7,
+        "Should have exactly 7 step subcommands"
     );
 }
 
@@ -614,6 +615,7 @@ fn test_complete_hook_subcommands(repo: TestRepo) {
     let subcommands = value_suggestions(&stdout);
     // Hook types and commands
     assert!(subcommands.contains(&"show"), "Missing show");
+    assert!(subcommands.contains(&"pre-create"), "Missing pre-create");
     assert!(subcommands.contains(&"post-create"), "Missing post-create");
     assert!(subcommands.contains(&"post-start"), "Missing post-start");
     assert!(subcommands.contains(&"post-switch"), "Missing post-switch");
@@ -624,8 +626,8 @@ fn test_complete_hook_subcommands(repo: TestRepo) {
     assert!(subcommands.contains(&"approvals"), "Missing approvals");
     assert_eq!(
         subcommands.len(),
-        9,
-        "Should have exactly 9 hook subcommands"
+        10,
+        "Should have exactly 10 hook subcommands"
     );
 
     // Test 2: Partial input "po" - filters to post-* subcommands
@@ -656,7 +658,10 @@ fn test_complete_init_shell_all_variations(repo: TestRepo) {
     assert!(shells.contains(&"bash"));
     assert!(shells.contains(&"fish"));
     assert!(shells.contains(&"zsh"));
-    assert!(!shells.contains(&"elvish"));
+    assert!(shells.contains(&"elvish"));
+    assert!(shells.contains(&"xonsh"));
+    assert!(shells.contains(&"tcsh"));
+    assert!(shells.contains(&"csh"));
     assert!(!shells.contains(&"nushell"));
 
     // Test 2: Partial input "fi" - filters to fish
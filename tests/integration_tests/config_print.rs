@@ -0,0 +1,141 @@
+use crate::common::{TestRepo, repo, wt_command};
+use insta::assert_snapshot;
+use rstest::rstest;
+
+fn config_print(repo: &TestRepo, args: &[&str]) -> String {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "print"])
+        .args(args)
+        .current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[rstest]
+fn test_config_print_merges_sources(repo: TestRepo) {
+    repo.write_test_config(
+        r#"pre-merge = "cargo test"
+
+[commit-generation]
+command = "llm"
+args = ["-m", "haiku"]
+
+[list]
+full = true
+"#,
+    );
+    repo.write_project_config(
+        r#"post-create = "npm ci"
+pre-merge = "npm test"
+
+[list]
+url = "http://localhost:{{ branch | hash_port }}"
+"#,
+    );
+
+    assert_snapshot!(config_print(&repo, &[]), @r#"
+    [worktree-path]
+    default = "../{{ repo }}.{{ branch | sanitize }}"
+
+    [commit-generation.global]
+    command = "llm"
+    args = [
+        "-m",
+        "haiku",
+    ]
+
+    [list.global]
+    full = true
+
+    [list.project]
+    url = "http://localhost:{{ branch | hash_port }}"
+
+    [hooks.pre-create]
+
+    [hooks.post-create]
+    project = "npm ci"
+
+    [hooks.post-start]
+
+    [hooks.post-switch]
+
+    [hooks.pre-commit]
+
+    [hooks.pre-merge]
+    global = "cargo test"
+    project = "npm test"
+
+    [hooks.post-merge]
+
+    [hooks.pre-remove]
+    "#);
+}
+
+#[rstest]
+fn test_config_print_json(repo: TestRepo) {
+    repo.write_test_config(r#"worktree-path = ".worktrees/{{ branch | sanitize }}""#);
+    repo.write_project_config(
+        r#"[post-start]
+server = "npm run dev"
+"#,
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_str(&config_print(&repo, &["--format", "json"])).unwrap();
+    assert_eq!(
+        json["worktree-path"],
+        serde_json::json!({"global": ".worktrees/{{ branch | sanitize }}"})
+    );
+    assert_eq!(
+        json["hooks"]["post-start"],
+        serde_json::json!({"project": {"server": "npm run dev"}})
+    );
+    // Unset hooks are listed with no sources
+    assert_eq!(json["hooks"]["pre-remove"], serde_json::json!({}));
+    assert!(json.get("commit-generation").is_none());
+}
+
+#[rstest]
+fn test_config_env_var_overrides_config_path(repo: TestRepo) {
+    // TestRepo points WORKTRUNK_CONFIG_PATH at its own config; WORKTRUNK_CONFIG wins
+    repo.write_test_config(r#"worktree-path = "from-config-path""#);
+    let config_file = repo.root_path().join("custom-config.toml");
+    std::fs::write(&config_file, r#"worktree-path = "from-env-var""#).unwrap();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .args(["config", "print", "--format", "json"])
+        .env("WORKTRUNK_CONFIG", &config_file)
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        json["worktree-path"],
+        serde_json::json!({"global": "from-env-var"})
+    );
+}
+
+#[rstest]
+fn test_config_env_var_missing_file(repo: TestRepo) {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .args(["config", "print"])
+        .env("WORKTRUNK_CONFIG", repo.root_path().join("missing.toml"))
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("WORKTRUNK_CONFIG points to a missing file"),
+        "{stderr}"
+    );
+}
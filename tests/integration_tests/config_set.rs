@@ -0,0 +1,115 @@
+use crate::common::{TestRepo, repo, wt_command};
+use insta::assert_snapshot;
+use rstest::rstest;
+use std::process::Output;
+
+fn config(repo: &TestRepo, args: &[&str]) -> Output {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.arg("config")
+        .args(args)
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap()
+}
+
+fn config_get(repo: &TestRepo, key: &str) -> String {
+    let output = config(repo, &["get", key]);
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn config_set(repo: &TestRepo, key: &str, value: &str) {
+    let output = config(repo, &["set", key, value]);
+    assert!(output.status.success(), "{output:?}");
+}
+
+#[rstest]
+fn test_config_set_round_trips(repo: TestRepo) {
+    repo.write_test_config(
+        r#"# Keep this comment
+worktree-path = "../{{ branch }}"
+
+[list]
+full = false
+"#,
+    );
+
+    config_set(&repo, "commit_generation.command", "llm -m haiku");
+    config_set(&repo, "commit-generation.args", r#"["-s"]"#);
+    config_set(&repo, "list.full", "true");
+
+    assert_snapshot!(
+        std::fs::read_to_string(repo.test_config_path()).unwrap(),
+        @r#"
+    # Keep this comment
+    worktree-path = "../{{ branch }}"
+
+    [list]
+    full = true
+
+    [commit-generation]
+    command = "llm -m haiku"
+    args = ["-s"]
+    "#
+    );
+
+    // A fresh process reloads the written values
+    assert_eq!(
+        config_get(&repo, "commit-generation.command"),
+        "llm -m haiku\n"
+    );
+    assert_eq!(config_get(&repo, "commit_generation.args"), "[\"-s\"]\n");
+    assert_eq!(config_get(&repo, "list.full"), "true\n");
+
+    let output = config(&repo, &["print", "--format", "json"]);
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        json["commit-generation"]["global"],
+        serde_json::json!({"command": "llm -m haiku", "args": ["-s"]})
+    );
+    assert_eq!(json["list"]["global"], serde_json::json!({"full": true}));
+}
+
+#[rstest]
+fn test_config_set_creates_file(repo: TestRepo) {
+    let _ = std::fs::remove_file(repo.test_config_path());
+
+    let output = config(&repo, &["set", "worktree-path", ".worktrees/{{ branch }}"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @r#"[32m✓[39m [32mSet [1mworktree-path[22m to [1m".worktrees/{{ branch }}"[22m[39m"#);
+
+    assert_eq!(
+        std::fs::read_to_string(repo.test_config_path()).unwrap(),
+        "worktree-path = \".worktrees/{{ branch }}\"\n"
+    );
+}
+
+#[rstest]
+fn test_config_set_invalid_value(repo: TestRepo) {
+    repo.write_test_config("[list]\nfull = false\n");
+
+    let output = config(&repo, &["set", "list.full", "maybe"]);
+    assert!(!output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @r#"[31m✗[39m [31mInvalid value for list.full: invalid type: string "maybe", expected a boolean[39m"#);
+
+    let output = config(&repo, &["set", "bogus", "1"]);
+    assert!(!output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[31m✗[39m [31mUnknown config key: bogus[39m");
+
+    // The file is untouched
+    assert_eq!(
+        std::fs::read_to_string(repo.test_config_path()).unwrap(),
+        "[list]\nfull = false\n"
+    );
+}
+
+#[rstest]
+fn test_config_get_unset(repo: TestRepo) {
+    repo.write_test_config("[list]\nfull = true\n");
+
+    let output = config(&repo, &["get", "commit-generation.command"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[2m○[22m [1mcommit-generation.command[22m is unset");
+}
@@ -129,6 +129,41 @@ fn test_configure_shell_already_exists(repo: TestRepo, temp_home: TempDir) {
     assert_eq!(count, 1, "Should only have one wt config shell init line");
 }
 
+/// PowerShell profiles often contain a hand-written integration line rather than
+/// the exact line we write; re-running install must not append a duplicate.
+#[rstest]
+#[cfg(not(windows))]
+fn test_configure_shell_powershell_hand_written_line(repo: TestRepo, temp_home: TempDir) {
+    let profile_dir = temp_home.path().join(".config").join("powershell");
+    fs::create_dir_all(&profile_dir).unwrap();
+    let profile_path = profile_dir.join("Microsoft.PowerShell_profile.ps1");
+    fs::write(
+        &profile_path,
+        "# Existing profile\nInvoke-Expression (& wt config shell init powershell | Out-String)\n",
+    )
+    .unwrap();
+
+    // Not a snapshot: the home snapshot settings filter out powershell lines
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    set_temp_home_env(&mut cmd, temp_home.path());
+    let output = cmd
+        .args(["config", "shell", "install", "powershell", "--yes"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Already configured shell extension for"),
+        "Expected already-configured message, got:\n{stderr}"
+    );
+
+    let content = fs::read_to_string(&profile_path).unwrap();
+    let count = content.matches("wt config shell init").count();
+    assert_eq!(count, 1, "Should only have one wt config shell init line");
+}
+
 #[rstest]
 fn test_configure_shell_fish(repo: TestRepo, temp_home: TempDir) {
     let settings = setup_home_snapshot_settings(&temp_home);
@@ -170,6 +205,46 @@ fn test_configure_shell_fish(repo: TestRepo, temp_home: TempDir) {
     );
 }
 
+/// Fish files follow XDG_CONFIG_HOME when it points somewhere other than ~/.config
+#[rstest]
+fn test_configure_shell_fish_xdg_config_home(repo: TestRepo, temp_home: TempDir) {
+    let xdg_config = temp_home.path().join(".xdg-config");
+
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("XDG_CONFIG_HOME", &xdg_config);
+        // Windows: etcetera reads APPDATA instead
+        cmd.env("APPDATA", &xdg_config);
+        cmd.env("SHELL", "/bin/fish");
+        cmd.arg("config")
+            .arg("shell")
+            .arg("install")
+            .arg("fish")
+            .arg("--yes")
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [32m✓[39m [32mCreated shell extension for [1mfish[22m @ [1m~/.xdg-config/fish/conf.d/wt.fish[22m[39m
+        [32m✓[39m [32mCreated completions for [1mfish[22m @ [1m~/.xdg-config/fish/completions/wt.fish[22m[39m
+
+        [32m✓[39m [32mConfigured 1 shell[39m
+        [2m↳[22m [2mRestart shell to activate shell integration[22m
+        ");
+    });
+
+    assert!(xdg_config.join("fish/conf.d/wt.fish").exists());
+    assert!(xdg_config.join("fish/completions/wt.fish").exists());
+    assert!(!temp_home.path().join(".config/fish").exists());
+}
+
 /// Fish completions are now inline in the init script, so no separate file is needed
 #[rstest]
 fn test_configure_shell_fish_extension_exists(repo: TestRepo, temp_home: TempDir) {
@@ -280,20 +355,63 @@ fn test_configure_shell_no_files(repo: TestRepo, temp_home: TempDir) {
         ----- stdout -----
 
         ----- stderr -----
-        [2m↳[22m [2mSkipped [90mbash[39m; [90m~/.bashrc[39m not found[22m
         [2m↳[22m [2mSkipped [90mzsh[39m; [90m~/.zshrc[39m not found[22m
+        [2m↳[22m [2mSkipped [90mbash[39m; [90m~/.bashrc[39m not found[22m
         [2m↳[22m [2mSkipped [90mfish[39m; [90m~/.config/fish/conf.d[39m not found[22m
         [31m✗[39m [31mNo shell config files found[39m
         ");
     });
 }
 
+#[rstest]
+fn test_configure_shell_lists_detected_shell_first(repo: TestRepo, temp_home: TempDir) {
+    fs::write(temp_home.path().join(".bashrc"), "# bash\n").unwrap();
+    fs::write(temp_home.path().join(".zshrc"), "# zsh\n").unwrap();
+    fs::create_dir_all(temp_home.path().join(".config/fish/conf.d")).unwrap();
+
+    let install_order = |shell_env: &str| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", shell_env);
+        let output = cmd
+            .args(["config", "shell", "install", "--dry-run"])
+            .current_dir(repo.root_path())
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        let mut order: Vec<_> = ["bash", "zsh", "fish"]
+            .into_iter()
+            .map(|shell| {
+                (
+                    stderr.find(&format!("for \u{1b}[1m{shell}")).unwrap(),
+                    shell,
+                )
+            })
+            .collect();
+        order.sort();
+        order
+            .into_iter()
+            .map(|(_, shell)| shell)
+            .collect::<Vec<_>>()
+    };
+
+    // The shell from $SHELL leads; the rest keep their usual order
+    assert_eq!(install_order("/usr/bin/fish"), ["fish", "bash", "zsh"]);
+    assert_eq!(install_order("/bin/zsh"), ["zsh", "bash", "fish"]);
+    // An unsupported shell detects as nothing, leaving the default order
+    assert_eq!(install_order("/usr/bin/unknownsh"), ["bash", "zsh", "fish"]);
+}
+
 #[rstest]
 fn test_configure_shell_multiple_configs(repo: TestRepo, temp_home: TempDir) {
     // Create multiple shell config files
     let bash_config_path = temp_home.path().join(".bashrc");
+    let bash_profile_path = temp_home.path().join(".bash_profile");
     let zshrc_path = temp_home.path().join(".zshrc");
     fs::write(&bash_config_path, "# Existing bash config\n").unwrap();
+    fs::write(&bash_profile_path, "# Existing bash profile\n").unwrap();
     fs::write(&zshrc_path, "# Existing zsh config\n").unwrap();
 
     let settings = setup_home_snapshot_settings(&temp_home);
@@ -316,8 +434,8 @@ fn test_configure_shell_multiple_configs(repo: TestRepo, temp_home: TempDir) {
         ----- stdout -----
 
         ----- stderr -----
-        [32m✓[39m [32mAdded shell extension & completions for [1mbash[22m @ [1m~/.bashrc[22m[39m
         [32m✓[39m [32mAdded shell extension & completions for [1mzsh[22m @ [1m~/.zshrc[22m[39m
+        [32m✓[39m [32mAdded shell extension & completions for [1mbash[22m @ [1m~/.bashrc[22m[39m
         [2m↳[22m [2mSkipped [90mfish[39m; [90m~/.config/fish/conf.d[39m not found[22m
 
         [32m✓[39m [32mConfigured 2 shells[39m
@@ -333,6 +451,11 @@ fn test_configure_shell_multiple_configs(repo: TestRepo, temp_home: TempDir) {
         bash_content.contains("eval \"$(command wt config shell init bash)\""),
         "Bash config should be updated"
     );
+    assert_eq!(
+        fs::read_to_string(&bash_profile_path).unwrap(),
+        "# Existing bash profile\n",
+        ".bash_profile should be left alone when .bashrc exists"
+    );
 
     let zsh_content = fs::read_to_string(&zshrc_path).unwrap();
     assert!(
@@ -375,8 +498,8 @@ fn test_configure_shell_mixed_states(repo: TestRepo, temp_home: TempDir) {
         ----- stdout -----
 
         ----- stderr -----
-        [2m○[22m Already configured shell extension & completions for [1mbash[22m @ [1m~/.bashrc[22m
         [32m✓[39m [32mAdded shell extension & completions for [1mzsh[22m @ [1m~/.zshrc[22m[39m
+        [2m○[22m Already configured shell extension & completions for [1mbash[22m @ [1m~/.bashrc[22m
         [2m↳[22m [2mSkipped [90mfish[39m; [90m~/.config/fish/conf.d[39m not found[22m
 
         [32m✓[39m [32mConfigured 1 shell[39m
@@ -402,6 +525,345 @@ fn test_configure_shell_mixed_states(repo: TestRepo, temp_home: TempDir) {
     );
 }
 
+#[rstest]
+fn test_configure_shell_bash_profile_fallback(repo: TestRepo, temp_home: TempDir) {
+    // Only a login profile exists
+    let bash_profile_path = temp_home.path().join(".bash_profile");
+    fs::write(&bash_profile_path, "# Existing bash profile\n").unwrap();
+
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/bash");
+        cmd.args(["config", "shell", "install", "bash", "--yes"])
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [32m✓[39m [32mAdded shell extension & completions for [1mbash[22m @ [1m~/.bash_profile[22m[39m
+
+        [32m✓[39m [32mConfigured 1 shell[39m
+        [2m↳[22m [2mRestart shell to activate shell integration[22m
+        ");
+    });
+
+    let content = fs::read_to_string(&bash_profile_path).unwrap();
+    assert!(
+        content.contains("eval \"$(command wt config shell init bash)\""),
+        ".bash_profile should be updated"
+    );
+    assert!(!temp_home.path().join(".bashrc").exists());
+}
+
+#[rstest]
+#[cfg(not(windows))]
+fn test_configure_shell_json_format(repo: TestRepo, temp_home: TempDir) {
+    let bashrc_path = temp_home.path().join(".bashrc");
+    fs::write(&bashrc_path, "# Existing bash config\n").unwrap();
+    let zshrc_path = temp_home.path().join(".zshrc");
+    fs::write(
+        &zshrc_path,
+        "if command -v wt >/dev/null 2>&1; then eval \"$(command wt config shell init zsh)\"; fi\n",
+    )
+    .unwrap();
+
+    let run = |args: &[&str]| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/bash");
+        cmd.args(["config", "shell", "install", "--format=json"])
+            .args(args)
+            .current_dir(repo.root_path());
+        let output = cmd.output().unwrap();
+        assert!(output.status.success(), "{output:?}");
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        report
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| {
+                let path = entry["config_path"].as_str().unwrap();
+                let path = std::path::Path::new(path)
+                    .strip_prefix(temp_home.path())
+                    .unwrap()
+                    .display()
+                    .to_string();
+                (
+                    entry["shell"].as_str().unwrap().to_string(),
+                    path,
+                    entry["action"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let entry = |shell: &str, path: &str, action: &str| {
+        (shell.to_string(), path.to_string(), action.to_string())
+    };
+
+    // --dry-run reports what would happen without writing
+    assert_eq!(
+        run(&["--dry-run"]),
+        [
+            entry("bash", ".bashrc", "would_modify"),
+            entry("zsh", ".zshrc", "already_configured"),
+            entry("fish", ".config/fish/conf.d", "skipped"),
+        ]
+    );
+    assert_eq!(
+        fs::read_to_string(&bashrc_path).unwrap(),
+        "# Existing bash config\n"
+    );
+
+    assert_eq!(
+        run(&["--yes"]),
+        [
+            entry("bash", ".bashrc", "modified"),
+            entry("zsh", ".zshrc", "already_configured"),
+            entry("fish", ".config/fish/conf.d", "skipped"),
+        ]
+    );
+    assert!(
+        fs::read_to_string(&bashrc_path)
+            .unwrap()
+            .contains("wt config shell init bash")
+    );
+}
+
+#[rstest]
+fn test_configure_shell_elvish(repo: TestRepo, temp_home: TempDir) {
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/usr/bin/elvish");
+        cmd.args(["config", "shell", "install", "elvish", "--yes"])
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [32m✓[39m [32mCreated shell extension for [1melvish[22m @ [1m~/.config/elvish/rc.elv[22m[39m
+        [2m↳[22m [2mCompletions not yet supported for [1melvish[22m[22m
+
+        [32m✓[39m [32mConfigured 1 shell[39m
+        [2m↳[22m [2mRestart shell to activate shell integration[22m
+        ");
+    });
+
+    // Created along with its directory, since elvish was targeted explicitly
+    let content = fs::read_to_string(temp_home.path().join(".config/elvish/rc.elv")).unwrap();
+    assert_eq!(
+        content,
+        "if (has-external wt) { eval (e:wt config shell init elvish | slurp) }\n"
+    );
+}
+
+#[rstest]
+fn test_configure_shell_xonsh(repo: TestRepo, temp_home: TempDir) {
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/usr/bin/xonsh");
+        cmd.args(["config", "shell", "install", "xonsh", "--yes"])
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [32m✓[39m [32mCreated shell extension for [1mxonsh[22m @ [1m~/.config/xonsh/rc.xsh[22m[39m
+        [2m↳[22m [2mCompletions not yet supported for [1mxonsh[22m[22m
+
+        [32m✓[39m [32mConfigured 1 shell[39m
+        [2m↳[22m [2mRestart shell to activate shell integration[22m
+        ");
+    });
+
+    // Created along with its directory, since xonsh was targeted explicitly
+    let content = fs::read_to_string(temp_home.path().join(".config/xonsh/rc.xsh")).unwrap();
+    assert_eq!(
+        content,
+        "if __import__(\"shutil\").which(\"wt\"): execx($(wt config shell init xonsh))\n"
+    );
+}
+
+#[rstest]
+fn test_configure_shell_tcsh_uses_existing_cshrc(repo: TestRepo, temp_home: TempDir) {
+    // Tcsh only reads .cshrc when .tcshrc is missing, so a new .tcshrc would shadow it
+    let cshrc_path = temp_home.path().join(".cshrc");
+    fs::write(&cshrc_path, "set prompt = '> '\n").unwrap();
+
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/tcsh");
+        cmd.args(["config", "shell", "install", "tcsh", "--yes"])
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [32m✓[39m [32mAdded shell extension for [1mtcsh[22m @ [1m~/.cshrc[22m[39m
+        [2m↳[22m [2mCompletions not yet supported for [1mtcsh[22m[22m
+
+        [32m✓[39m [32mConfigured 1 shell[39m
+        [2m↳[22m [2mRestart shell to activate shell integration[22m
+        ");
+    });
+
+    assert!(!temp_home.path().join(".tcshrc").exists());
+    assert_eq!(
+        fs::read_to_string(&cshrc_path).unwrap(),
+        "set prompt = '> '\n\nwhich wt >& /dev/null && eval \"`wt config shell init tcsh`\"\n"
+    );
+}
+
+#[rstest]
+fn test_configure_shell_bash_rc_selector(repo: TestRepo, temp_home: TempDir) {
+    // .bashrc is already configured, but the user wants the line in .bash_profile
+    let bashrc_path = temp_home.path().join(".bashrc");
+    let bashrc_content = "# Existing config\nif command -v wt >/dev/null 2>&1; then eval \"$(command wt config shell init bash)\"; fi\n";
+    fs::write(&bashrc_path, bashrc_content).unwrap();
+    let bash_profile_path = temp_home.path().join(".bash_profile");
+    fs::write(&bash_profile_path, "# Existing bash profile\n").unwrap();
+
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/bash");
+        cmd.args([
+            "config",
+            "shell",
+            "install",
+            "--bash-rc",
+            "bash_profile",
+            "--yes",
+        ])
+        .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [32m✓[39m [32mAdded shell extension & completions for [1mbash[22m @ [1m~/.bash_profile[22m[39m
+        [2m↳[22m [2mSkipped [90mzsh[39m; [90m~/.zshrc[39m not found[22m
+        [2m↳[22m [2mSkipped [90mfish[39m; [90m~/.config/fish/conf.d[39m not found[22m
+        [33m▲[39m [33mShell integration for [1mbash[22m is in multiple files: [1m~/.bashrc[22m, [1m~/.bash_profile[22m[39m
+        [2m↳[22m [2mRemove the line from all but one so it only loads once[22m
+
+        [32m✓[39m [32mConfigured 1 shell[39m
+        [2m↳[22m [2mRestart shell to activate shell integration[22m
+        ");
+    });
+
+    // The already-configured check looks at the chosen file, not .bashrc
+    let content = fs::read_to_string(&bash_profile_path).unwrap();
+    assert!(
+        content.contains("eval \"$(command wt config shell init bash)\""),
+        ".bash_profile should be updated"
+    );
+    assert_eq!(fs::read_to_string(&bashrc_path).unwrap(), bashrc_content);
+}
+
+#[rstest]
+fn test_configure_shell_warns_about_duplicate_lines(repo: TestRepo, temp_home: TempDir) {
+    let line = "if command -v wt >/dev/null 2>&1; then eval \"$(command wt config shell init bash)\"; fi\n";
+    let bashrc_path = temp_home.path().join(".bashrc");
+    let bash_profile_path = temp_home.path().join(".bash_profile");
+    fs::write(&bashrc_path, line).unwrap();
+    fs::write(&bash_profile_path, line).unwrap();
+
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/bash");
+        cmd.args(["config", "shell", "install", "bash", "--yes"])
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [2m○[22m Already configured shell extension & completions for [1mbash[22m @ [1m~/.bashrc[22m
+        [33m▲[39m [33mShell integration for [1mbash[22m is in multiple files: [1m~/.bashrc[22m, [1m~/.bash_profile[22m[39m
+        [2m↳[22m [2mRemove the line from all but one so it only loads once[22m
+        [32m✓[39m [32mAll shells already configured[39m
+        ");
+    });
+
+    // The check is read-only
+    assert_eq!(fs::read_to_string(&bashrc_path).unwrap(), line);
+    assert_eq!(fs::read_to_string(&bash_profile_path).unwrap(), line);
+}
+
+#[rstest]
+fn test_configure_shell_dry_run_warns_about_duplicate_lines(repo: TestRepo, temp_home: TempDir) {
+    // Installing would add the line to .bashrc while .bash_profile already has it
+    let bashrc_path = temp_home.path().join(".bashrc");
+    fs::write(&bashrc_path, "# Existing config\n").unwrap();
+    fs::write(
+        temp_home.path().join(".bash_profile"),
+        "if command -v wt >/dev/null 2>&1; then eval \"$(command wt config shell init bash)\"; fi\n",
+    )
+    .unwrap();
+
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/bash");
+        cmd.args(["config", "shell", "install", "bash", "--dry-run"])
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [2m○[22m Will add shell extension & completions for [1mbash[0m @ [1m~/.bashrc
+        [107m [0m [2m[0m[2m[35mif[0m[2m [0m[2m[34mcommand[0m[2m [0m[2m[36m-v[0m[2m wt [0m[2m[36m>[0m[2m/dev/null [0m[2m[33m2[0m[2m>&1; [0m[2m[35mthen[0m[2m [0m[2m[34meval[0m[2m [0m[2m[32m"$([0m[2m[34mcommand[0m[2m wt config shell init bash)"[0m[2m; [0m[2m[35mfi[0m[2m
+
+        [33m▲[39m [33mShell integration for [1mbash[22m is in multiple files: [1m~/.bashrc[22m, [1m~/.bash_profile[22m[39m
+        [2m↳[22m [2mRemove the line from all but one so it only loads once[22m
+        "#);
+    });
+
+    assert_eq!(
+        fs::read_to_string(&bashrc_path).unwrap(),
+        "# Existing config\n"
+    );
+}
+
 #[rstest]
 fn test_uninstall_shell(repo: TestRepo, temp_home: TempDir) {
     // Create a fake .zshrc file with wt integration
@@ -646,6 +1108,90 @@ fn test_install_uninstall_roundtrip(repo: TestRepo, temp_home: TempDir) {
     );
 }
 
+/// `--cmd` is remembered per shell, so later installs keep each shell's name
+#[rstest]
+fn test_configure_shell_remembers_cmd_per_shell(repo: TestRepo, temp_home: TempDir) {
+    let zshrc_path = temp_home.path().join(".zshrc");
+    let bashrc_path = temp_home.path().join(".bashrc");
+    fs::write(&zshrc_path, "# zsh config\n").unwrap();
+    fs::write(&bashrc_path, "# bash config\n").unwrap();
+    fs::create_dir_all(temp_home.path().join(".config/fish/conf.d")).unwrap();
+
+    let install = |args: &[&str]| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/bash");
+        cmd.args(["config", "shell", "install"])
+            .args(args)
+            .arg("--yes")
+            .current_dir(repo.root_path());
+        let output = cmd.output().expect("Failed to execute command");
+        assert!(
+            output.status.success(),
+            "Install failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    };
+
+    install(&["zsh", "--cmd", "w"]);
+    install(&["fish", "--cmd", "w"]);
+
+    let config = fs::read_to_string(repo.test_config_path()).unwrap();
+    assert!(config.contains("[shell-cmd]"), "config: {config}");
+    assert!(config.contains("zsh = \"w\""), "config: {config}");
+    assert!(config.contains("fish = \"w\""), "config: {config}");
+
+    // Regenerating all shells without --cmd keeps zsh and fish on `w`
+    install(&[]);
+
+    let zshrc = fs::read_to_string(&zshrc_path).unwrap();
+    assert!(zshrc.contains("command w config shell init zsh"));
+    assert!(!zshrc.contains("wt config shell init"), "zshrc: {zshrc}");
+
+    let bashrc = fs::read_to_string(&bashrc_path).unwrap();
+    assert!(bashrc.contains("command wt config shell init bash"));
+
+    let fish = temp_home.path().join(".config/fish");
+    assert!(fish.join("conf.d/w.fish").exists());
+    assert!(fish.join("completions/w.fish").exists());
+    assert!(!fish.join("conf.d/wt.fish").exists());
+    assert!(!fish.join("completions/wt.fish").exists());
+}
+
+/// A user config that fails to load doesn't block install or uninstall
+#[rstest]
+fn test_install_uninstall_with_broken_config(repo: TestRepo, temp_home: TempDir) {
+    let zshrc_path = temp_home.path().join(".zshrc");
+    fs::write(&zshrc_path, "# zsh config\n").unwrap();
+    fs::write(repo.test_config_path(), "[shell-cmd\nzsh = ").unwrap();
+
+    let run = |action: &str| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/zsh");
+        cmd.args(["config", "shell", action, "zsh", "--yes"])
+            .current_dir(repo.root_path());
+        let output = cmd.output().expect("Failed to execute command");
+        assert!(output.status.success(), "{action} failed: {output:?}");
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    };
+
+    let stderr = run("install");
+    assert!(stderr.contains("Failed to load config"), "{stderr}");
+    let zshrc = fs::read_to_string(&zshrc_path).unwrap();
+    assert!(
+        zshrc.contains("command wt config shell init zsh"),
+        "{zshrc}"
+    );
+
+    let stderr = run("uninstall");
+    assert!(stderr.contains("Failed to load config"), "{stderr}");
+    let zshrc = fs::read_to_string(&zshrc_path).unwrap();
+    assert!(!zshrc.contains("config shell init"), "{zshrc}");
+}
+
 #[rstest]
 fn test_install_uninstall_no_blank_line_accumulation(repo: TestRepo, temp_home: TempDir) {
     // Create initial config file matching the user's real zshrc structure
@@ -1014,3 +1560,174 @@ mod pty_tests {
         );
     }
 }
+
+#[rstest]
+fn test_configure_shell_rc_file_creates_custom_path(repo: TestRepo, temp_home: TempDir) {
+    // A config file outside the locations worktrunk scans (e.g. ZDOTDIR users)
+    let rc_path = temp_home.path().join(".dotfiles/bash/bashrc");
+
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/bash");
+        cmd.arg("config")
+            .arg("shell")
+            .arg("install")
+            .arg("--rc-file")
+            .arg(&rc_path)
+            .arg("--yes")
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [32m✓[39m [32mCreated shell extension & completions for [1mbash[22m @ [1m~/.dotfiles/bash/bashrc[22m[39m
+
+        [32m✓[39m [32mConfigured 1 shell[39m
+        [2m↳[22m [2mRestart shell to activate shell integration[22m
+        ");
+    });
+
+    let content = fs::read_to_string(&rc_path).unwrap();
+    assert!(content.contains("eval \"$(command wt config shell init bash)\""));
+    // Default locations are untouched
+    assert!(!temp_home.path().join(".bashrc").exists());
+}
+
+#[rstest]
+fn test_configure_shell_rc_file_already_configured(repo: TestRepo, temp_home: TempDir) {
+    let rc_path = temp_home.path().join(".dotfiles/bashrc");
+    fs::create_dir_all(rc_path.parent().unwrap()).unwrap();
+    fs::write(
+        &rc_path,
+        "# Existing config\nif command -v wt >/dev/null 2>&1; then eval \"$(command wt config shell init bash)\"; fi\n",
+    )
+    .unwrap();
+
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/bash");
+        cmd.arg("config")
+            .arg("shell")
+            .arg("install")
+            .arg("bash")
+            .arg("--rc-file")
+            .arg(&rc_path)
+            .arg("--yes")
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [2m○[22m Already configured shell extension & completions for [1mbash[22m @ [1m~/.dotfiles/bashrc[22m
+        [32m✓[39m [32mAll shells already configured[39m
+        ");
+    });
+
+    // Line isn't duplicated
+    let content = fs::read_to_string(&rc_path).unwrap();
+    assert_eq!(content.matches("wt config shell init").count(), 1);
+}
+
+#[rstest]
+fn test_configure_shell_rc_file_dry_run(repo: TestRepo, temp_home: TempDir) {
+    let rc_path = temp_home.path().join(".config/zsh/.zshrc");
+    fs::create_dir_all(rc_path.parent().unwrap()).unwrap();
+    fs::write(&rc_path, "# Existing config\n").unwrap();
+
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/zsh");
+        cmd.arg("config")
+            .arg("shell")
+            .arg("install")
+            .arg("--rc-file")
+            .arg(&rc_path)
+            .arg("--dry-run")
+            .current_dir(repo.root_path());
+
+        // Shell inferred as zsh from the file name
+        assert_cmd_snapshot!(cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [2m○[22m Will add shell extension & completions for [1mzsh[0m @ [1m~/.config/zsh/.zshrc
+        [107m [0m [2m[0m[2m[35mif[0m[2m [0m[2m[34mcommand[0m[2m [0m[2m[36m-v[0m[2m wt [0m[2m[36m>[0m[2m/dev/null [0m[2m[33m2[0m[2m>&1; [0m[2m[35mthen[0m[2m [0m[2m[34meval[0m[2m [0m[2m[32m"$([0m[2m[34mcommand[0m[2m wt config shell init zsh)"[0m[2m; [0m[2m[35mfi[0m[2m
+        "#);
+    });
+
+    let content = fs::read_to_string(&rc_path).unwrap();
+    assert_eq!(content, "# Existing config\n");
+}
+
+#[rstest]
+fn test_configure_shell_rc_file_explicit_shell_overrides_name(repo: TestRepo, temp_home: TempDir) {
+    // Name suggests zsh, but the explicit shell wins
+    let rc_path = temp_home.path().join("shared.zshrc");
+    fs::write(&rc_path, "").unwrap();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    set_temp_home_env(&mut cmd, temp_home.path());
+    cmd.env("SHELL", "/bin/bash");
+    cmd.arg("config")
+        .arg("shell")
+        .arg("install")
+        .arg("bash")
+        .arg("--rc-file")
+        .arg(&rc_path)
+        .arg("--yes")
+        .current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    let content = fs::read_to_string(&rc_path).unwrap();
+    assert!(content.contains("command wt config shell init bash"));
+    assert!(!content.contains("init zsh"));
+}
+
+#[rstest]
+fn test_configure_shell_rc_file_unknown_shell(repo: TestRepo, temp_home: TempDir) {
+    let rc_path = temp_home.path().join(".shellrc");
+
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.arg("config")
+            .arg("shell")
+            .arg("install")
+            .arg("--rc-file")
+            .arg(&rc_path)
+            .arg("--yes")
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd, @"
+        success: false
+        exit_code: 1
+        ----- stdout -----
+
+        ----- stderr -----
+        [31m✗[39m [31mCannot infer shell from ~/.shellrc; specify it, e.g. `install zsh --rc-file <PATH>`[39m
+        ");
+    });
+
+    assert!(!rc_path.exists());
+}
@@ -1,6 +1,6 @@
 use crate::common::{TestRepo, repo, repo_with_remote};
 use rstest::rstest;
-use worktrunk::git::Repository;
+use worktrunk::git::{GitError, Repository};
 
 #[rstest]
 fn test_get_default_branch_with_origin_head(#[from(repo_with_remote)] repo: TestRepo) {
@@ -12,6 +12,39 @@ fn test_get_default_branch_with_origin_head(#[from(repo_with_remote)] repo: Test
     assert_eq!(branch, "main");
 }
 
+#[rstest]
+fn test_get_default_branch_master_from_origin_head(mut repo: TestRepo) {
+    // Remote whose default is master, alongside other local branches
+    repo.git_command()
+        .args(["branch", "-m", "main", "master"])
+        .status()
+        .unwrap();
+    repo.git_command()
+        .args(["branch", "feature"])
+        .status()
+        .unwrap();
+    repo.setup_remote("master");
+
+    let branch = Repository::at(repo.root_path()).default_branch().unwrap();
+    assert_eq!(branch, "master");
+}
+
+#[rstest]
+fn test_get_default_branch_origin_head_points_elsewhere(#[from(repo_with_remote)] repo: TestRepo) {
+    // origin/HEAD wins over local names, even though main exists locally
+    repo.git_command()
+        .args(["push", "origin", "main:develop"])
+        .status()
+        .unwrap();
+    repo.git_command()
+        .args(["remote", "set-head", "origin", "develop"])
+        .status()
+        .unwrap();
+
+    let branch = Repository::at(repo.root_path()).default_branch().unwrap();
+    assert_eq!(branch, "develop");
+}
+
 #[rstest]
 fn test_get_default_branch_without_origin_head(#[from(repo_with_remote)] repo: TestRepo) {
     // Clear origin/HEAD to force remote query
@@ -180,11 +213,50 @@ fn test_get_default_branch_no_remote_fails_when_no_match(repo: TestRepo) {
     // points to the current branch, not the default branch.
     // Should fail with an error
     let result = Repository::at(repo.root_path()).default_branch();
-    assert!(result.is_err());
-    let err = result.unwrap_err().to_string();
-    assert!(
-        err.contains("Could not infer default branch"),
-        "Expected error about inferring default branch, got: {}",
-        err
+    let err = result.unwrap_err();
+    let Some(GitError::DefaultBranchNotFound { tried }) = err.downcast_ref() else {
+        panic!("Expected DefaultBranchNotFound, got: {err}");
+    };
+    // No remote, so only the local sources were checked
+    assert_eq!(
+        tried,
+        &[
+            "init.defaultBranch",
+            "branches named main/master/develop/trunk"
+        ]
+    );
+}
+
+#[rstest]
+fn test_get_default_branch_not_found_lists_remote_sources(
+    #[from(repo_with_remote)] repo: TestRepo,
+) {
+    // Remote has no HEAD to report and local branches have no common names
+    repo.clear_origin_head();
+    repo.git_command()
+        .args(["branch", "-m", "main", "xyz"])
+        .status()
+        .unwrap();
+    repo.git_command().args(["branch", "abc"]).status().unwrap();
+    let missing = repo.root_path().join("missing.git");
+    repo.git_command()
+        .args(["remote", "set-url", "origin", missing.to_str().unwrap()])
+        .status()
+        .unwrap();
+
+    let err = Repository::at(repo.root_path())
+        .default_branch()
+        .unwrap_err();
+    let Some(GitError::DefaultBranchNotFound { tried }) = err.downcast_ref() else {
+        panic!("Expected DefaultBranchNotFound, got: {err}");
+    };
+    assert_eq!(
+        tried,
+        &[
+            "origin/HEAD",
+            "origin's HEAD",
+            "init.defaultBranch",
+            "branches named main/master/develop/trunk"
+        ]
     );
 }
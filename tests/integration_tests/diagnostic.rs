@@ -428,6 +428,45 @@ fn test_v_does_not_write_diagnostic_without_error(repo: TestRepo) {
     );
 }
 
+/// With --log-format json, every log line on stderr is a JSON object.
+#[rstest]
+fn test_v_log_format_json(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["list", "-v", "--log-format", "json"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "Command should succeed");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let records: Vec<serde_json::Value> = stderr
+        .lines()
+        .map(|line| {
+            serde_json::from_str(line)
+                .unwrap_or_else(|e| panic!("stderr line should be JSON ({e}): {line}"))
+        })
+        .collect();
+    assert!(!records.is_empty(), "Should log with -v");
+
+    for record in &records {
+        for key in ["timestamp", "level", "target", "message"] {
+            assert!(record[key].is_string(), "Missing {key}: {record}");
+        }
+    }
+    assert!(
+        records
+            .iter()
+            .any(|r| r["level"] == "DEBUG" && r["message"] == "$ git worktree list --porcelain [.]"),
+        "Should log git commands without styling. stderr: {stderr}"
+    );
+
+    // The verbose log file stays plain text
+    let verbose_log =
+        fs::read_to_string(repo.root_path().join(".git/wt-logs/verbose.log")).unwrap();
+    assert!(!verbose_log.starts_with('{'), "verbose.log: {verbose_log}");
+}
+
 /// With -vv outside a git repo, command should still work (no crash).
 #[test]
 fn test_vv_outside_repo_no_crash() {
@@ -40,6 +40,167 @@ fn test_switch_directive_file(#[from(repo_with_remote)] mut repo: TestRepo) {
     });
 }
 
+#[rstest]
+fn test_switch_error_directive(repo: TestRepo) {
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "nonexistent"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    // The failure is reported to the wrapper, with no cd to apply
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    let lines: Vec<_> = directives.lines().collect();
+    assert_eq!(
+        lines.len(),
+        1,
+        "Expected only an error directive, got: {directives}"
+    );
+    assert!(
+        lines[0].starts_with("__WORKTRUNK_ERR__") && lines[0].contains("nonexistent"),
+        "Expected an error directive naming the branch, got: {directives}"
+    );
+    assert!(
+        !lines[0].contains('\x1b'),
+        "Error directive should be plain text, got: {directives}"
+    );
+}
+
+#[rstest]
+fn test_switch_env_directives(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.write_project_config(
+        r#"[env]
+DATABASE_URL = "postgres://localhost/{{ branch | sanitize }}?sslmode=disable"
+BAD-NAME = "skipped"
+"#,
+    );
+    repo.commit("Add env config");
+    let _feature_wt = repo.add_worktree("feature/env");
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "feature/env", "--yes"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("BAD-NAME") && stderr.contains("not a valid variable name"),
+        "Invalid env names should be skipped with a warning, got: {stderr}"
+    );
+
+    // Value is written verbatim (including `=`); invalid names are not emitted
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    let env_lines: Vec<_> = directives
+        .lines()
+        .filter(|l| l.starts_with("__WORKTRUNK_ENV__"))
+        .collect();
+    assert_eq!(
+        env_lines,
+        ["__WORKTRUNK_ENV__DATABASE_URL=postgres://localhost/feature-env?sslmode=disable"]
+    );
+}
+
+#[rstest]
+fn test_switch_env_requires_approval(mut repo: TestRepo) {
+    repo.write_project_config(
+        r#"[env]
+PATH = "/tmp/evil:$PATH"
+GREETING = "hello {{ branch | sanitize }}"
+BROKEN = "{{ nonexistent.field }}"
+"#,
+    );
+    repo.commit("Add env config");
+    repo.add_worktree("feature/env");
+
+    let switch = || {
+        let (directive_path, guard) = directive_file();
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        configure_directive_file(&mut cmd, &directive_path);
+        let output = cmd
+            .args(["switch", "feature/env"])
+            .current_dir(repo.root_path())
+            .output()
+            .unwrap();
+        let directives = std::fs::read_to_string(&directive_path).unwrap_or_default();
+        drop(guard);
+        (output, directives)
+    };
+
+    // Unapproved entries are listed for approval (not PATH, which is never exported)
+    let (output, directives) = switch();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("GREETING=hello"), "{stderr}");
+    assert!(stderr.contains("BROKEN="), "{stderr}");
+    assert!(!stderr.contains("PATH="), "{stderr}");
+    assert!(!directives.contains("__WORKTRUNK_ENV__"), "{directives}");
+
+    // Once approved, PATH and the entry that fails to expand are skipped with warnings
+    repo.write_test_config(
+        r#"[projects."repo"]
+approved-commands = ["GREETING=hello {{ branch | sanitize }}", "BROKEN={{ nonexistent.field }}"]
+"#,
+    );
+    let (output, directives) = switch();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("the shell uses it to find or run programs"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("Template render error"), "{stderr}");
+    let env_lines: Vec<_> = directives
+        .lines()
+        .filter(|l| l.starts_with("__WORKTRUNK_ENV__"))
+        .collect();
+    assert_eq!(env_lines, ["__WORKTRUNK_ENV__GREETING=hello feature-env"]);
+}
+
+#[rstest]
+fn test_switch_csh_directives(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.write_project_config(
+        r#"open-editor-command = "code ."
+
+[env]
+GREETING = "it's {{ branch | sanitize }}"
+"#,
+    );
+    repo.commit("Add env config");
+    repo.add_worktree("feature/env");
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "feature/env", "--yes"])
+        .env("WORKTRUNK_SHELL", "tcsh")
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // The csh alias sources the file as-is, so env and exec lines are plain csh
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    let lines: Vec<_> = directives.lines().collect();
+    assert_eq!(lines.len(), 3, "got: {directives}");
+    assert!(lines[0].starts_with("cd '"), "got: {directives}");
+    assert_eq!(lines[1], r"setenv GREETING 'it'\''s feature-env'");
+    assert_eq!(lines[2], "code .");
+}
+
 #[rstest]
 fn test_merge_directive_file(mut repo_with_remote_and_feature: TestRepo) {
     let repo = &mut repo_with_remote_and_feature;
@@ -172,3 +333,185 @@ fn test_merge_directive_remove(mut repo_with_feature_worktree: TestRepo) {
         );
     });
 }
+
+#[rstest]
+fn test_merge_quiet_still_writes_directive(mut repo_with_feature_worktree: TestRepo) {
+    let repo = &mut repo_with_feature_worktree;
+    let feature_wt = &repo.worktrees["feature"];
+    let (directive_path, _guard) = directive_file();
+
+    let mut settings = setup_snapshot_settings(repo);
+    settings.add_filter(r"cd '[^']+'", "cd '[PATH]'");
+
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        configure_directive_file(&mut cmd, &directive_path);
+        cmd.arg("--quiet")
+            .arg("merge")
+            .arg("main")
+            .current_dir(feature_wt);
+
+        // Info and hint lines are hidden; progress and success lines remain
+        assert_cmd_snapshot!(cmd);
+
+        // --quiet never suppresses the cd the shell wrapper depends on
+        let directives = std::fs::read_to_string(&directive_path).unwrap_or_default();
+        assert!(
+            directives.contains("cd '"),
+            "Directive file should contain cd command, got: {}",
+            directives
+        );
+    });
+}
+
+#[rstest]
+fn test_switch_open_editor_directive(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.write_project_config(r#"open-editor-command = "code {{ worktree_path }}""#);
+    repo.commit("Add editor config");
+    let feature_wt = repo.add_worktree("feature/editor");
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "feature/editor", "--yes"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Emitted after the cd, with template values shell-escaped
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    let lines: Vec<_> = directives.lines().collect();
+    assert_eq!(lines.len(), 2, "got: {directives}");
+    assert!(lines[0].starts_with("cd '"), "got: {directives}");
+    assert_eq!(
+        lines[1],
+        format!(
+            "__WORKTRUNK_EXEC__code {}",
+            shell_escape::escape(feature_wt.to_string_lossy())
+        )
+    );
+}
+
+#[rstest]
+fn test_switch_open_editor_without_shell_integration(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.write_project_config(r#"open-editor-command = "echo should-not-run""#);
+    repo.commit("Add editor config");
+    repo.add_worktree("feature/editor");
+
+    // No directive file: nothing is executed or printed
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .args(["switch", "feature/editor", "--yes"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stdout.contains("should-not-run") && !stderr.contains("__WORKTRUNK_EXEC__"),
+        "Editor command leaked without shell integration:\nstdout: {stdout}\nstderr: {stderr}"
+    );
+}
+
+#[rstest]
+fn test_switch_open_editor_skipped_with_no_verify(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.write_project_config(r#"open-editor-command = "code .""#);
+    repo.commit("Add editor config");
+    repo.add_worktree("feature/editor");
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "feature/editor", "--no-verify"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    assert!(
+        !directives.contains("__WORKTRUNK_EXEC__"),
+        "got: {directives}"
+    );
+}
+
+#[rstest]
+fn test_switch_post_switch_command_directive(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.write_project_config(r#"open-editor-command = "code .""#);
+    repo.commit("Add editor config");
+    repo.write_test_config(r#"post-switch-command = "direnv reload""#);
+    repo.add_worktree("feature/env");
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "feature/env", "--yes"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Runs after the cd, ahead of the project's editor command
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    let lines: Vec<_> = directives.lines().collect();
+    assert_eq!(lines.len(), 3, "got: {directives}");
+    assert!(lines[0].starts_with("cd '"), "got: {directives}");
+    assert_eq!(lines[1], "__WORKTRUNK_EXEC__direnv reload");
+    assert_eq!(lines[2], "__WORKTRUNK_EXEC__code .");
+}
+
+#[rstest]
+fn test_switch_post_switch_command_ignores_no_verify(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.write_test_config(r#"post-switch-command = "direnv reload""#);
+    repo.add_worktree("feature/env");
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "feature/env", "--no-verify"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    assert!(
+        directives.contains("__WORKTRUNK_EXEC__direnv reload"),
+        "got: {directives}"
+    );
+}
+
+#[rstest]
+fn test_switch_no_post_switch_skips_command(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.write_test_config(r#"post-switch-command = "direnv reload""#);
+    repo.add_worktree("feature/env");
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "feature/env", "--no-post-switch"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    assert!(
+        !directives.contains("__WORKTRUNK_EXEC__"),
+        "got: {directives}"
+    );
+}
@@ -0,0 +1,84 @@
+use crate::common::{
+    TestRepo, repo, set_temp_home_env, setup_snapshot_settings_with_home, temp_home, wt_command,
+};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// PATH with the directory containing `wt` first, as after a normal install
+fn path_with_wt() -> std::ffi::OsString {
+    let bin_dir = Path::new(env!("CARGO_BIN_EXE_wt")).parent().unwrap();
+    let path = std::env::var_os("PATH").unwrap_or_default();
+    let paths = std::iter::once(bin_dir.to_path_buf()).chain(std::env::split_paths(&path));
+    std::env::join_paths(paths).unwrap()
+}
+
+#[rstest]
+fn test_doctor_all_checks_pass(repo: TestRepo, temp_home: TempDir) {
+    fs::write(
+        temp_home.path().join(".zshrc"),
+        "if command -v wt >/dev/null 2>&1; then eval \"$(command wt config shell init zsh)\"; fi\n",
+    )
+    .unwrap();
+    fs::write(
+        repo.test_config_path(),
+        "[commit-generation]\ncommand = \"sh\"\n",
+    )
+    .unwrap();
+    let directive_file = temp_home.path().join("directive");
+    fs::write(&directive_file, "").unwrap();
+
+    let settings = setup_snapshot_settings_with_home(&repo, &temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        cmd.arg("doctor").current_dir(repo.root_path());
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("PATH", path_with_wt());
+        cmd.env("WORKTRUNK_DIRECTIVE_FILE", &directive_file);
+
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+#[rstest]
+fn test_doctor_reports_failures(repo: TestRepo, temp_home: TempDir) {
+    // No shell config, no LLM command, wt not on PATH, and outside any repository
+    let settings = setup_snapshot_settings_with_home(&repo, &temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        cmd.arg("doctor").current_dir(temp_home.path());
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("PATH", "/usr/bin:/bin");
+
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+#[rstest]
+fn test_doctor_missing_llm_command_fails(repo: TestRepo, temp_home: TempDir) {
+    fs::write(
+        repo.test_config_path(),
+        "[commit-generation]\ncommand = \"nonexistent-llm-command-12345\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    set_temp_home_env(&mut cmd, temp_home.path());
+    let output = cmd
+        .arg("doctor")
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("nonexistent-llm-command-12345") && stderr.contains("not found"),
+        "{stderr}"
+    );
+}
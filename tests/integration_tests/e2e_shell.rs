@@ -63,6 +63,84 @@ fn test_shell_integration_switch_and_remove(#[case] shell: &str, repo: TestRepo)
     );
 }
 
+#[rstest]
+#[case("bash")]
+#[case("fish")]
+#[case("zsh")]
+fn test_shell_integration_exports_project_env(#[case] shell: &str, repo: TestRepo) {
+    repo.write_project_config(
+        r#"[env]
+WT_TEST_URL = "postgres://localhost/{{ branch }}?a=b c"
+"#,
+    );
+    repo.commit("Add env config");
+
+    let init_code = generate_init_code(&repo, shell);
+    let bin_path = wt_bin_dir();
+
+    let script = format!(
+        r#"
+        {}
+        {}
+        wt switch --create env-branch --yes
+        echo "__ENV_AFTER_SWITCH__ $WT_TEST_URL"
+        "#,
+        path_export_syntax(shell, &bin_path),
+        init_code
+    );
+
+    let output = execute_shell_script(&repo, shell, &script);
+
+    assert!(
+        output.contains("__ENV_AFTER_SWITCH__ postgres://localhost/env-branch?a=b c"),
+        "Shell should export WT_TEST_URL, got:\n{}",
+        output
+    );
+    assert!(
+        !output.contains("__WORKTRUNK"),
+        "Directive leakage detected in shell output:\n{}",
+        output
+    );
+}
+
+#[rstest]
+#[case("bash")]
+#[case("fish")]
+#[case("zsh")]
+fn test_shell_integration_runs_open_editor_command(#[case] shell: &str, repo: TestRepo) {
+    repo.write_project_config(r#"open-editor-command = "echo __EDITOR_IN__ $PWD""#);
+    repo.commit("Add editor config");
+
+    let init_code = generate_init_code(&repo, shell);
+    let bin_path = wt_bin_dir();
+
+    let script = format!(
+        r#"
+        {}
+        {}
+        wt switch --create editor-branch --yes
+        "#,
+        path_export_syntax(shell, &bin_path),
+        init_code
+    );
+
+    let output = execute_shell_script(&repo, shell, &script);
+
+    // Runs after the cd, so $PWD is already the new worktree
+    assert!(
+        output
+            .lines()
+            .any(|l| l.contains("__EDITOR_IN__ ") && l.trim_end().ends_with("editor-branch")),
+        "Shell should run the editor command from the new worktree, got:\n{}",
+        output
+    );
+    assert!(
+        !output.contains("__WORKTRUNK"),
+        "Directive leakage detected in shell output:\n{}",
+        output
+    );
+}
+
 #[rstest]
 fn test_bash_shell_integration_error_handling(repo: TestRepo) {
     let init_code = generate_init_code(&repo, "bash");
@@ -72,11 +150,10 @@ fn test_bash_shell_integration_error_handling(repo: TestRepo) {
         r#"
         {}
         {}
-        wt switch --create dup-branch
-        if wt switch --create dup-branch 2>&1; then
+        if wt switch missing-branch 2>&1; then
           echo "__UNEXPECTED_SUCCESS__"
         else
-          echo "__DUPLICATE_ERROR__"
+          echo "__MISSING_ERROR__"
         fi
         "#,
         path_export_syntax("bash", &bin_path),
@@ -85,12 +162,12 @@ fn test_bash_shell_integration_error_handling(repo: TestRepo) {
 
     let output = execute_shell_script(&repo, "bash", &script);
     assert!(
-        output.contains("__DUPLICATE_ERROR__"),
-        "Second switch should have failed, output: {}",
+        output.contains("__MISSING_ERROR__"),
+        "Switch to a missing branch should have failed, output: {}",
         output
     );
     assert!(
-        output.contains("already exists") || output.contains("Branch \"dup-branch\""),
+        output.contains("not found"),
         "User-facing error details missing: {}",
         output
     );
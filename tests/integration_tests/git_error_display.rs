@@ -271,6 +271,7 @@ fn display_hook_command_failed_with_name() {
         command_name: Some("test".into()),
         error: "exit code 1".into(),
         exit_code: Some(1),
+        log_path: None,
     };
 
     assert_snapshot!("hook_command_failed_with_name", err.to_string());
@@ -283,11 +284,25 @@ fn display_hook_command_failed_without_name() {
         command_name: None,
         error: "command not found".into(),
         exit_code: Some(127),
+        log_path: None,
     };
 
     assert_snapshot!("hook_command_failed_without_name", err.to_string());
 }
 
+#[test]
+fn display_hook_command_failed_with_log_path() {
+    let err = WorktrunkError::HookCommandFailed {
+        hook_type: HookType::PreMerge,
+        command_name: Some("test".into()),
+        error: "exit code 1".into(),
+        exit_code: Some(1),
+        log_path: Some("/tmp/hook-logs/feature-project-pre-merge-test.log".into()),
+    };
+
+    assert_snapshot!("hook_command_failed_with_log_path", err.to_string());
+}
+
 /// Shows the complete error with hint, as users would see it.
 #[test]
 fn display_hook_command_failed_with_skip_hint() {
@@ -296,6 +311,7 @@ fn display_hook_command_failed_with_skip_hint() {
         command_name: Some("test".into()),
         error: "exit code 1".into(),
         exit_code: Some(1),
+        log_path: None,
     }
     .into();
 
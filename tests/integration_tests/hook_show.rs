@@ -153,11 +153,13 @@ test = "cargo test"
 fn test_error_with_context_formatting(temp_home: TempDir) {
     let temp_dir = tempfile::tempdir().unwrap();
 
-    // Run wt remove outside a git repo - should show "Failed to remove worktree" context
+    // Run a hook outside a git repo - should show "Failed to determine current branch" context
     let settings = setup_home_snapshot_settings(&temp_home);
     settings.bind(|| {
         let mut cmd = wt_command();
-        cmd.arg("remove").current_dir(temp_dir.path());
+        cmd.arg("hook")
+            .arg("pre-merge")
+            .current_dir(temp_dir.path());
         set_temp_home_env(&mut cmd, temp_home.path());
 
         assert_cmd_snapshot!(cmd);
@@ -223,3 +225,24 @@ lint = "pre-commit run"
         assert_cmd_snapshot!(cmd);
     });
 }
+
+#[rstest]
+fn test_hook_show_warns_about_shadowed_project_config(repo: TestRepo) {
+    repo.write_project_config("pre-merge = \"cargo test\"\n");
+    fs::write(repo.root_path().join(".wt.toml"), "pre-merge = \"make\"\n").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["hook", "show"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("cargo"), "{stderr}");
+    assert!(!stderr.contains("make"), "{stderr}");
+    assert!(
+        stderr.contains(".wt.toml") && stderr.contains("takes precedence"),
+        "{stderr}"
+    );
+}
@@ -39,10 +39,198 @@ fn snapshot_init(test_name: &str, repo: &TestRepo, shell: &str, extra_args: &[&s
 #[case("bash")]
 #[case("fish")]
 #[case("zsh")]
+#[case("nu")]
+#[case("elvish")]
+#[case("xonsh")]
+#[case("tcsh")]
 fn test_init(#[case] shell: &str, repo: TestRepo) {
     snapshot_init(&format!("init_{}", shell), &repo, shell, &[]);
 }
 
+#[rstest]
+fn test_init_cache(repo: TestRepo) {
+    let cache_home = tempfile::tempdir().unwrap();
+    let run = |args: &[&str]| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        cmd.args(["config", "shell", "init", "bash"])
+            .args(args)
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .current_dir(repo.root_path());
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    // --cache prints a shell config line sourcing the cached script
+    let snippet = run(&["--cache"]);
+    let cache_dir = cache_home.path().join("worktrunk");
+    let cached: Vec<_> = std::fs::read_dir(&cache_dir)
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .collect();
+    assert_eq!(cached.len(), 1, "expected one cache file: {cached:?}");
+    let cache_path = &cached[0];
+    let file_name = cache_path.file_name().unwrap().to_string_lossy();
+    assert!(
+        file_name.starts_with("init-wt-") && file_name.ends_with(".bash"),
+        "cache file should be keyed by command and version: {file_name}"
+    );
+    let path = cache_path.display();
+    assert_eq!(
+        snippet.trim(),
+        format!(
+            "if [ -f {path} ]; then source {path}; else eval \"$(command wt config shell init bash --cache)\"; fi"
+        )
+    );
+
+    // The cached script matches the uncached output
+    let full = run(&["--cache", "--no-cache"]);
+    assert!(full.contains("shell integration"));
+    assert_eq!(
+        std::fs::read_to_string(cache_path).unwrap(),
+        full.trim_end()
+    );
+
+    // A stale cache (e.g. from a dev build with the same version) is rewritten
+    std::fs::write(cache_path, "stale").unwrap();
+    assert_eq!(run(&["--cache"]), snippet);
+    assert_eq!(
+        std::fs::read_to_string(cache_path).unwrap(),
+        full.trim_end()
+    );
+}
+
+#[rstest]
+fn test_init_custom_cmd_completions(repo: TestRepo) {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "shell", "init", "bash", "--cmd", "worktree"])
+        .current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let init = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        init.contains("COMPLETE=bash WORKTRUNK_COMPLETE_CMD=worktree command"),
+        "{init}"
+    );
+
+    // The registration script the init script evals defines the completer it calls
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.env("COMPLETE", "bash")
+        .env("WORKTRUNK_COMPLETE_CMD", "worktree")
+        .current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let registration = String::from_utf8(output.stdout).unwrap();
+    assert!(init.contains("_clap_complete_worktree \"$@\""), "{init}");
+    assert!(
+        registration.contains("_clap_complete_worktree()"),
+        "{registration}"
+    );
+    assert!(
+        registration.contains("-F _clap_complete_worktree worktree"),
+        "{registration}"
+    );
+    assert!(
+        !registration.contains("_clap_complete_wt"),
+        "{registration}"
+    );
+}
+
+#[rstest]
+fn test_init_without_completion_support(repo: TestRepo) {
+    // Elvish has a wrapper but no completions: init still succeeds with the wrapper
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "shell", "init", "elvish"])
+        .current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("fn wt {|@args|"),
+        "missing wrapper:\n{stdout}"
+    );
+    assert!(
+        stdout.trim_end().ends_with(
+            "# Completion not yet supported for elvish; only the wt wrapper is installed"
+        ),
+        "missing completion note:\n{stdout}"
+    );
+}
+
+#[rstest]
+#[case("bash")]
+#[case("zsh")]
+#[case("nu")]
+fn test_init_sections(#[case] shell: &str, repo: TestRepo) {
+    snapshot_init(
+        &format!("init_{shell}_integration_only"),
+        &repo,
+        shell,
+        &["--integration-only"],
+    );
+    snapshot_init(
+        &format!("init_{shell}_completions_only"),
+        &repo,
+        shell,
+        &["--completions-only"],
+    );
+}
+
+#[rstest]
+fn test_init_completions_only_fish(repo: TestRepo) {
+    // Fish completions are a separate file, printed instead of the wrapper
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "shell", "init", "fish", "--completions-only"])
+        .current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.starts_with("# worktrunk completions for fish"),
+        "{stdout}"
+    );
+    assert!(!stdout.contains("function wt"), "{stdout}");
+}
+
+#[rstest]
+fn test_init_completions_only_without_completion_support(repo: TestRepo) {
+    // Not an error: a notice on stderr and nothing to eval
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "shell", "init", "elvish", "--completions-only"])
+        .current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("Completion not yet supported for elvish"),
+        "{stderr}"
+    );
+}
+
+#[rstest]
+fn test_init_integration_only_skips_completion_note(repo: TestRepo) {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "shell", "init", "elvish", "--integration-only"])
+        .current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("fn wt {|@args|"), "{stdout}");
+    assert!(!stdout.contains("Completion not yet supported"), "{stdout}");
+}
+
 #[rstest]
 fn test_init_invalid_shell(repo: TestRepo) {
     // Same custom settings as snapshot_init
@@ -223,6 +223,21 @@ fn test_list_json_with_metadata(mut repo: TestRepo) {
     });
 }
 
+#[rstest]
+fn test_list_porcelain(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.add_worktree("locked-feature");
+    repo.lock_worktree("locked-feature", Some("Testing"));
+    repo.detach_head_in_worktree("feature");
+    repo.create_branch("no-worktree");
+
+    assert_cmd_snapshot!({
+        let mut cmd = list_snapshots::command(&repo, repo.root_path());
+        cmd.args(["--format=porcelain", "--branches"]);
+        cmd
+    });
+}
+
 /// This tests the merge commit scenario where content matches main even with different commit history.
 #[rstest]
 fn test_list_json_tree_matches_main_after_merge(mut repo: TestRepo) {
@@ -254,6 +269,76 @@ fn test_list_json_tree_matches_main_after_merge(mut repo: TestRepo) {
     });
 }
 
+/// Worktrees for `--stale`: one merged into main, one idle, one recent
+fn setup_stale_worktrees(repo: &mut TestRepo) {
+    repo.add_worktree("merged-feature");
+
+    for (branch, age) in [("old-feature", 60 * DAY), ("fresh-feature", HOUR)] {
+        let path = repo.add_worktree(branch);
+        std::fs::write(path.join(format!("{branch}.txt")), branch).unwrap();
+        repo.run_git_in(&path, &["add", "."]);
+        repo.commit_staged_with_age(&format!("Work on {branch}"), age, &path);
+    }
+}
+
+#[rstest]
+fn test_list_stale(mut repo: TestRepo) {
+    setup_stale_worktrees(&mut repo);
+
+    assert_cmd_snapshot!({
+        let mut cmd = list_snapshots::command(&repo, repo.root_path());
+        cmd.arg("--stale");
+        cmd
+    });
+}
+
+#[rstest]
+fn test_list_stale_json(mut repo: TestRepo) {
+    setup_stale_worktrees(&mut repo);
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--stale", "90"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let markers: Vec<_> = items
+        .iter()
+        .map(|item| {
+            (
+                item["branch"].as_str().unwrap(),
+                item["merged"].as_bool().unwrap(),
+                item["stale"].as_bool().unwrap(),
+            )
+        })
+        .collect();
+
+    // A 90-day threshold doesn't flag the 60-day-old branch
+    assert_eq!(
+        markers,
+        [
+            ("main", false, false),
+            ("fresh-feature", false, false),
+            ("merged-feature", true, false),
+            ("old-feature", false, false),
+        ]
+    );
+
+    // Without --stale, the fields are absent
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(
+        items
+            .iter()
+            .all(|item| item.get("merged").is_none() && item.get("stale").is_none())
+    );
+}
+
 #[rstest]
 fn test_list_with_branches_flag(mut repo: TestRepo) {
     // Create some branches without worktrees
@@ -1802,6 +1887,38 @@ fn test_list_with_c_flag(mut repo: TestRepo) {
     });
 }
 
+#[rstest]
+fn test_list_with_repo_flag(mut repo: TestRepo) {
+    repo.add_worktree("feature-a");
+    let elsewhere = tempfile::TempDir::new().unwrap();
+
+    let output = wt_command()
+        .args(["--repo", repo.root_path().to_str().unwrap(), "list"])
+        .current_dir(elsewhere.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("feature-a"));
+
+    // A path outside any repository is rejected up front
+    let output = wt_command()
+        .args(["--repo", elsewhere.path().to_str().unwrap(), "list"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("is not a git repository"), "{stderr}");
+
+    // Shell setup ignores it
+    let output = wt_command()
+        .args(["--repo", elsewhere.path().to_str().unwrap()])
+        .args(["config", "shell", "init", "bash"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+}
+
 #[rstest]
 fn test_list_large_diffs_alignment(mut repo: TestRepo) {
     // Worktree with large uncommitted changes and ahead commits
@@ -0,0 +1,50 @@
+use crate::common::{TestRepo, make_snapshot_cmd, repo, wt_command};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_log_feature_branch(mut repo: TestRepo) {
+    let path = repo.add_worktree("feature");
+    repo.commit_in_worktree(&path, "a.txt", "a", "Add a");
+    repo.commit_in_worktree(&path, "b.txt", "b", "Add b");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "log", &[], Some(&path)));
+}
+
+#[rstest]
+fn test_log_oneline(mut repo: TestRepo) {
+    let path = repo.add_worktree("feature");
+    repo.commit_in_worktree(&path, "a.txt", "a", "Add a");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "log", &["--oneline"], Some(&path)));
+}
+
+#[rstest]
+fn test_log_default_branch_is_empty(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "log", &[], None));
+}
+
+#[rstest]
+fn test_log_json(mut repo: TestRepo) {
+    let path = repo.add_worktree("feature");
+    repo.commit_in_worktree(&path, "a.txt", "a", "Add a");
+    repo.commit_in_worktree(&path, "b.txt", "b", "Add b");
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .args(["log", "--format=json"])
+        .current_dir(&path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let commits = json.as_array().unwrap();
+    assert_eq!(commits.len(), 2);
+    assert_eq!(commits[0]["subject"], "Add b");
+    assert_eq!(commits[1]["subject"], "Add a");
+    assert_eq!(commits[0]["sha"], repo.head_sha_in(&path));
+    assert!(commits[0]["author"].is_string());
+    assert!(commits[0]["date"].as_str().unwrap().contains('T'));
+}
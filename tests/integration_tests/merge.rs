@@ -1,12 +1,13 @@
 use crate::common::{
-    TestRepo, make_snapshot_cmd, merge_scenario,
+    TestRepo, configure_directive_file, directive_file, make_snapshot_cmd, merge_scenario,
     mock_commands::{
-        create_mock_cargo, create_mock_llm_api, create_mock_llm_auth, create_mock_pytest,
-        create_mock_ruff, create_mock_uv_pytest_ruff, create_mock_uv_sync,
+        create_mock_cargo, create_mock_gpg, create_mock_llm_api, create_mock_llm_auth,
+        create_mock_pytest, create_mock_ruff, create_mock_uv_pytest_ruff, create_mock_uv_sync,
     },
     repo, repo_with_alternate_primary, repo_with_feature_worktree, repo_with_main_worktree,
-    repo_with_multi_commit_feature, setup_snapshot_settings,
+    repo_with_multi_commit_feature, setup_snapshot_settings, wait_for_path_removed,
 };
+use insta::assert_snapshot;
 use insta_cmd::assert_cmd_snapshot;
 use rstest::rstest;
 use std::fs;
@@ -404,6 +405,246 @@ args = ["-c", "cat > /dev/null; echo 'Error: connection refused' >&2 && exit 1"]
     ));
 }
 
+#[rstest]
+fn test_merge_squash_llm_retry(mut repo_with_main_worktree: TestRepo) {
+    let repo = &mut repo_with_main_worktree;
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "file1.txt", "content 1", "feat: new feature");
+    repo.commit_in_worktree(&feature_wt, "file2.txt", "content 2", "fix: bug fix");
+
+    // First invocation fails (model server still warming up), second succeeds
+    let marker = repo.root_path().parent().unwrap().join("llm-attempted");
+    let worktrunk_config = format!(
+        r#"
+[commit-generation]
+command = "sh"
+args = ["-c", "cat > /dev/null; if [ -f '{marker}' ]; then echo 'feat: combine changes'; else touch '{marker}'; echo 'Error: server warming up' >&2; exit 1; fi"]
+retries = 2
+"#,
+        marker = marker.display()
+    );
+    fs::write(repo.test_config_path(), worktrunk_config).unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        repo,
+        "merge",
+        &["main"],
+        Some(&feature_wt)
+    ));
+    assert!(marker.exists(), "First LLM attempt should have run");
+}
+
+#[rstest]
+fn test_merge_squash_llm_retries_exhausted(mut repo_with_main_worktree: TestRepo) {
+    let repo = &mut repo_with_main_worktree;
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "file1.txt", "content 1", "feat: new feature");
+    repo.commit_in_worktree(&feature_wt, "file2.txt", "content 2", "fix: bug fix");
+
+    // Every attempt fails; the last stderr is surfaced
+    let worktrunk_config = r#"
+[commit-generation]
+command = "sh"
+args = ["-c", "cat > /dev/null; echo 'Error: connection refused' >&2 && exit 1"]
+retries = 1
+"#;
+    fs::write(repo.test_config_path(), worktrunk_config).unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        repo,
+        "merge",
+        &["main"],
+        Some(&feature_wt)
+    ));
+}
+
+#[rstest]
+fn test_step_commit_llm_context_error_retries_with_shorter_diff(repo: TestRepo) {
+    fs::write(repo.root_path().join("a.txt"), "small change\n").unwrap();
+    fs::write(repo.root_path().join("z.txt"), "BIGFILE\n".repeat(2000)).unwrap();
+    repo.run_git(&["add", "a.txt", "z.txt"]);
+
+    // Rejects any prompt that still carries the large file
+    let worktrunk_config = r#"
+[commit-generation]
+command = "sh"
+args = ["-c", "if grep -q BIGFILE; then echo 'Error: prompt exceeds maximum context length' >&2; exit 1; fi; echo 'feat: add files'"]
+"#;
+    fs::write(repo.test_config_path(), worktrunk_config).unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "step", &["commit"], None));
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%s"]),
+        "feat: add files"
+    );
+}
+
+#[rstest]
+fn test_step_commit_llm_context_error_retry_fails(repo: TestRepo) {
+    fs::write(repo.root_path().join("a.txt"), "small change\n").unwrap();
+    repo.run_git(&["add", "a.txt"]);
+
+    // Every prompt is rejected; the retry runs once, then the first error is shown
+    let attempts = repo.root_path().parent().unwrap().join("llm-attempts");
+    let worktrunk_config = format!(
+        r#"
+[commit-generation]
+command = "sh"
+args = ["-c", "cat > /dev/null; echo attempt >> '{attempts}'; echo 'Error: too many tokens' >&2; exit 1"]
+retries = 3
+"#,
+        attempts = attempts.display()
+    );
+    fs::write(repo.test_config_path(), worktrunk_config).unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("too many tokens"), "{stderr}");
+    // Context errors skip the generic retries: one attempt plus the shorter retry
+    assert_eq!(fs::read_to_string(&attempts).unwrap().lines().count(), 2);
+}
+
+#[rstest]
+fn test_step_commit_appends_trailers_once(repo: TestRepo) {
+    fs::write(repo.root_path().join("a.txt"), "change\n").unwrap();
+    repo.run_git(&["add", "a.txt"]);
+
+    // The LLM already returns a subject plus body; the trailer lands after the body
+    let prompt_file = repo.root_path().parent().unwrap().join("llm-prompt");
+    let worktrunk_config = format!(
+        r#"
+[commit-generation]
+command = "sh"
+args = ["-c", "cat > '{prompt}'; printf 'feat: add a\\n\\nExplain the change.\\n'"]
+trailers = ["Co-authored-by: Pair <pair@example.com>"]
+"#,
+        prompt = prompt_file.display()
+    );
+    fs::write(repo.test_config_path(), worktrunk_config).unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let message = repo.git_output(&["log", "-1", "--format=%B"]);
+    assert_eq!(
+        message.trim_end(),
+        "feat: add a\n\nExplain the change.\n\nCo-authored-by: Pair <pair@example.com>"
+    );
+    // Trailers are added after generation, never sent to the LLM
+    let prompt = fs::read_to_string(&prompt_file).unwrap();
+    assert!(!prompt.contains("Co-authored-by"), "{prompt}");
+}
+
+#[rstest]
+fn test_step_commit_custom_fallback_message(repo: TestRepo) {
+    fs::write(repo.root_path().join("a.txt"), "change\n").unwrap();
+    repo.run_git(&["add", "a.txt"]);
+
+    // No command configured: the custom fallback replaces "Changes to a.txt"
+    fs::write(
+        repo.test_config_path(),
+        "[commit-generation]\nfallback-message = \"chore: checkpoint\"\n",
+    )
+    .unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%B"]),
+        "chore: checkpoint"
+    );
+}
+
+#[rstest]
+fn test_merge_squash_llm_timeout(mut repo_with_main_worktree: TestRepo) {
+    let repo = &mut repo_with_main_worktree;
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "file1.txt", "content 1", "feat: new feature");
+    repo.commit_in_worktree(&feature_wt, "file2.txt", "content 2", "fix: bug fix");
+
+    // Hangs without ever reading stdin; should be killed rather than blocking forever
+    let worktrunk_config = r#"
+[commit-generation]
+command = "sh"
+args = ["-c", "exec sleep 30"]
+timeout-secs = 1
+"#;
+    fs::write(repo.test_config_path(), worktrunk_config).unwrap();
+
+    let start = std::time::Instant::now();
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        repo,
+        "merge",
+        &["main"],
+        Some(&feature_wt)
+    ));
+    assert!(
+        start.elapsed() < std::time::Duration::from_secs(20),
+        "LLM command should be killed after the timeout"
+    );
+}
+
+#[rstest]
+fn test_merge_squash_llm_streams_output_when_verbose(mut repo_with_main_worktree: TestRepo) {
+    let repo = &mut repo_with_main_worktree;
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "file1.txt", "content 1", "feat: new feature");
+    repo.commit_in_worktree(&feature_wt, "file2.txt", "content 2", "fix: bug fix");
+
+    let worktrunk_config = r#"
+[commit-generation]
+command = "sh"
+args = ["-c", "cat > /dev/null; printf 'feat: combine changes\\n\\nStreamed body line\\n'"]
+"#;
+    fs::write(repo.test_config_path(), worktrunk_config).unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["-v", "merge", "main", "--no-remove"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Each line is logged as it arrives...
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("LLM: Streamed body line"),
+        "LLM output should stream with -v, got: {stderr}"
+    );
+
+    // ...while the commit message is unchanged
+    let message = repo.git_output(&["log", "-1", "--format=%B", "main"]);
+    assert_eq!(
+        message.trim(),
+        "feat: combine changes\n\nStreamed body line"
+    );
+}
+
 #[rstest]
 fn test_merge_squash_single_commit(mut repo_with_main_worktree: TestRepo) {
     let repo = &mut repo_with_main_worktree;
@@ -434,6 +675,64 @@ fn test_merge_no_squash(repo_with_multi_commit_feature: TestRepo) {
     ));
 }
 
+#[rstest]
+fn test_merge_squash_signs_commit(repo_with_multi_commit_feature: TestRepo) {
+    let repo = &repo_with_multi_commit_feature;
+    let feature_wt = &repo.worktrees["feature"];
+    let bin_dir = tempfile::tempdir().unwrap();
+    let gpg_log = bin_dir.path().join("gpg.log");
+    create_mock_gpg(bin_dir.path(), &gpg_log, true);
+    repo.run_git(&[
+        "config",
+        "gpg.program",
+        &bin_dir.path().join("gpg").to_string_lossy(),
+    ]);
+    repo.write_test_config("[commit]\nsign = true\n");
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--yes", "--no-remove"])
+        .current_dir(feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    // git commit -S hands the squash commit to gpg for a detached signature
+    let gpg_args = fs::read_to_string(&gpg_log).unwrap();
+    assert!(gpg_args.contains("-bsau"), "gpg args: {gpg_args}");
+    let commit = repo.git_output(&["cat-file", "commit", "main"]);
+    assert!(
+        commit.contains("gpgsig"),
+        "Squash commit should be signed: {commit}"
+    );
+}
+
+#[rstest]
+fn test_merge_squash_sign_failure(repo_with_multi_commit_feature: TestRepo) {
+    let repo = &repo_with_multi_commit_feature;
+    let feature_wt = &repo.worktrees["feature"];
+    let bin_dir = tempfile::tempdir().unwrap();
+    create_mock_gpg(bin_dir.path(), &bin_dir.path().join("gpg.log"), false);
+    repo.run_git(&[
+        "config",
+        "gpg.program",
+        &bin_dir.path().join("gpg").to_string_lossy(),
+    ]);
+    repo.write_test_config("[commit]\nsign = true\n");
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+    let feature_before = repo.git_output(&["rev-parse", "feature"]);
+
+    // Git's signing error is shown; no unsigned commit is made in its place
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        repo,
+        "merge",
+        &["main", "--yes", "--no-remove"],
+        Some(feature_wt)
+    ));
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+    assert_eq!(repo.git_output(&["rev-parse", "feature"]), feature_before);
+}
+
 #[rstest]
 fn test_merge_squash_empty_changes(mut repo_with_main_worktree: TestRepo) {
     let repo = &mut repo_with_main_worktree;
@@ -613,73 +912,264 @@ fn test_merge_pre_merge_command_failure(mut repo: TestRepo) {
 }
 
 #[rstest]
-fn test_merge_pre_merge_command_no_hooks(mut repo: TestRepo) {
-    // Create project config with failing pre-merge command
-    let config_dir = repo.root_path().join(".config");
-    fs::create_dir_all(&config_dir).unwrap();
-    fs::write(config_dir.join("wt.toml"), r#"pre-merge = "exit 1""#).unwrap();
-
+fn test_merge_abort_after_pre_merge_failure(mut repo: TestRepo) {
+    repo.write_project_config(r#"pre-merge = "exit 1""#);
     repo.commit("Add config");
 
     let feature_wt = repo.add_feature();
+    repo.commit_in_worktree(&feature_wt, "second.txt", "second", "Add second file");
+    fs::write(feature_wt.join("wip.txt"), "uncommitted\n").unwrap();
+    let start = repo.head_sha_in(&feature_wt);
 
-    // Merge with --no-verify - should skip pre-merge commands and succeed
-    assert_cmd_snapshot!(make_snapshot_cmd(
-        &repo,
-        "merge",
-        &["main", "--no-verify"],
-        Some(&feature_wt)
-    ));
+    // Squashes the two commits plus wip.txt, then the hook fails
+    let output = make_snapshot_cmd(&repo, "merge", &["main", "--yes"], Some(&feature_wt))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert_ne!(repo.head_sha_in(&feature_wt), start);
+
+    assert_cmd_snapshot!(
+        "merge_abort_after_pre_merge_failure",
+        make_snapshot_cmd(&repo, "merge", &["--abort"], Some(&feature_wt))
+    );
+    assert_eq!(repo.head_sha_in(&feature_wt), start);
+    let status = std::process::Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&status.stdout), "?? wip.txt\n");
+
+    // Nothing left to undo
+    assert_cmd_snapshot!(
+        "merge_abort_nothing_to_abort",
+        make_snapshot_cmd(&repo, "merge", &["--abort"], Some(&feature_wt))
+    );
 }
 
 #[rstest]
-fn test_merge_pre_merge_command_named(mut repo: TestRepo) {
-    // Create project config with named pre-merge commands
-    let config_dir = repo.root_path().join(".config");
-    fs::create_dir_all(&config_dir).unwrap();
-    fs::write(
-        config_dir.join("wt.toml"),
-        r#"
-[pre-merge]
-format = "exit 0"
-lint = "exit 0"
-test = "exit 0"
-"#,
-    )
-    .unwrap();
-
+fn test_merge_abort_refuses_after_new_commits(mut repo: TestRepo) {
+    repo.write_project_config(r#"pre-merge = "exit 1""#);
     repo.commit("Add config");
 
     let feature_wt = repo.add_feature();
+    let output = make_snapshot_cmd(&repo, "merge", &["main", "--yes"], Some(&feature_wt))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
 
-    // Merge with --yes - all pre-merge commands should pass
-    assert_cmd_snapshot!(make_snapshot_cmd(
-        &repo,
-        "merge",
-        &["main", "--yes"],
-        Some(&feature_wt)
-    ));
+    // Work continues on the branch after the failed merge
+    repo.commit_in_worktree(&feature_wt, "later.txt", "later", "Add later file");
+    let head = repo.head_sha_in(&feature_wt);
+
+    assert_cmd_snapshot!(
+        "merge_abort_refuses_after_new_commits",
+        make_snapshot_cmd(&repo, "merge", &["--abort"], Some(&feature_wt))
+    );
+    assert_eq!(repo.head_sha_in(&feature_wt), head);
+
+    // Without a record of where the merge stopped, new commits are still detected
+    repo.run_git_in(
+        &feature_wt,
+        &["update-ref", "-d", "refs/wt-merge/end/feature"],
+    );
+    let output = make_snapshot_cmd(&repo, "merge", &["--abort"], Some(&feature_wt))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert_eq!(repo.head_sha_in(&feature_wt), head);
 }
 
 #[rstest]
-fn test_merge_post_merge_command_success(mut repo: TestRepo) {
-    // Create project config with post-merge command that writes a marker file
-    let config_dir = repo.root_path().join(".config");
-    fs::create_dir_all(&config_dir).unwrap();
-    fs::write(
-        config_dir.join("wt.toml"),
-        r#"post-merge = "echo 'merged {{ branch }} to {{ target }}' > post-merge-ran.txt""#,
-    )
-    .unwrap();
-
+fn test_merge_after_failed_merge_of_parent_branch_name(mut repo: TestRepo) {
+    repo.write_project_config(r#"pre-merge = "test {{ branch }} != feature""#);
     repo.commit("Add config");
 
+    // A failed merge of `feature` leaves its records behind...
     let feature_wt = repo.add_feature();
-
-    // Merge with --yes
-    assert_cmd_snapshot!(make_snapshot_cmd(
-        &repo,
-        "merge",
+    let output = make_snapshot_cmd(&repo, "merge", &["main", "--yes"], Some(&feature_wt))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    repo.run_git(&[
+        "worktree",
+        "remove",
+        "--force",
+        feature_wt.to_str().unwrap(),
+    ]);
+    repo.run_git(&["branch", "-D", "feature"]);
+
+    // ...which must not block recording a merge of `feature/x`
+    let nested_wt = repo.add_worktree_with_commit("feature/x", "x.txt", "x", "Add x");
+    let output = make_snapshot_cmd(&repo, "merge", &["main", "--yes"], Some(&nested_wt))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(repo.root_path().join("x.txt").exists());
+}
+
+#[rstest]
+fn test_merge_autostash(mut repo: TestRepo) {
+    // The hook fails if it sees uncommitted changes
+    repo.write_project_config(r#"pre-merge = "test -z \"$(git status --porcelain)\"""#);
+    repo.commit("Add config");
+
+    let feature_wt = repo.add_feature();
+    repo.commit_in_worktree(&feature_wt, "second.txt", "second", "Add second file");
+    fs::write(feature_wt.join("second.txt"), "edited\n").unwrap();
+    fs::write(feature_wt.join("wip.txt"), "uncommitted\n").unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--yes", "--autostash"],
+        Some(&feature_wt)
+    ));
+
+    // The branch's commit landed on main without the uncommitted changes
+    assert_eq!(repo.head_sha_in(&feature_wt), repo.head_sha());
+    assert_eq!(
+        fs::read_to_string(repo.root_path().join("second.txt")).unwrap(),
+        "second"
+    );
+    assert!(!repo.root_path().join("wip.txt").exists());
+
+    // ...and the changes are back in the kept worktree
+    let status = std::process::Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&status.stdout),
+        " M second.txt\n?? wip.txt\n"
+    );
+    let stashes = repo.git_output(&["stash", "list"]);
+    assert!(stashes.is_empty(), "stash should be popped: {stashes}");
+}
+
+#[rstest]
+fn test_merge_autostash_restores_on_failure(mut repo: TestRepo) {
+    repo.write_project_config(r#"pre-merge = "exit 1""#);
+    repo.commit("Add config");
+
+    let feature_wt = repo.add_feature();
+    fs::write(feature_wt.join("wip.txt"), "uncommitted\n").unwrap();
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--yes", "--autostash"],
+        Some(&feature_wt),
+    )
+    .output()
+    .unwrap();
+    assert!(!output.status.success());
+    assert_eq!(
+        fs::read_to_string(feature_wt.join("wip.txt")).unwrap(),
+        "uncommitted\n"
+    );
+    assert!(repo.git_output(&["stash", "list"]).is_empty());
+}
+
+#[rstest]
+fn test_merge_autostash_kept_during_rebase_conflict(mut repo: TestRepo) {
+    let feature_wt = repo.add_feature();
+    repo.commit_in_worktree(&feature_wt, "shared.txt", "feature\n", "Feature edit");
+    fs::write(repo.root_path().join("shared.txt"), "main\n").unwrap();
+    repo.run_git(&["add", "shared.txt"]);
+    repo.run_git(&["commit", "-m", "Main edit"]);
+    fs::write(feature_wt.join("wip.txt"), "uncommitted\n").unwrap();
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--yes", "--no-squash", "--autostash"],
+        Some(&feature_wt),
+    )
+    .output()
+    .unwrap();
+    assert!(!output.status.success());
+
+    // Popping mid-rebase would mix the changes into the conflict, so they stay stashed
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("stash@{0}"), "{stderr}");
+    assert!(!feature_wt.join("wip.txt").exists());
+    assert_eq!(repo.git_output(&["stash", "list"]).lines().count(), 1);
+}
+
+#[rstest]
+fn test_merge_pre_merge_command_no_hooks(mut repo: TestRepo) {
+    // Create project config with failing pre-merge command
+    let config_dir = repo.root_path().join(".config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(config_dir.join("wt.toml"), r#"pre-merge = "exit 1""#).unwrap();
+
+    repo.commit("Add config");
+
+    let feature_wt = repo.add_feature();
+
+    // Merge with --no-verify - should skip pre-merge commands and succeed
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--no-verify"],
+        Some(&feature_wt)
+    ));
+}
+
+#[rstest]
+fn test_merge_pre_merge_command_named(mut repo: TestRepo) {
+    // Create project config with named pre-merge commands
+    let config_dir = repo.root_path().join(".config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("wt.toml"),
+        r#"
+[pre-merge]
+format = "exit 0"
+lint = "exit 0"
+test = "exit 0"
+"#,
+    )
+    .unwrap();
+
+    repo.commit("Add config");
+
+    let feature_wt = repo.add_feature();
+
+    // Merge with --yes - all pre-merge commands should pass
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--yes"],
+        Some(&feature_wt)
+    ));
+}
+
+#[rstest]
+fn test_merge_post_merge_command_success(mut repo: TestRepo) {
+    // Create project config with post-merge command that writes a marker file
+    let config_dir = repo.root_path().join(".config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("wt.toml"),
+        r#"post-merge = "echo 'merged {{ branch }} to {{ target }}' > post-merge-ran.txt""#,
+    )
+    .unwrap();
+
+    repo.commit("Add config");
+
+    let feature_wt = repo.add_feature();
+
+    // Merge with --yes
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
         &["main", "--yes"],
         Some(&feature_wt)
     ));
@@ -699,21 +1189,92 @@ fn test_merge_post_merge_command_success(mut repo: TestRepo) {
 }
 
 #[rstest]
-fn test_merge_post_merge_command_skipped_with_no_verify(mut repo: TestRepo) {
-    // Create project config with post-merge command that writes a marker file
+fn test_merge_removes_worktree_and_returns_to_target(mut repo: TestRepo) {
     let config_dir = repo.root_path().join(".config");
     fs::create_dir_all(&config_dir).unwrap();
     fs::write(
         config_dir.join("wt.toml"),
-        r#"post-merge = "echo 'merged {{ branch }} to {{ target }}' > post-merge-ran.txt""#,
+        r#"post-merge = "echo 'merged {{ branch }}' > post-merge-ran.txt""#,
     )
     .unwrap();
+    repo.commit("Add config");
+
+    let feature_wt = repo.add_feature();
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = repo.wt_command();
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["merge", "main", "--yes"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    // The shell is sent back to the target worktree
+    let directives = fs::read_to_string(&directive_path).unwrap();
+    assert!(
+        directives.contains("cd '"),
+        "Directive file should cd back to main, got: {directives}"
+    );
+
+    // Post-merge ran in the target worktree; the feature worktree is removed
+    // in the background
+    assert!(repo.root_path().join("post-merge-ran.txt").exists());
+    wait_for_path_removed(&feature_wt);
+}
 
+#[rstest]
+fn test_merge_keeps_worktree_dirtied_by_pre_merge(mut repo: TestRepo) {
+    // A pre-merge hook that leaves an untracked file behind
+    let config_dir = repo.root_path().join(".config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("wt.toml"),
+        r#"pre-merge = "echo x > build.log""#,
+    )
+    .unwrap();
     repo.commit("Add config");
 
     let feature_wt = repo.add_feature();
 
-    // Merge with --no-verify - hook should be skipped entirely
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--yes"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("uncommitted changes"),
+        "Removal should abort on the dirty worktree: {stderr}"
+    );
+
+    // The merge itself landed; only the removal was skipped
+    let merged = repo.git_output(&["rev-parse", "main"]);
+    let feature = repo.git_output(&["rev-parse", "feature"]);
+    assert_eq!(merged, feature);
+    assert!(feature_wt.join("build.log").exists());
+}
+
+#[rstest]
+fn test_merge_post_merge_command_runs_with_no_verify(mut repo: TestRepo) {
+    // Create project config with a pre-merge and a post-merge command that write marker files
+    let config_dir = repo.root_path().join(".config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("wt.toml"),
+        r#"pre-merge = "echo ran > pre-merge-ran.txt"
+post-merge = "echo 'merged {{ branch }} to {{ target }}' > post-merge-ran.txt""#,
+    )
+    .unwrap();
+
+    repo.commit("Add config");
+
+    let feature_wt = repo.add_feature();
+
+    // Merge with --no-verify - pre-merge is skipped, post-merge still runs
     assert_cmd_snapshot!(make_snapshot_cmd(
         &repo,
         "merge",
@@ -721,11 +1282,14 @@ fn test_merge_post_merge_command_skipped_with_no_verify(mut repo: TestRepo) {
         Some(&feature_wt)
     ));
 
-    // Verify the command did not run in the main worktree
+    assert!(
+        !feature_wt.join("pre-merge-ran.txt").exists(),
+        "Pre-merge command should not run when --no-verify is set"
+    );
     let marker_file = repo.root_path().join("post-merge-ran.txt");
     assert!(
-        !marker_file.exists(),
-        "Post-merge command should not run when --no-verify is set"
+        marker_file.exists(),
+        "Post-merge command should still run when --no-verify is set"
     );
 }
 
@@ -1976,9 +2540,395 @@ fn test_step_commit_with_both_flags(repo: TestRepo) {
     });
 }
 
+#[rstest]
+fn test_step_commit_model_flag_overrides_config(repo: TestRepo) {
+    fs::write(repo.root_path().join("file1.txt"), "content 1").expect("Failed to write file");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--model", "cli-model"])
+        .env("WORKTRUNK_COMMIT_GENERATION__COMMAND", "echo")
+        .env("WORKTRUNK_COMMIT_GENERATION__ARGS", "feat: via {model}")
+        .env("WORKTRUNK_COMMIT_GENERATION__MODEL", "config-model")
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "wt step commit --model should succeed, got: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let message = repo.git_output(&["log", "-1", "--format=%s"]);
+    assert_eq!(message.trim(), "feat: via cli-model");
+}
+
+#[rstest]
+fn test_step_commit_model_needs_placeholder_or_model_arg(repo: TestRepo) {
+    fs::write(repo.root_path().join("file1.txt"), "content 1").expect("Failed to write file");
+
+    let step_commit = |model_arg: Option<&str>| {
+        let mut cmd = repo.wt_command();
+        cmd.args(["step", "commit", "--model", "cli-model"])
+            .env("WORKTRUNK_COMMIT_GENERATION__COMMAND", "echo")
+            .env("WORKTRUNK_COMMIT_GENERATION__ARGS", "feat: via")
+            .current_dir(repo.root_path());
+        if let Some(model_arg) = model_arg {
+            cmd.env("WORKTRUNK_COMMIT_GENERATION__MODEL_ARG", model_arg);
+        }
+        cmd.output().unwrap()
+    };
+
+    // `--model` isn't passed to a command that might not accept it
+    let output = step_commit(None);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("model-arg"), "{stderr}");
+
+    let output = step_commit(Some("--model"));
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let message = repo.git_output(&["log", "-1", "--format=%s"]);
+    assert_eq!(message.trim(), "feat: via --model cli-model");
+}
+
+#[rstest]
+fn test_step_commit_max_diff_bytes_truncates_prompt(repo: TestRepo) {
+    fs::write(repo.root_path().join("a.txt"), "small\n").unwrap();
+    fs::write(repo.root_path().join("b.txt"), "large\n".repeat(1000)).unwrap();
+    repo.run_git(&["add", "a.txt", "b.txt"]);
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--show-prompt"])
+        .env("WORKTRUNK_COMMIT_GENERATION__MAX_DIFF_BYTES", "500")
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let prompt = String::from_utf8_lossy(&output.stdout);
+    assert!(prompt.contains("diff --git a/a.txt b/a.txt"));
+    assert!(!prompt.contains("diff --git a/b.txt b/b.txt"));
+    assert!(prompt.contains("... [diff truncated, 1 files omitted]"));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("over max-diff-bytes (500)"),
+        "Truncation should warn, got: {stderr}"
+    );
+}
+
+#[rstest]
+fn test_step_commit_style_commit_count_limits_recent_commits(repo: TestRepo) {
+    for subject in ["First change", "Second change", "Third change"] {
+        repo.commit(subject);
+    }
+    fs::write(repo.root_path().join("a.txt"), "content\n").unwrap();
+    repo.run_git(&["add", "a.txt"]);
+
+    let show_prompt = |count: &str| {
+        let output = repo
+            .wt_command()
+            .args(["step", "commit", "--show-prompt"])
+            .env("WORKTRUNK_COMMIT_GENERATION__STYLE_COMMIT_COUNT", count)
+            .current_dir(repo.root_path())
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let prompt = show_prompt("2");
+    assert!(prompt.contains("- Third change"), "{prompt}");
+    assert!(prompt.contains("- Second change"), "{prompt}");
+    assert!(!prompt.contains("- First change"), "{prompt}");
+
+    // Zero leaves history out entirely
+    let prompt = show_prompt("0");
+    assert!(!prompt.contains("<recent_commits>"), "{prompt}");
+    assert!(!prompt.contains("Third change"), "{prompt}");
+}
+
+#[rstest]
+fn test_step_commit_style_include_bodies(repo: TestRepo) {
+    fs::write(repo.root_path().join("auth.txt"), "auth\n").unwrap();
+    repo.run_git(&["add", "auth.txt"]);
+    repo.run_git(&[
+        "commit",
+        "-m",
+        "Add auth",
+        "-m",
+        "Tokens expire after an hour.\n\nRefresh is handled by the client.",
+    ]);
+    fs::write(repo.root_path().join("a.txt"), "content\n").unwrap();
+    repo.run_git(&["add", "a.txt"]);
+
+    let show_prompt = |include_bodies: &str| {
+        let output = repo
+            .wt_command()
+            .args(["step", "commit", "--show-prompt"])
+            .env(
+                "WORKTRUNK_COMMIT_GENERATION__STYLE_INCLUDE_BODIES",
+                include_bodies,
+            )
+            .current_dir(repo.root_path())
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    // Bodies are indented under their subject, inside the list
+    let prompt = show_prompt("true");
+    assert!(
+        prompt.contains(
+            "- Add auth\n  Tokens expire after an hour.\n  Refresh is handled by the client.\n"
+        ),
+        "{prompt}"
+    );
+
+    // Off by default: subjects only
+    let prompt = show_prompt("false");
+    assert!(prompt.contains("- Add auth\n"), "{prompt}");
+    assert!(!prompt.contains("Tokens expire"), "{prompt}");
+}
+
+#[rstest]
+fn test_step_commit_template_from_env_var(repo: TestRepo) {
+    fs::write(repo.root_path().join("a.txt"), "content\n").unwrap();
+    repo.run_git(&["add", "a.txt"]);
+
+    let show_prompt = |config_template: Option<&str>| {
+        let mut cmd = repo.wt_command();
+        cmd.args(["step", "commit", "--show-prompt"])
+            .env(
+                "WORKTRUNK_COMMIT_TEMPLATE",
+                "Describe {{ branch }}: {{ files }}",
+            )
+            .current_dir(repo.root_path());
+        if let Some(template) = config_template {
+            cmd.env("WORKTRUNK_COMMIT_GENERATION__TEMPLATE", template);
+        }
+        let output = cmd.output().unwrap();
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    // Used when config has no template
+    assert_eq!(show_prompt(None).trim(), "Describe main: A a.txt");
+    // A template in config takes precedence
+    assert_eq!(show_prompt(Some("From config")).trim(), "From config");
+}
+
+#[rstest]
+fn test_step_commit_template_cli_override(repo: TestRepo) {
+    fs::write(repo.root_path().join("a.txt"), "content\n").unwrap();
+    repo.run_git(&["add", "a.txt"]);
+    let template_path = repo.root_path().parent().unwrap().join("one-off.txt");
+    fs::write(&template_path, "From file: {{ files }}").unwrap();
+
+    let show_prompt = |args: &[&str]| {
+        repo.wt_command()
+            .args(["step", "commit", "--show-prompt"])
+            .args(args)
+            .env("WORKTRUNK_COMMIT_GENERATION__TEMPLATE", "From config")
+            .current_dir(repo.root_path())
+            .output()
+            .unwrap()
+    };
+
+    // Either flag wins over the configured template
+    let output = show_prompt(&["--template", "From CLI: {{ branch }}"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "From CLI: main"
+    );
+
+    let output = show_prompt(&["--template-file", template_path.to_str().unwrap()]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "From file: A a.txt"
+    );
+
+    // A missing file gets the same read error as the config option
+    let output = show_prompt(&["--template-file", "/nonexistent/template.txt"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Failed to read template-file"), "{stderr}");
+
+    // Mutually exclusive, and neither may be empty
+    let output = show_prompt(&["--template", "x", "--template-file", "y"]);
+    assert_eq!(output.status.code(), Some(2));
+    let output = show_prompt(&["--template", ""]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[rstest]
+fn test_step_commit_scope_from_staged_paths(repo: TestRepo) {
+    let auth_dir = repo.root_path().join("src/auth");
+    fs::create_dir_all(auth_dir.join("oauth")).unwrap();
+    fs::write(auth_dir.join("login.rs"), "fn login() {}\n").unwrap();
+    fs::write(auth_dir.join("oauth/github.rs"), "fn github() {}\n").unwrap();
+    repo.run_git(&["add", "src"]);
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--show-prompt"])
+        .env(
+            "WORKTRUNK_COMMIT_GENERATION__TEMPLATE",
+            "feat({{ scope }}): {{ branch }}",
+        )
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "feat(auth): main"
+    );
+}
+
+#[rstest]
+fn test_step_commit_redacts_secrets(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[commit-generation]
+template = "{{ git_diff }}\n{{ files }}"
+redact-defaults = true
+redact-patterns = ["internal-[a-z]+"]
+"#,
+    );
+    fs::write(
+        repo.root_path().join("settings-internal-staging.env"),
+        "OPENAI_API_KEY=\"sk-proj-abcdefghijklmnopqrstuvwxyz123456\"\nHOST=db\n",
+    )
+    .unwrap();
+    repo.run_git(&["add", "."]);
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--show-prompt"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let prompt = String::from_utf8(output.stdout).unwrap();
+    assert!(!prompt.contains("sk-proj-abcdef"), "{prompt}");
+    assert!(!prompt.contains("internal-staging"), "{prompt}");
+    assert!(prompt.contains("+[REDACTED]\n+HOST=db"), "{prompt}");
+    assert!(prompt.contains("A settings-[REDACTED].env"), "{prompt}");
+}
+
+#[rstest]
+fn test_step_commit_redacts_credentials_by_default(repo: TestRepo) {
+    let write_config = |extra: &str| {
+        repo.write_test_config(&format!(
+            "[commit-generation]\ntemplate = \"{{{{ git_diff }}}}\"\n{extra}"
+        ))
+    };
+    let show_prompt = || {
+        let output = repo
+            .wt_command()
+            .args(["step", "commit", "--show-prompt"])
+            .current_dir(repo.root_path())
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8(output.stdout).unwrap()
+    };
+    fs::write(
+        repo.root_path().join("client.py"),
+        "API_KEY = \"sk-abcdefghijklmnopqrstuvwx\"\n",
+    )
+    .unwrap();
+    repo.run_git(&["add", "."]);
+
+    write_config("");
+    let prompt = show_prompt();
+    assert!(!prompt.contains("sk-abcdef"), "{prompt}");
+    assert!(prompt.contains("+[REDACTED]"), "{prompt}");
+
+    write_config("redact-defaults = false\n");
+    let prompt = show_prompt();
+    assert!(prompt.contains("sk-abcdefghijklmnopqrstuvwx"), "{prompt}");
+}
+
+#[rstest]
+fn test_step_commit_project_template_uses_user_command(repo: TestRepo) {
+    // The user's command echoes the first line of the prompt as the message
+    fs::write(
+        repo.test_config_path(),
+        r#"
+[commit-generation]
+command = "sh"
+args = ["-c", "head -n 1"]
+template = "feat: from user template"
+"#,
+    )
+    .unwrap();
+    // The project only overrides the template
+    repo.write_project_config(
+        r#"
+[commit-generation]
+template = "feat: from project template on {{ branch }}"
+"#,
+    );
+    fs::write(repo.root_path().join("a.txt"), "content\n").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%s"]),
+        "feat: from project template on main"
+    );
+}
+
+#[rstest]
+fn test_step_commit_project_command_needs_approval(repo: TestRepo) {
+    repo.write_project_config(
+        r#"
+[commit-generation]
+command = "sh"
+args = ["-c", "cat >/dev/null && echo 'feat: from project command'"]
+"#,
+    );
+    fs::write(repo.root_path().join("a.txt"), "content\n").unwrap();
+
+    // Non-interactive without --yes: the unapproved project command can't run
+    let output = repo
+        .wt_command()
+        .args(["step", "commit"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success(), "{output:?}");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--yes"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%s"]),
+        "feat: from project command"
+    );
+}
+
 #[rstest]
 fn test_step_commit_nothing_to_commit(repo: TestRepo) {
-    // No changes made - commit should fail with "nothing to commit"
+    // No changes made - commit should fail before generating a message
     assert_cmd_snapshot!({
         let mut cmd = make_snapshot_cmd(&repo, "step", &[], None);
         cmd.arg("commit").args(["--stage=none"]);
@@ -1995,6 +2945,33 @@ fn test_step_commit_nothing_to_commit(repo: TestRepo) {
     });
 }
 
+#[rstest]
+fn test_step_commit_no_staged_changes_skips_llm(repo: TestRepo) {
+    // Unstaged edits with --stage=none leave the staged diff empty, so the
+    // LLM must not be spawned with an empty prompt
+    std::fs::write(repo.root_path().join("file.txt"), "unstaged").unwrap();
+    let marker = repo.root_path().join("llm-invoked");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--stage=none"])
+        .env("WORKTRUNK_COMMIT_GENERATION__COMMAND", "touch")
+        .env(
+            "WORKTRUNK_COMMIT_GENERATION__ARGS",
+            marker.to_str().unwrap(),
+        )
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("No staged changes to generate a commit message from"),
+        "unexpected stderr: {stderr}"
+    );
+    assert!(!marker.exists(), "LLM command should not run");
+}
+
 // =============================================================================
 // Error message snapshot tests
 // =============================================================================
@@ -2100,6 +3077,185 @@ fn test_step_squash_show_prompt(repo_with_multi_commit_feature: TestRepo) {
     ));
 }
 
+#[rstest]
+fn test_step_commit_show_prompt_since(repo: TestRepo) {
+    let base = repo.head_sha();
+    repo.commit_in_worktree(repo.root_path(), "first.txt", "first\n", "Add first");
+    repo.commit_in_worktree(repo.root_path(), "second.txt", "second\n", "Add second");
+    fs::write(repo.root_path().join("staged.txt"), "staged\n").unwrap();
+    repo.run_git(&["add", "staged.txt"]);
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--show-prompt", "--since", &base])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let prompt = String::from_utf8_lossy(&output.stdout);
+
+    // The diff covers the commits since the ref, not the staged changes
+    assert!(prompt.contains("+++ b/first.txt"), "{prompt}");
+    assert!(prompt.contains("+++ b/second.txt"), "{prompt}");
+    assert!(!prompt.contains("staged.txt"), "{prompt}");
+    // Recent commits still come from the current branch
+    assert!(prompt.contains("Add second"), "{prompt}");
+}
+
+#[rstest]
+fn test_step_commit_since_sends_range_diff(repo: TestRepo) {
+    let base = repo.head_sha();
+    repo.commit_in_worktree(repo.root_path(), "first.txt", "first\n", "Add first");
+    fs::write(repo.root_path().join("staged.txt"), "staged\n").unwrap();
+
+    let prompt_file = repo.root_path().parent().unwrap().join("llm-prompt");
+    fs::write(
+        repo.test_config_path(),
+        format!(
+            r#"
+[commit-generation]
+command = "sh"
+args = ["-c", "cat > '{prompt}'; echo 'feat: describe range'"]
+"#,
+            prompt = prompt_file.display()
+        ),
+    )
+    .unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--since", &base])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let prompt = fs::read_to_string(&prompt_file).unwrap();
+    assert!(prompt.contains("+++ b/first.txt"), "{prompt}");
+    assert!(!prompt.contains("staged.txt"), "{prompt}");
+    // The staged changes are still what gets committed
+    assert_eq!(
+        repo.git_output(&["show", "--name-only", "--format=%s", "HEAD"]),
+        "feat: describe range\n\nstaged.txt"
+    );
+}
+
+#[rstest]
+fn test_step_commit_since_unresolvable_ref(repo: TestRepo) {
+    fs::write(repo.root_path().join("a.txt"), "change\n").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--since", "no-such-ref"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("no-such-ref"), "{stderr}");
+    // Nothing was staged before the ref was rejected
+    assert_eq!(repo.git_output(&["diff", "--staged", "--name-only"]), "");
+}
+
+// =============================================================================
+// --preview tests
+// =============================================================================
+
+#[rstest]
+fn test_step_commit_preview_matches_commit(repo: TestRepo) {
+    fs::write(repo.root_path().join("a.txt"), "change\n").unwrap();
+    fs::write(
+        repo.test_config_path(),
+        r#"
+[commit-generation]
+command = "sh"
+args = ["-c", "cat >/dev/null; printf 'feat: add a\\n\\nExplain the change.\\n'"]
+trailers = ["Co-authored-by: Pair <pair@example.com>"]
+"#,
+    )
+    .unwrap();
+    let head = repo.head_sha();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--preview"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let preview = String::from_utf8(output.stdout).unwrap();
+    assert_snapshot!(preview, @r"
+    feat: add a
+
+    Explain the change.
+
+    Co-authored-by: Pair <pair@example.com>
+    ");
+
+    // Nothing committed; the changes the message describes are staged
+    assert_eq!(repo.head_sha(), head);
+    assert_eq!(
+        repo.git_output(&["diff", "--staged", "--name-only"]),
+        "a.txt"
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%B"]),
+        preview.trim_end()
+    );
+}
+
+#[rstest]
+fn test_step_commit_preview_fallback(repo: TestRepo) {
+    // No LLM configured: previews the deterministic fallback message
+    fs::write(repo.root_path().join("a.txt"), "change\n").unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["commit", "--preview"],
+        None
+    ));
+}
+
+#[rstest]
+fn test_step_commit_preview_skips_hooks(repo: TestRepo) {
+    fs::write(repo.root_path().join("a.txt"), "change\n").unwrap();
+    repo.write_test_config("pre-commit = \"touch hook-ran\"\n");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--preview"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Changes to a.txt\n"
+    );
+    assert!(!repo.root_path().join("hook-ran").exists());
+}
+
 // =============================================================================
 // step rebase tests
 // =============================================================================
@@ -2143,3 +3299,106 @@ fn test_step_rebase_with_merge_commit(mut repo: TestRepo) {
         cmd
     });
 }
+
+#[rstest]
+fn test_step_amend_rewords_head(repo: TestRepo) {
+    fs::write(repo.root_path().join("b.txt"), "content\n").unwrap();
+    repo.run_git(&["add", "b.txt"]);
+    repo.run_git(&["commit", "-m", "wip"]);
+    // Staged changes stay out of the amended commit
+    fs::write(repo.root_path().join("c.txt"), "staged\n").unwrap();
+    repo.run_git(&["add", "c.txt"]);
+
+    // Fake LLM echoes the prompt, so the message shows what the template saw
+    fs::write(
+        repo.test_config_path(),
+        r#"[commit-generation]
+command = "cat"
+template = "Reword: {{ files }}"
+"#,
+    )
+    .unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "amend", "--show-prompt"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "Reword: A b.txt"
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["step", "amend"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%B"]),
+        "Reword: A b.txt"
+    );
+    assert_eq!(
+        repo.git_output(&["show", "--format=", "--name-only", "HEAD"]),
+        "b.txt"
+    );
+    assert_eq!(
+        repo.git_output(&["diff", "--staged", "--name-only"]),
+        "c.txt"
+    );
+}
+
+#[rstest]
+fn test_step_amend_refuses_merge_and_pushed_commits(mut repo: TestRepo) {
+    fs::write(
+        repo.test_config_path(),
+        "[commit-generation]\ncommand = \"sh\"\nargs = [\"-c\", \"cat >/dev/null; echo 'feat: reworded'\"]\n",
+    )
+    .unwrap();
+    let amend = |repo: &TestRepo, args: &[&str]| {
+        repo.wt_command()
+            .args(["step", "amend"])
+            .args(args)
+            .current_dir(repo.root_path())
+            .output()
+            .unwrap()
+    };
+
+    // HEAD is on origin/main once pushed
+    repo.setup_remote("main");
+    let output = amend(&repo, &[]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("already pushed to"), "{stderr}");
+    assert!(stderr.contains("origin/main"), "{stderr}");
+
+    let output = amend(&repo, &["--force"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%B"]),
+        "feat: reworded"
+    );
+
+    // A merge commit is refused even without an upstream
+    repo.run_git(&["branch", "--unset-upstream"]);
+    repo.run_git(&["checkout", "-b", "side"]);
+    repo.commit("side change");
+    repo.run_git(&["checkout", "main"]);
+    fs::write(repo.root_path().join("main.txt"), "main\n").unwrap();
+    repo.run_git(&["add", "main.txt"]);
+    repo.run_git(&["commit", "-m", "main change"]);
+    repo.run_git(&["merge", "--no-ff", "-m", "Merge side", "side"]);
+    let output = amend(&repo, &[]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("merge commit"), "{stderr}");
+    assert_eq!(repo.git_output(&["log", "-1", "--format=%s"]), "Merge side");
+}
@@ -12,10 +12,13 @@ pub mod approval_ui;
 pub mod approvals;
 pub mod bare_repository;
 pub mod ci_status;
+pub mod clone;
 pub mod column_alignment_verification;
 pub mod completion;
 pub mod completion_validation;
 pub mod config_init;
+pub mod config_print;
+pub mod config_set;
 pub mod config_show;
 pub mod config_show_theme;
 pub mod config_state;
@@ -24,6 +27,7 @@ pub mod default_branch;
 pub mod diagnostic;
 pub mod directives;
 pub mod doc_templates;
+pub mod doctor;
 pub mod e2e_shell;
 pub mod e2e_shell_post_start;
 pub mod for_each;
@@ -35,12 +39,16 @@ pub mod list;
 pub mod list_column_alignment;
 pub mod list_config;
 pub mod list_progressive;
+pub mod log;
 pub mod merge;
+pub mod move_worktree;
 pub mod output_system_guard;
 pub mod post_start_commands;
+pub mod prune;
 pub mod push;
 pub mod readme_sync;
 pub mod remove;
+pub mod rename;
 pub mod repository;
 pub mod security;
 pub mod select;
@@ -49,6 +57,7 @@ pub mod shell_integration_prompt;
 pub mod shell_integration_windows;
 pub mod shell_wrapper;
 pub mod spacing_edge_cases;
+pub mod status;
 pub mod statusline;
 pub mod step_copy_ignored;
 pub mod switch;
@@ -0,0 +1,101 @@
+use crate::common::{
+    TestRepo, configure_directive_file, directive_file, make_snapshot_cmd, repo, wt_command,
+};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::path::PathBuf;
+
+/// A sibling of the main worktree named like worktrunk's default layout (`<repo>.<suffix>`)
+fn moved_path(repo: &TestRepo, suffix: &str) -> PathBuf {
+    let mut path = repo.root_path().as_os_str().to_owned();
+    path.push(format!(".{suffix}"));
+    PathBuf::from(path)
+}
+
+#[rstest]
+fn test_move_worktree(mut repo: TestRepo) {
+    let old_path = repo.add_worktree("feature");
+    let new_path = moved_path(&repo, "moved");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "move",
+        &["feature", new_path.to_str().unwrap()],
+        None
+    ));
+
+    assert!(!old_path.exists(), "Old worktree directory should be gone");
+    let branch = repo.git_output(&["-C", new_path.to_str().unwrap(), "branch", "--show-current"]);
+    assert_eq!(branch, "feature");
+
+    // Switching to the branch afterwards targets the new location
+    let (directive_path, _guard) = directive_file();
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "feature"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    assert!(directives.contains(".moved'"), "{directives}");
+}
+
+#[rstest]
+fn test_move_from_inside_worktree_changes_directory(mut repo: TestRepo) {
+    let old_path = repo.add_worktree("feature");
+    std::fs::create_dir(old_path.join("src")).unwrap();
+    let new_path = moved_path(&repo, "moved");
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["move", "@", new_path.to_str().unwrap()])
+        .current_dir(old_path.join("src"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "move should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    assert!(
+        directives.contains("moved/src'"),
+        "Should cd into the same subdirectory of the moved worktree, got: {directives}"
+    );
+}
+
+#[rstest]
+fn test_move_onto_existing_path_fails(mut repo: TestRepo) {
+    let path = repo.add_worktree("feature");
+    let taken = moved_path(&repo, "taken");
+    std::fs::create_dir(&taken).unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "move",
+        &["feature", taken.to_str().unwrap()],
+        None
+    ));
+    assert!(path.exists(), "Nothing should move when the target exists");
+}
+
+#[rstest]
+fn test_move_main_worktree_fails(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "move",
+        &["^", "../elsewhere"],
+        None
+    ));
+}
@@ -32,6 +32,103 @@ fn snapshot_switch(test_name: &str, repo: &TestRepo, args: &[&str]) {
     });
 }
 
+// ============================================================================
+// Pre-Create Command Tests (sequential, blocking, fail-fast)
+// ============================================================================
+
+#[rstest]
+fn test_pre_create_allows_creation(repo: TestRepo) {
+    repo.write_project_config(
+        r#"[pre-create]
+naming = "echo '{{ branch }}' | grep -q '^feature'"
+"#,
+    );
+    repo.commit("Add config");
+
+    repo.write_test_config(
+        r#"[projects."repo"]
+approved-commands = ["echo '{{ branch }}' | grep -q '^feature'"]
+"#,
+    );
+
+    snapshot_switch(
+        "pre_create_allows_creation",
+        &repo,
+        &["--create", "feature"],
+    );
+}
+
+#[rstest]
+fn test_pre_create_vetoes_creation(repo: TestRepo) {
+    repo.write_project_config(
+        r#"[pre-create]
+naming = "echo 'branch must start with feature/' >&2; exit 1"
+"#,
+    );
+    repo.commit("Add config");
+
+    repo.write_test_config(
+        r#"[projects."repo"]
+approved-commands = ["echo 'branch must start with feature/' >&2; exit 1"]
+"#,
+    );
+
+    snapshot_switch(
+        "pre_create_vetoes_creation",
+        &repo,
+        &["--create", "bad-name"],
+    );
+
+    // Neither the worktree nor the branch were created
+    let worktrees = repo.git_output(&["worktree", "list"]);
+    assert!(
+        !worktrees.contains("bad-name"),
+        "No worktree should be created, got: {worktrees}"
+    );
+    assert!(
+        repo.git_output(&["branch", "--list", "bad-name"])
+            .is_empty()
+    );
+    let repo_name = repo.root_path().file_name().unwrap().to_string_lossy();
+    let worktree_path = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join(format!("{repo_name}.bad-name"));
+    assert!(!worktree_path.exists());
+}
+
+#[rstest]
+fn test_pre_create_skipped_when_path_exists(repo: TestRepo) {
+    repo.write_project_config(
+        r#"[pre-create]
+marker = "touch pre-create-ran"
+"#,
+    );
+    repo.commit("Add config");
+
+    repo.write_test_config(
+        r#"[projects."repo"]
+approved-commands = ["touch pre-create-ran"]
+"#,
+    );
+
+    let repo_name = repo.root_path().file_name().unwrap().to_string_lossy();
+    let worktree_path = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join(format!("{repo_name}.feature"));
+    fs::create_dir_all(&worktree_path).unwrap();
+
+    // The switch fails on the existing path before any hook runs
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(!repo.root_path().join("pre-create-ran").exists());
+}
+
 // ============================================================================
 // Post-Create Command Tests (sequential, blocking)
 // ============================================================================
@@ -116,6 +213,83 @@ approved-commands = ["exit 1"]
     );
 }
 
+#[rstest]
+fn test_post_create_logged_to_hook_log_dir(repo: TestRepo) {
+    repo.write_project_config(
+        r#"hook-log-dir = "hook-logs"
+
+[post-create]
+build = "echo building"
+check = "echo 'missing dependency' >&2 && exit 1"
+"#,
+    );
+    repo.commit("Add config with hook log dir");
+    repo.write_test_config(
+        r#"[projects."repo"]
+approved-commands = ["echo building", "echo 'missing dependency' >&2 && exit 1"]
+"#,
+    );
+
+    // Output streams to the terminal, and the failure points at the log.
+    // Stdout and stderr come from separate commands so their order is stable.
+    snapshot_switch(
+        "post_create_logged_to_hook_log_dir",
+        &repo,
+        &["--create", "feature"],
+    );
+
+    let log_dir = repo.root_path().join("hook-logs");
+    let build =
+        fs::read_to_string(log_dir.join("feature-project-post-create-build-20250102T000000Z.log"))
+            .unwrap();
+    assert!(build.contains("building"), "{build}");
+    let check =
+        fs::read_to_string(log_dir.join("feature-project-post-create-check-20250102T000000Z.log"))
+            .unwrap();
+    assert!(check.contains("missing dependency"), "{check}");
+}
+
+#[rstest]
+fn test_hook_log_dir_survives_non_utf8_output(repo: TestRepo) {
+    // A non-UTF-8 line followed by more output than a pipe buffer holds
+    // (backslashes escaped for TOML)
+    let noisy = r"printf '\\377\\n'; seq 1 300000";
+    repo.write_project_config(&format!(
+        r#"hook-log-dir = "hook-logs"
+
+[post-create]
+noisy = "{noisy}"
+"#
+    ));
+    repo.commit("Add config with hook log dir");
+    repo.write_test_config(&format!(
+        r#"[projects."repo"]
+approved-commands = ["{noisy}"]
+"#
+    ));
+
+    let output = make_snapshot_cmd(&repo, "switch", &["--create", "feature"], None)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "non-UTF-8 hook output should not kill the hook, got: {:?}",
+        output.status
+    );
+
+    let log = fs::read(
+        repo.root_path()
+            .join("hook-logs/feature-project-post-create-noisy-20250102T000000Z.log"),
+    )
+    .unwrap();
+    let log = String::from_utf8_lossy(&log);
+    assert!(log.contains('\u{FFFD}'), "invalid byte is logged lossily");
+    assert!(
+        log.contains("\n300000"),
+        "output should be logged to the end"
+    );
+}
+
 #[rstest]
 fn test_post_create_template_expansion(repo: TestRepo) {
     // Create project config with template variables
@@ -198,6 +372,103 @@ approved-commands = [
     );
 }
 
+#[rstest]
+fn test_post_create_files(repo: TestRepo) {
+    repo.write_project_config(
+        r#"post-create = "cat config/branch.txt > seen.txt"
+
+[post-create-files]
+"config/branch.txt" = "branch={{ branch }} repo={{ repo }}"
+"kept.txt" = "replaced"
+"replaced.txt" = { template = "new {{ branch | sanitize }}", overwrite = true }
+"#,
+    );
+    fs::write(repo.root_path().join("kept.txt"), "committed\n").unwrap();
+    fs::write(repo.root_path().join("replaced.txt"), "committed\n").unwrap();
+    repo.commit("Add config and tracked files");
+    repo.write_test_config(
+        r#"[projects."repo"]
+approved-commands = [
+    "cat config/branch.txt > seen.txt",
+    "cat > config/branch.txt <<'EOF'\nbranch={{ branch }} repo={{ repo }}\nEOF",
+    "cat > kept.txt <<'EOF'\nreplaced\nEOF",
+    "cat > replaced.txt <<'EOF'\nnew {{ branch | sanitize }}\nEOF",
+]
+"#,
+    );
+
+    snapshot_switch("post_create_files", &repo, &["--create", "feature/x"]);
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature-x");
+    let read = |path: &str| fs::read_to_string(worktree_path.join(path)).unwrap();
+    assert_eq!(read("config/branch.txt"), "branch=feature/x repo=repo");
+    // Written before the post-create hook ran
+    assert_eq!(read("seen.txt"), "branch=feature/x repo=repo");
+    assert_eq!(read("kept.txt"), "committed\n");
+    assert_eq!(read("replaced.txt"), "new feature-x");
+}
+
+#[rstest]
+fn test_post_create_files_need_approval(repo: TestRepo) {
+    repo.write_project_config(
+        r#"[post-create-files]
+".env.local" = "BRANCH={{ branch }}"
+"#,
+    );
+    repo.commit("Add config");
+
+    // Listed in the approval prompt even without any post-create hooks
+    snapshot_switch(
+        "post_create_files_need_approval",
+        &repo,
+        &["--create", "feature"],
+    );
+    assert!(
+        !repo
+            .root_path()
+            .parent()
+            .unwrap()
+            .join("repo.feature/.env.local")
+            .exists()
+    );
+}
+
+#[rstest]
+#[cfg(unix)]
+fn test_post_create_files_refuse_symlinks(repo: TestRepo) {
+    let outside = TempDir::new().unwrap();
+    std::os::unix::fs::symlink(outside.path(), repo.root_path().join("escape")).unwrap();
+    std::os::unix::fs::symlink(
+        outside.path().join("target.txt"),
+        repo.root_path().join("link.txt"),
+    )
+    .unwrap();
+    repo.write_project_config(
+        r#"[post-create-files]
+"escape/new/file.txt" = "x"
+"link.txt" = { template = "x", overwrite = true }
+"#,
+    );
+    repo.commit("Add config and symlinks");
+
+    let mut cmd = repo.wt_command();
+    let output = cmd
+        .args(["switch", "--create", "feature", "--yes"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("path must be inside the worktree"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("it's a symlink"), "{stderr}");
+
+    // Nothing was written or created through the symlinks
+    assert_eq!(fs::read_dir(outside.path()).unwrap().count(), 0);
+}
+
 #[rstest]
 fn test_post_create_default_branch_template(repo: TestRepo) {
     // Create project config with default_branch template variable
@@ -0,0 +1,73 @@
+use crate::common::{TestRepo, make_snapshot_cmd, repo_with_remote};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+/// Create a worktree tracking `origin/<branch>`, then delete that branch on the remote
+fn add_worktree_with_gone_upstream(repo: &mut TestRepo, branch: &str) -> std::path::PathBuf {
+    let path = repo.add_worktree(branch);
+    repo.run_git_in(&path, &["push", "-u", "origin", branch]);
+    repo.run_git(&["push", "origin", "--delete", branch]);
+    repo.run_git(&["fetch", "--prune"]);
+    path
+}
+
+#[rstest]
+fn test_prune_nothing_to_prune(#[from(repo_with_remote)] mut repo: TestRepo) {
+    // Upstream still exists, so nothing is stale
+    let path = repo.add_worktree("feature");
+    repo.run_git_in(&path, &["push", "-u", "origin", "feature"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "prune", &[], None));
+}
+
+#[rstest]
+fn test_prune_dry_run(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let gone = add_worktree_with_gone_upstream(&mut repo, "merged-feature");
+    repo.add_worktree("no-upstream");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "prune", &["--dry-run"], None));
+    assert!(gone.exists(), "--dry-run should not remove anything");
+}
+
+#[rstest]
+fn test_prune_yes(#[from(repo_with_remote)] mut repo: TestRepo) {
+    add_worktree_with_gone_upstream(&mut repo, "merged-feature");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "prune", &["--yes"], None));
+}
+
+#[rstest]
+fn test_prune_skips_current_worktree(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let current = add_worktree_with_gone_upstream(&mut repo, "current-feature");
+    add_worktree_with_gone_upstream(&mut repo, "other-feature");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "prune",
+        &["--dry-run"],
+        Some(&current)
+    ));
+}
+
+#[rstest]
+fn test_prune_missing_directory(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let path = repo.add_worktree("deleted-dir");
+    std::fs::remove_dir_all(&path).unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "prune", &[], None));
+
+    let list = repo.git_output(&["worktree", "list"]);
+    assert!(
+        !list.contains("deleted-dir"),
+        "git metadata should be pruned, got: {list}"
+    );
+}
+
+#[rstest]
+fn test_prune_requires_confirmation(#[from(repo_with_remote)] mut repo: TestRepo) {
+    // No TTY and no --yes: fail rather than hang
+    let gone = add_worktree_with_gone_upstream(&mut repo, "merged-feature");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "prune", &[], None));
+    assert!(gone.exists());
+}
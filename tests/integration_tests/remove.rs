@@ -322,6 +322,33 @@ fn test_remove_by_name_dirty_target(mut repo: TestRepo) {
     assert_cmd_snapshot!(make_snapshot_cmd(&repo, "remove", &["feature-dirty"], None));
 }
 
+#[rstest]
+#[case::force("--force")]
+#[case::yes("--yes")]
+fn test_remove_dirty_target_discards_changes(mut repo: TestRepo, #[case] flag: &str) {
+    let worktree_path = repo.add_worktree("feature-discard");
+    std::fs::write(worktree_path.join("file.txt"), "uncommitted").unwrap();
+    repo.run_git_in(&worktree_path, &["add", "file.txt"]);
+
+    // Without a terminal to confirm on, removal declines
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "feature-discard"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(worktree_path.exists());
+
+    // --force and --yes discard the changes without asking
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", flag, "feature-discard"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert!(!worktree_path.exists());
+}
+
 #[rstest]
 fn test_remove_multiple_worktrees(mut repo: TestRepo) {
     // Create three worktrees
@@ -512,6 +539,34 @@ fn test_remove_no_delete_branch(mut repo: TestRepo) {
     ));
 }
 
+#[rstest]
+fn test_remove_keep_branch_with_force(mut repo: TestRepo) {
+    // Untracked files would block removal without --force
+    let worktree_path = repo.add_worktree("feature-keep-force");
+    std::fs::write(worktree_path.join("build.log"), "artifact").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args([
+            "remove",
+            "--foreground",
+            "--keep-branch",
+            "--force",
+            "feature-keep-force",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    assert!(!worktree_path.exists());
+    let branches = repo
+        .git_command()
+        .args(["branch", "--list", "feature-keep-force"])
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&branches.stdout).contains("feature-keep-force"));
+}
+
 #[rstest]
 fn test_remove_no_delete_branch_unmerged(mut repo: TestRepo) {
     // Create a worktree with an unmerged commit
@@ -1109,6 +1164,33 @@ approved-commands = ["exit 1"]
     );
 }
 
+#[rstest]
+fn test_pre_remove_hook_failure_with_force_continues(mut repo: TestRepo) {
+    repo.write_project_config(r#"pre-remove = "exit 1""#);
+    repo.commit("Add config");
+
+    repo.write_test_config(
+        r#"[projects."repo"]
+approved-commands = ["exit 1"]
+"#,
+    );
+
+    let worktree_path = repo.add_worktree("feature-fail");
+
+    // --force downgrades the hook failure to a warning
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["--foreground", "--force", "feature-fail"],
+        None
+    ));
+
+    assert!(
+        !worktree_path.exists(),
+        "Worktree should be removed despite hook failure with --force"
+    );
+}
+
 #[rstest]
 fn test_pre_remove_hook_not_for_branch_only(repo: TestRepo) {
     // Create a marker file that the hook would create
@@ -1407,3 +1489,122 @@ fn test_remove_path_mismatch_warning_foreground(repo: TestRepo) {
         None
     ));
 }
+
+// PTY-based tests for the uncommitted-changes confirmation prompt
+#[cfg(all(unix, feature = "shell-integration-tests"))]
+mod pty_tests {
+    use crate::common::{TestRepo, configure_pty_command, open_pty, repo};
+    use insta_cmd::get_cargo_bin;
+    use portable_pty::CommandBuilder;
+    use rstest::rstest;
+    use std::io::{Read, Write};
+
+    /// Run `wt remove --foreground <branch>` in a PTY, answering the prompt with `input`
+    fn exec_remove_in_pty(repo: &TestRepo, branch: &str, input: &str) -> (String, i32) {
+        let pair = open_pty();
+
+        let mut cmd = CommandBuilder::new(get_cargo_bin("wt"));
+        cmd.args(["remove", "--foreground", branch]);
+        cmd.cwd(repo.root_path());
+
+        configure_pty_command(&mut cmd);
+        for (key, value) in repo.test_env_vars() {
+            cmd.env(key, value);
+        }
+
+        let mut child = pair.slave.spawn_command(cmd).unwrap();
+        drop(pair.slave);
+
+        let mut reader = pair.master.try_clone_reader().unwrap();
+        let mut writer = pair.master.take_writer().unwrap();
+
+        writer.write_all(input.as_bytes()).unwrap();
+        writer.flush().unwrap();
+        drop(writer);
+
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf).unwrap();
+
+        let exit_status = child.wait().unwrap();
+        (buf, exit_status.exit_code() as i32)
+    }
+
+    #[rstest]
+    fn test_remove_dirty_prompt_accept(mut repo: TestRepo) {
+        let worktree_path = repo.add_worktree("feature-dirty");
+        std::fs::write(worktree_path.join("notes.txt"), "uncommitted").unwrap();
+
+        let (output, exit_code) = exec_remove_in_pty(&repo, "feature-dirty", "y\n");
+
+        assert_eq!(exit_code, 0, "{output}");
+        assert!(output.contains("has uncommitted changes"), "{output}");
+        assert!(output.contains("?? notes.txt"), "{output}");
+        assert!(!worktree_path.exists());
+    }
+
+    #[rstest]
+    fn test_remove_dirty_prompt_decline(mut repo: TestRepo) {
+        let worktree_path = repo.add_worktree("feature-dirty");
+        std::fs::write(worktree_path.join("notes.txt"), "uncommitted").unwrap();
+
+        let (output, exit_code) = exec_remove_in_pty(&repo, "feature-dirty", "n\n");
+
+        assert_ne!(exit_code, 0, "{output}");
+        assert!(output.contains("Remove it and discard them?"), "{output}");
+        assert!(worktree_path.exists());
+    }
+}
+
+#[rstest]
+fn test_remove_all_merged(mut repo: TestRepo) {
+    // Branches with no commits of their own are merged into main
+    let merged = repo.add_worktree("merged-feature");
+    let dirty = repo.add_worktree("dirty-feature");
+    std::fs::write(dirty.join("scratch.txt"), "wip").unwrap();
+    let unmerged = repo.add_worktree("unmerged-feature");
+    repo.commit_in_worktree(&unmerged, "new.txt", "content", "Unmerged work");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["--all-merged", "--yes", "--foreground"],
+        None
+    ));
+    assert!(!merged.exists(), "merged worktree should be removed");
+    assert!(dirty.exists(), "dirty worktree should be skipped");
+    assert!(unmerged.exists(), "unmerged worktree should be kept");
+    let branches = repo.git_output(&["branch", "--format=%(refname:short)"]);
+    assert!(!branches.contains("merged-feature\n"), "{branches}");
+}
+
+#[rstest]
+fn test_remove_all_merged_force_discards_changes(mut repo: TestRepo) {
+    let dirty = repo.add_worktree("dirty-feature");
+    std::fs::write(dirty.join("scratch.txt"), "wip").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--all-merged", "--yes", "--force", "--foreground"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert!(!dirty.exists());
+}
+
+#[rstest]
+fn test_remove_all_merged_requires_confirmation(mut repo: TestRepo) {
+    // No TTY and no --yes: fail rather than hang
+    let merged = repo.add_worktree("merged-feature");
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--all-merged"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("--yes"),
+        "{output:?}"
+    );
+    assert!(merged.exists());
+}
@@ -0,0 +1,130 @@
+use crate::common::{
+    TestRepo, configure_directive_file, directive_file, make_snapshot_cmd, repo, repo_with_remote,
+    wt_command,
+};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_rename_moves_worktree(mut repo: TestRepo) {
+    let old_path = repo.add_worktree("feature");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "rename",
+        &["feature", "renamed"],
+        None
+    ));
+
+    let new_path = old_path.with_file_name(
+        old_path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .replace("feature", "renamed"),
+    );
+    assert!(!old_path.exists(), "Old worktree directory should be gone");
+    assert!(new_path.exists(), "Worktree should move to {new_path:?}");
+    let branch = repo.git_output(&["-C", new_path.to_str().unwrap(), "branch", "--show-current"]);
+    assert_eq!(branch.trim(), "renamed");
+}
+
+#[rstest]
+fn test_rename_branch_without_worktree(repo: TestRepo) {
+    repo.run_git(&["branch", "no-worktree"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "rename",
+        &["no-worktree", "still-no-worktree"],
+        None
+    ));
+    assert!(
+        repo.git_output(&["branch", "--list", "still-no-worktree"])
+            .contains("still-no-worktree")
+    );
+}
+
+#[rstest]
+fn test_rename_from_inside_worktree_changes_directory(mut repo: TestRepo) {
+    let old_path = repo.add_worktree("feature");
+    std::fs::create_dir(old_path.join("src")).unwrap();
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["rename", "feature", "renamed"])
+        .current_dir(old_path.join("src"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "rename should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    assert!(
+        directives.contains("renamed/src'"),
+        "Should cd into the same subdirectory of the moved worktree, got: {directives}"
+    );
+}
+
+#[rstest]
+fn test_rename_default_branch_fails(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "rename", &["main", "trunk"], None));
+}
+
+#[rstest]
+fn test_rename_target_exists_fails(mut repo: TestRepo) {
+    let path = repo.add_worktree("feature");
+    repo.run_git(&["branch", "taken"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "rename",
+        &["feature", "taken"],
+        None
+    ));
+    assert!(
+        path.exists(),
+        "Nothing should move when the rename is refused"
+    );
+}
+
+#[rstest]
+fn test_rename_updates_upstream(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let path = repo.add_worktree("feature");
+    repo.run_git_in(&path, &["push", "-u", "origin", "feature"]);
+    repo.run_git_in(&path, &["push", "origin", "feature:renamed"]);
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .args(["rename", "feature", "renamed"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let upstream = repo.git_output(&["rev-parse", "--abbrev-ref", "renamed@{u}"]);
+    assert_eq!(upstream.trim(), "origin/renamed");
+}
+
+#[rstest]
+fn test_rename_keeps_upstream_without_remote_branch(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let path = repo.add_worktree("feature");
+    repo.run_git_in(&path, &["push", "-u", "origin", "feature"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "rename",
+        &["feature", "renamed"],
+        None
+    ));
+
+    let upstream = repo.git_output(&["rev-parse", "--abbrev-ref", "renamed@{u}"]);
+    assert_eq!(upstream.trim(), "origin/feature");
+}
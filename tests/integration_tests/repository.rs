@@ -334,3 +334,205 @@ fn test_set_config() {
     let value = repository.get_config("test.setting").unwrap();
     assert_eq!(value, Some("new-value".to_string()));
 }
+
+// =============================================================================
+// load_project_config() discovery tests
+// =============================================================================
+
+#[test]
+fn test_load_project_config_from_subdirectory() {
+    let repo = TestRepo::new();
+    repo.write_project_config("pre-merge = \"cargo test\"\n");
+    let nested = repo.root_path().join("src/deeply/nested");
+    fs::create_dir_all(&nested).unwrap();
+
+    let repository = Repository::at(nested);
+    let config = repository.load_project_config().unwrap();
+    assert!(config.is_some_and(|c| c.hooks.pre_merge.is_some()));
+}
+
+#[test]
+fn test_load_project_config_nearest_directory_wins() {
+    let repo = TestRepo::new();
+    repo.write_project_config("pre-merge = \"cargo test\"\n");
+    let package = repo.root_path().join("packages/app");
+    fs::create_dir_all(package.join(".config")).unwrap();
+    fs::write(
+        package.join(".config/wt.toml"),
+        "post-create = \"npm install\"\n",
+    )
+    .unwrap();
+
+    fs::create_dir_all(package.join("src")).unwrap();
+    let repository = Repository::at(package.join("src"));
+    let config = repository.load_project_config().unwrap().unwrap();
+    assert!(config.hooks.post_create.is_some());
+    assert!(config.hooks.pre_merge.is_none());
+}
+
+#[test]
+fn test_load_project_config_falls_back_to_main_worktree() {
+    let mut repo = TestRepo::new();
+    repo.commit("initial");
+    let feature = repo.add_worktree("feature");
+    // Written after the worktree was created, so only the main worktree has it
+    repo.write_project_config("pre-merge = \"cargo test\"\n");
+
+    let repository = Repository::at(feature);
+    let config = repository.load_project_config().unwrap();
+    assert!(config.is_some_and(|c| c.hooks.pre_merge.is_some()));
+}
+
+#[test]
+fn test_load_project_config_root_dotfile() {
+    let repo = TestRepo::new();
+    fs::write(
+        repo.root_path().join(".wt.toml"),
+        "pre-merge = \"cargo test\"\n",
+    )
+    .unwrap();
+
+    let repository = Repository::at(repo.root_path().to_path_buf());
+    let config = repository.load_project_config().unwrap();
+    assert!(config.is_some_and(|c| c.hooks.pre_merge.is_some()));
+}
+
+#[test]
+fn test_load_project_config_root_plain_file() {
+    let repo = TestRepo::new();
+    fs::write(
+        repo.root_path().join("wt.toml"),
+        "pre-merge = \"cargo test\"\n",
+    )
+    .unwrap();
+
+    let repository = Repository::at(repo.root_path().to_path_buf());
+    let config = repository.load_project_config().unwrap();
+    assert!(config.is_some_and(|c| c.hooks.pre_merge.is_some()));
+}
+
+#[test]
+fn test_load_project_config_precedence() {
+    let repo = TestRepo::new();
+    fs::write(repo.root_path().join("wt.toml"), "pre-commit = \"plain\"\n").unwrap();
+    fs::write(
+        repo.root_path().join(".wt.toml"),
+        "pre-merge = \"dotfile\"\n",
+    )
+    .unwrap();
+
+    // .wt.toml beats wt.toml
+    let config = Repository::at(repo.root_path().to_path_buf())
+        .load_project_config()
+        .unwrap()
+        .unwrap();
+    assert!(config.hooks.pre_merge.is_some());
+    assert!(config.hooks.pre_commit.is_none());
+
+    // .config/wt.toml beats both
+    repo.write_project_config("post-create = \"npm install\"\n");
+    let config = Repository::at(repo.root_path().to_path_buf())
+        .load_project_config()
+        .unwrap()
+        .unwrap();
+    assert!(config.hooks.post_create.is_some());
+    assert!(config.hooks.pre_merge.is_none());
+}
+
+#[test]
+fn test_load_project_config_include() {
+    let repo = TestRepo::new();
+    fs::write(
+        repo.root_path().join("common-wt.toml"),
+        r#"post-create = "npm install"
+pre-merge = "npm test"
+
+[env]
+SHARED = "base"
+"#,
+    )
+    .unwrap();
+    repo.write_project_config(
+        r#"include = ["../common-wt.toml"]
+pre-merge = "cargo test"
+
+[env]
+LOCAL = "project"
+"#,
+    );
+
+    let config = Repository::at(repo.root_path().to_path_buf())
+        .load_project_config()
+        .unwrap()
+        .unwrap();
+    // Inherited from the included file
+    let post_create = config.hooks.post_create.unwrap();
+    assert_eq!(post_create.commands()[0].template, "npm install");
+    // Overridden by the including file
+    let pre_merge = config.hooks.pre_merge.unwrap();
+    assert_eq!(pre_merge.commands().len(), 1);
+    assert_eq!(pre_merge.commands()[0].template, "cargo test");
+    // Tables merge key by key
+    let env = config.env.unwrap();
+    assert_eq!(env["SHARED"], "base");
+    assert_eq!(env["LOCAL"], "project");
+}
+
+#[test]
+fn test_load_project_config_include_cycle() {
+    let repo = TestRepo::new();
+    fs::write(
+        repo.root_path().join("common-wt.toml"),
+        "include = [\".config/wt.toml\"]\n",
+    )
+    .unwrap();
+    repo.write_project_config("include = [\"../common-wt.toml\"]\n");
+
+    let err = Repository::at(repo.root_path().to_path_buf())
+        .load_project_config()
+        .unwrap_err();
+    let err = format!("{err:#}");
+    assert!(err.contains("Cyclic include"), "{err}");
+    assert!(err.contains("common-wt.toml"), "{err}");
+}
+
+#[test]
+fn test_load_project_config_not_found() {
+    let repo = TestRepo::new();
+    let nested = repo.root_path().join("src");
+    fs::create_dir_all(&nested).unwrap();
+
+    let repository = Repository::at(nested);
+    assert!(repository.load_project_config().unwrap().is_none());
+}
+
+// =============================================================================
+// Per-instance caching of repeated queries
+// =============================================================================
+
+#[test]
+fn test_cached_queries_live_as_long_as_the_instance() {
+    let repo = TestRepo::new();
+    repo.commit("initial");
+    repo.run_git(&["branch", "other"]);
+
+    let repository = Repository::at(repo.root_path().to_path_buf());
+    let root = repository.worktree_root().unwrap();
+    assert_eq!(repository.current_branch().unwrap(), Some("main"));
+    assert_eq!(repository.default_branch().unwrap(), "main");
+
+    // Change everything behind the instance's back
+    repo.run_git(&["switch", "-q", "other"]);
+    repo.run_git(&["config", "worktrunk.default-branch", "other"]);
+
+    // The same instance answers from its cache without asking git again
+    assert!(std::ptr::eq(repository.worktree_root().unwrap(), root));
+    assert_eq!(repository.current_branch().unwrap(), Some("main"));
+    assert_eq!(repository.default_branch().unwrap(), "main");
+
+    // A new instance starts with an empty cache
+    let fresh = Repository::at(repo.root_path().to_path_buf());
+    assert_eq!(fresh.worktree_root().unwrap(), root);
+    assert_eq!(fresh.current_branch().unwrap(), Some("other"));
+    assert_eq!(fresh.default_branch().unwrap(), "other");
+}
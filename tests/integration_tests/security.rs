@@ -354,6 +354,21 @@ fn test_branch_name_with_cd_directive_not_executed(repo: TestRepo) {
 
         // Branch name should appear in success message, but not as a separate directive
         assert_cmd_snapshot!(cmd);
+
+        // The only cd goes to the new worktree, never to the path in the branch name
+        let directives = std::fs::read_to_string(&directive_path).unwrap();
+        let cds: Vec<_> = directives
+            .lines()
+            .filter(|line| line.starts_with("cd "))
+            .collect();
+        assert_eq!(cds.len(), 1, "{directives}");
+        assert!(cds[0].contains("__WORKTRUNK_CD__"), "{directives}");
+        assert!(
+            !directives
+                .lines()
+                .any(|line| matches!(line.trim(), "cd /tmp" | "cd '/tmp'")),
+            "{directives}"
+        );
     });
 }
 
@@ -139,7 +139,7 @@ fn test_switch_non_tty_shows_hint(repo: TestRepo) {
 }
 
 ///
-/// When SHELL is set to an unsupported shell (like tcsh), we should:
+/// When SHELL is set to an unsupported shell (like ksh), we should:
 /// - Show a hint that the shell is not supported
 /// - List the supported shells
 #[rstest]
@@ -148,7 +148,7 @@ fn test_switch_unsupported_shell_shows_hint(repo: TestRepo) {
 
     // Run with an unsupported shell
     let mut cmd = repo.wt_command();
-    cmd.env("SHELL", "/bin/tcsh");
+    cmd.env("SHELL", "/bin/ksh");
 
     let output = cmd
         .args(["switch", "--create", "feature"])
@@ -161,7 +161,7 @@ fn test_switch_unsupported_shell_shows_hint(repo: TestRepo) {
     // Should show unsupported shell message
     let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(
-        stderr.contains("not yet supported for tcsh"),
+        stderr.contains("not yet supported for ksh"),
         "Should show unsupported shell message: {stderr}"
     );
     assert!(
@@ -0,0 +1,99 @@
+use crate::common::{TestRepo, make_snapshot_cmd, repo, repo_with_remote, wt_command};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_status_no_upstream(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "status", &[], None));
+}
+
+#[rstest]
+fn test_status_ahead_and_dirty(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let path = repo.add_worktree("feature");
+    repo.run_git_in(&path, &["push", "-u", "origin", "feature"]);
+    repo.run_git_in(&path, &["commit", "--allow-empty", "-m", "Local work"]);
+    std::fs::write(path.join("scratch.txt"), "wip").unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "status", &[], Some(&path)));
+}
+
+#[rstest]
+fn test_status_detached_head(repo: TestRepo) {
+    repo.run_git(&["checkout", "--detach", "HEAD"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "status", &[], None));
+}
+
+#[rstest]
+fn test_status_json(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.write_project_config(
+        r#"post-create = "npm install"
+pre-merge = "cargo test"
+"#,
+    );
+    repo.commit("Add project config");
+    let path = repo.add_worktree("feature");
+    repo.run_git_in(&path, &["push", "-u", "origin", "feature"]);
+    repo.run_git(&["push", "origin", "main:feature"]);
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .args(["status", "--format=json"])
+        .current_dir(&path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["branch"], "feature");
+    assert_eq!(json["detached"], false);
+    assert_eq!(json["dirty"], false);
+    assert_eq!(json["upstream"]["name"], "origin/feature");
+    assert_eq!(json["upstream"]["ahead"], 0);
+    assert_eq!(json["upstream"]["behind"], 0);
+    assert_eq!(json["hooks"]["post-create"], true);
+    assert_eq!(json["hooks"]["pre-merge"], true);
+    assert_eq!(json["hooks"]["pre-create"], false);
+    assert_eq!(json["hooks"]["post-merge"], false);
+    assert_eq!(
+        json["hook_sources"]["project"],
+        serde_json::json!(["post-create", "pre-merge"])
+    );
+    assert_eq!(json["hook_sources"]["user"], serde_json::json!([]));
+}
+
+#[rstest]
+fn test_status_json_detached(repo: TestRepo) {
+    repo.run_git(&["checkout", "--detach", "HEAD"]);
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .args(["status", "--format=json"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["branch"], serde_json::Value::Null);
+    assert_eq!(json["detached"], true);
+    assert_eq!(json["upstream"], serde_json::Value::Null);
+}
+
+#[rstest]
+fn test_status_hooks_configured_and_unset(repo: TestRepo) {
+    repo.write_project_config(
+        r#"post-create = "npm install"
+pre-merge = "cargo test"
+"#,
+    );
+    repo.commit("Add project config");
+    repo.write_test_config(
+        r#"post-start = "echo started"
+"#,
+    );
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "status", &[], None));
+}
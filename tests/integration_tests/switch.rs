@@ -61,15 +61,23 @@ fn test_switch_create_new_branch(repo: TestRepo) {
 }
 
 #[rstest]
-fn test_switch_create_existing_branch_error(mut repo: TestRepo) {
+fn test_switch_create_existing_branch(mut repo: TestRepo) {
     // Create a branch first
     repo.add_worktree("feature-y");
 
-    // Try to create it again - should error
+    // --create on an existing branch switches to its worktree
+    snapshot_switch("switch_create_existing", &repo, &["--create", "feature-y"]);
+}
+
+#[rstest]
+fn test_switch_create_existing_branch_no_worktree(repo: TestRepo) {
+    repo.run_git(&["branch", "feature-nw"]);
+
+    // --create on a branch without a worktree creates the worktree for that branch
     snapshot_switch(
-        "switch_create_existing_error",
+        "switch_create_existing_no_worktree",
         &repo,
-        &["--create", "feature-y"],
+        &["--create", "feature-nw", "--base", "main"],
     );
 }
 
@@ -392,6 +400,60 @@ fn test_switch_no_config_commands_with_yes(repo: TestRepo) {
         &["--create", "yes-no-hooks", "--yes", "--no-verify"],
     );
 }
+
+#[rstest]
+fn test_switch_no_hooks_alias_skips_post_create(repo: TestRepo) {
+    repo.write_project_config(r#"post-create = "touch post-create-ran""#);
+    repo.commit("Add config");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "throwaway", "--yes", "--no-hooks"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // The worktree is created, but its post-create setup never ran
+    let worktree_path = repo.root_path().parent().unwrap().join(format!(
+        "{}.throwaway",
+        repo.root_path().file_name().unwrap().to_str().unwrap()
+    ));
+    assert!(worktree_path.is_dir());
+    assert!(!worktree_path.join("post-create-ran").exists());
+}
+/// Switching to a remote-only branch creates a worktree, so its post-create
+/// commands need approval just like with `--create`
+#[rstest]
+fn test_switch_remote_branch_approves_post_create(#[from(repo_with_remote)] repo: TestRepo) {
+    repo.write_project_config(r#"post-create = "touch post-create-ran""#);
+    repo.commit("Add config");
+    repo.run_git(&["branch", "remote-feature"]);
+    repo.run_git(&["push", "origin", "remote-feature"]);
+    repo.run_git(&["branch", "-D", "remote-feature"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "remote-feature"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "{stderr}");
+    assert!(stderr.contains("Cannot prompt for approval"), "{stderr}");
+    assert!(stderr.contains("post-create-ran"), "{stderr}");
+
+    let worktree_path = repo.root_path().parent().unwrap().join(format!(
+        "{}.remote-feature",
+        repo.root_path().file_name().unwrap().to_str().unwrap()
+    ));
+    assert!(!worktree_path.join("post-create-ran").exists());
+}
+
 // Branch inference and special branch tests
 #[rstest]
 fn test_switch_create_no_remote(repo: TestRepo) {
@@ -424,10 +486,278 @@ fn test_switch_primary_on_different_branch(mut repo: TestRepo) {
 
 #[rstest]
 fn test_switch_previous_branch_no_history(repo: TestRepo) {
-    // No checkout history, so wt switch - should fail with helpful error
+    // No switch history, so wt switch - prints a hint instead of failing
     snapshot_switch("switch_previous_branch_no_history", &repo, &["-"]);
 }
 
+#[rstest]
+fn test_switch_fuzzy_single_match(mut repo: TestRepo) {
+    repo.add_worktree("feature/login");
+    repo.add_worktree("fix-typo");
+
+    // "login" isn't a branch but only one worktree branch contains it; the
+    // directive cds to the matched worktree
+    snapshot_switch_with_directive_file("switch_fuzzy_single_match", &repo, &["login"]);
+}
+
+#[rstest]
+fn test_switch_fuzzy_ambiguous(mut repo: TestRepo) {
+    repo.add_worktree("feature/login");
+    repo.add_worktree("feature/logout");
+
+    snapshot_switch("switch_fuzzy_ambiguous", &repo, &["log"]);
+}
+
+#[rstest]
+fn test_switch_fuzzy_disabled_with_exact(mut repo: TestRepo) {
+    repo.add_worktree("feature/login");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--exact", "login"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("login"));
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("feature/login"));
+}
+
+#[rstest]
+fn test_switch_print_path(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature/login");
+    let (directive_path, _guard) = directive_file();
+
+    // Even with shell integration active, only the path is printed: no directive
+    let mut cmd = repo.wt_command();
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "--exact", "feature/login", "--print-path"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let printed = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        dunce::canonicalize(printed.trim_end_matches('\n')).unwrap(),
+        dunce::canonicalize(&feature_path).unwrap()
+    );
+    assert!(
+        !String::from_utf8_lossy(&output.stderr).contains("Switched"),
+        "{output:?}"
+    );
+    assert_eq!(std::fs::read_to_string(&directive_path).unwrap(), "");
+}
+
+#[rstest]
+fn test_switch_error_format_json(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "nonexistent", "--error-format", "json"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let json: serde_json::Value = serde_json::from_str(stderr.trim())
+        .unwrap_or_else(|e| panic!("stderr should be one JSON object ({e}): {stderr}"));
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "error": {
+                "kind": "invalid-reference",
+                "message": "Branch nonexistent not found",
+            }
+        })
+    );
+}
+
+#[rstest]
+fn test_switch_with_repo_flag(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    let (directive_path, _guard) = directive_file();
+    let elsewhere = TempDir::new().unwrap();
+
+    // Run from outside the repo; the cd still targets its worktree
+    let mut cmd = repo.wt_command();
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args([
+            "--repo",
+            repo.root_path().to_str().unwrap(),
+            "switch",
+            "feature",
+        ])
+        .current_dir(elsewhere.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let directive = std::fs::read_to_string(&directive_path).unwrap();
+    let cd_path = directive
+        .trim()
+        .strip_prefix("cd '")
+        .and_then(|rest| rest.strip_suffix('\''))
+        .unwrap_or_else(|| panic!("unexpected directive: {directive}"));
+    assert_eq!(
+        dunce::canonicalize(cd_path).unwrap(),
+        dunce::canonicalize(&feature_path).unwrap()
+    );
+}
+
+#[rstest]
+fn test_switch_print_path_without_worktree(repo: TestRepo) {
+    repo.run_git(&["branch", "no-worktree"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "no-worktree", "--print-path"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No worktree found for branch"));
+}
+
+#[rstest]
+fn test_switch_porcelain(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature/login");
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = repo.wt_command();
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "--exact", "feature/login", "--porcelain"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    // Exactly one versioned line: `wt-directive:1 cd <path>`, path unquoted
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let path = stdout
+        .strip_prefix("wt-directive:1 cd ")
+        .and_then(|rest| rest.strip_suffix('\n'))
+        .unwrap_or_else(|| panic!("unexpected porcelain output: {stdout:?}"));
+    assert!(!path.contains('\n'), "{stdout:?}");
+    assert_eq!(
+        dunce::canonicalize(path).unwrap(),
+        dunce::canonicalize(&feature_path).unwrap()
+    );
+
+    // The shell wrapper's directive file is still written in the legacy format
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    assert!(directives.starts_with("cd '"), "{directives}");
+}
+
+#[rstest]
+fn test_switch_porcelain_without_shell_integration(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "feature", "--porcelain"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert!(
+        String::from_utf8_lossy(&output.stdout).starts_with("wt-directive:1 cd "),
+        "{output:?}"
+    );
+    // The caller changes directory, so there's nothing to warn about
+    assert!(
+        !String::from_utf8_lossy(&output.stderr)
+            .to_lowercase()
+            .contains("cannot change directory"),
+        "{output:?}"
+    );
+}
+
+#[rstest]
+fn test_switch_print_path_conflicts_with_create(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "new", "--print-path"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be used with"));
+}
+
+#[rstest]
+fn test_switch_detach(repo: TestRepo) {
+    snapshot_switch_with_directive_file("switch_detach_created", &repo, &["--detach", "HEAD"]);
+
+    // The scratch worktree is detached at HEAD's commit
+    let head = repo.git_output(&["rev-parse", "HEAD"]);
+    let porcelain = repo.git_output(&["worktree", "list", "--porcelain"]);
+    let scratch = porcelain
+        .split("\n\n")
+        .find(|entry| entry.lines().any(|line| line == "detached"))
+        .expect("detached worktree should exist");
+    assert!(scratch.contains(&format!("HEAD {head}")), "{porcelain}");
+
+    // Same commit again (by another name) reuses the worktree
+    snapshot_switch_with_directive_file("switch_detach_reused", &repo, &["--detach", "main"]);
+    let porcelain = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert_eq!(
+        porcelain.lines().filter(|line| *line == "detached").count(),
+        1,
+        "{porcelain}"
+    );
+}
+
+#[rstest]
+fn test_switch_detach_unresolvable_ref(repo: TestRepo) {
+    snapshot_switch(
+        "switch_detach_unresolvable_ref",
+        &repo,
+        &["--detach", "no-such-ref"],
+    );
+}
+
+#[rstest]
+fn test_switch_create_branch_from_tag(repo: TestRepo) {
+    let tagged = repo.head_sha();
+    repo.run_git(&["tag", "v1.0"]);
+    repo.commit("After the release");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "from-tag", "--branch-from", "v1.0"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    // Started at the tag, not the default branch's newer HEAD
+    assert_eq!(repo.git_output(&["rev-parse", "from-tag"]), tagged);
+}
+
+#[rstest]
+fn test_switch_branch_from_errors(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.run_git(&["tag", "v1.0"]);
+
+    // The worktree already exists, so there's nothing to branch
+    snapshot_switch(
+        "switch_branch_from_existing",
+        &repo,
+        &["--create", "feature", "--branch-from", "v1.0"],
+    );
+    // Without --create, the flag has nothing to apply to
+    snapshot_switch(
+        "switch_branch_from_without_create",
+        &repo,
+        &["new-branch", "--branch-from", "v1.0"],
+    );
+    // The ref is checked before anything is created
+    snapshot_switch(
+        "switch_branch_from_unresolvable_ref",
+        &repo,
+        &["--create", "new-branch", "--branch-from", "no-such-ref"],
+    );
+    assert!(!repo.root_path().with_file_name("repo.new-branch").exists());
+}
+
 #[rstest]
 fn test_switch_main_branch(repo: TestRepo) {
     // Create a feature branch
@@ -743,9 +1073,19 @@ fn test_switch_ping_pong_realistic(repo: TestRepo) {
 }
 
 #[rstest]
-fn test_switch_missing_argument_shows_hints(repo: TestRepo) {
-    // Run switch with no arguments - should show clap error plus hints
-    snapshot_switch("switch_missing_argument_hints", &repo, &[]);
+fn test_switch_without_branch_lists_worktrees(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    // No terminal on stdin: print the worktrees instead of opening the picker
+    snapshot_switch("switch_without_branch_lists_worktrees", &repo, &[]);
+}
+
+#[rstest]
+fn test_switch_list_conflicts_with_branch(repo: TestRepo) {
+    snapshot_switch(
+        "switch_list_conflicts_with_branch",
+        &repo,
+        &["--list", "main"],
+    );
 }
 
 ///
@@ -797,7 +1137,7 @@ fn test_switch_execute_stdin_inheritance(repo: TestRepo) {
 fn test_switch_outside_git_repo(temp_home: TempDir) {
     let temp_dir = tempfile::tempdir().unwrap();
 
-    // Run wt switch --create outside a git repo - should show "Failed to switch worktree" context
+    // Run wt switch --create outside a git repo - should fail before touching git
     let settings = setup_home_snapshot_settings(&temp_home);
     settings.bind(|| {
         let mut cmd = wt_command();
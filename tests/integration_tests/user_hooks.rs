@@ -305,7 +305,7 @@ check = "echo 'USER_PRE_MERGE' > user_premerge_marker.txt"
         Some(&feature_wt),
     );
 
-    // User hook should NOT have run (--no-verify skips all hooks)
+    // User hook should NOT have run (--no-verify skips pre-merge hooks)
     let marker_file = feature_wt.join("user_premerge_marker.txt");
     assert!(
         !marker_file.exists(),
@@ -660,6 +660,65 @@ vars = "echo 'repo={{ repo }} branch={{ branch }}' > template_vars.txt"
     );
 }
 
+#[rstest]
+fn test_user_hook_env_variables(repo: TestRepo) {
+    // Both foreground and background hooks get WT_* variables without templating
+    repo.write_test_config(
+        r#"[post-create]
+env = "echo \"$WT_BRANCH|$WT_WORKTREE|$WT_REPO_ROOT|${WT_TARGET_BRANCH-unset}\" > env_vars.txt"
+
+[post-start]
+env = "echo \"$WT_BRANCH\" > bg_env_vars.txt"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    let contents = fs::read_to_string(worktree_path.join("env_vars.txt")).unwrap();
+    let fields: Vec<&str> = contents.trim().split('|').collect();
+    assert_eq!(fields[0], "feature");
+    assert!(
+        fields[1].ends_with("repo.feature"),
+        "WT_WORKTREE: {}",
+        fields[1]
+    );
+    assert!(fields[2].ends_with("repo"), "WT_REPO_ROOT: {}", fields[2]);
+    assert_eq!(fields[3], "unset", "No target branch outside merge hooks");
+
+    let bg_marker = worktree_path.join("bg_env_vars.txt");
+    wait_for_file_content(&bg_marker);
+    assert_eq!(fs::read_to_string(&bg_marker).unwrap().trim(), "feature");
+}
+
+#[rstest]
+fn test_user_merge_hook_target_branch_env(mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "feature content", "Add feature");
+
+    repo.write_test_config(
+        r#"[pre-merge]
+env = "echo \"$WT_BRANCH -> $WT_TARGET_BRANCH\" > merge_env.txt"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--yes", "--no-remove"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let contents = fs::read_to_string(feature_wt.join("merge_env.txt")).unwrap();
+    assert_eq!(contents.trim(), "feature -> main");
+}
+
 // ============================================================================
 // Combined User and Project Hooks Tests
 // ============================================================================
@@ -730,6 +789,35 @@ fn test_standalone_hook_post_create(repo: TestRepo) {
     assert!(content.contains("STANDALONE_POST_CREATE"));
 }
 
+#[rstest]
+fn test_standalone_hook_post_create_dry_run(repo: TestRepo) {
+    // Unapproved project hook: dry run needs no approval and runs nothing
+    repo.write_project_config(
+        r#"[post-create]
+install = "echo '{{ branch }} {{ env }}' > hook_ran.txt"
+"#,
+    );
+    repo.write_test_config(
+        r#"[post-create]
+log = "echo 'worktree: {{ worktree_path }}' > user_ran.txt"
+"#,
+    );
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(
+            &repo,
+            "hook",
+            &["post-create", "--dry-run", "--var", "env=staging"],
+            None,
+        );
+        assert_cmd_snapshot!("standalone_hook_post_create_dry_run", cmd);
+    });
+
+    assert!(!repo.root_path().join("hook_ran.txt").exists());
+    assert!(!repo.root_path().join("user_ran.txt").exists());
+}
+
 #[rstest]
 fn test_standalone_hook_post_start(repo: TestRepo) {
     // Write project config with post-start hook
@@ -783,6 +871,139 @@ fn test_standalone_hook_post_start_foreground(repo: TestRepo) {
     );
 }
 
+#[rstest]
+fn test_standalone_hook_post_start_parallel(repo: TestRepo) {
+    repo.write_project_config(
+        r#"post-start-parallelism = 2
+
+[post-start]
+build = "echo 'BUILD_OUT' && echo 'marker' > build.txt"
+lint = "echo 'LINT_OUT' && echo 'marker' > lint.txt"
+"#,
+    );
+
+    let mut cmd = crate::common::wt_command();
+    cmd.current_dir(repo.root_path());
+    cmd.env("WORKTRUNK_CONFIG_PATH", repo.test_config_path());
+    cmd.args(["hook", "post-start", "--yes", "--parallel"]);
+
+    let output = cmd.output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "wt hook post-start --parallel should succeed, got: {stderr}"
+    );
+
+    // Both commands complete before wt exits
+    assert!(repo.root_path().join("build.txt").exists());
+    assert!(repo.root_path().join("lint.txt").exists());
+
+    // Each output line is prefixed with its command
+    assert!(
+        stderr.contains("[project:build]") && stderr.contains("BUILD_OUT"),
+        "build output should be prefixed, got: {stderr}"
+    );
+    assert!(
+        stderr.contains("[project:lint]") && stderr.contains("LINT_OUT"),
+        "lint output should be prefixed, got: {stderr}"
+    );
+}
+
+#[rstest]
+fn test_standalone_hook_post_start_parallel_non_utf8_output(repo: TestRepo) {
+    // A non-UTF-8 line followed by more output than a pipe buffer holds
+    repo.write_project_config(
+        r#"[post-start]
+noisy = "printf '\\377\\n'; seq 1 300000 && echo 'marker' > noisy.txt"
+"#,
+    );
+
+    let mut cmd = crate::common::wt_command();
+    cmd.current_dir(repo.root_path());
+    cmd.env("WORKTRUNK_CONFIG_PATH", repo.test_config_path());
+    cmd.args(["hook", "post-start", "--yes", "--parallel"]);
+
+    let output = cmd.output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "non-UTF-8 hook output should not kill the hook, got: {:?}",
+        output.status
+    );
+    assert!(repo.root_path().join("noisy.txt").exists());
+    assert!(
+        stderr.contains("300000"),
+        "output should be read to the end"
+    );
+}
+
+#[rstest]
+fn test_standalone_hook_post_start_parallel_failure(repo: TestRepo) {
+    repo.write_project_config(
+        r#"[post-start]
+fail = "exit 3"
+ok = "echo 'marker' > ok.txt"
+"#,
+    );
+
+    let mut cmd = crate::common::wt_command();
+    cmd.current_dir(repo.root_path());
+    cmd.env("WORKTRUNK_CONFIG_PATH", repo.test_config_path());
+    cmd.args(["hook", "post-start", "--yes", "--parallel"]);
+
+    let output = cmd.output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !output.status.success(),
+        "wt hook post-start --parallel should fail when a command fails"
+    );
+    assert_eq!(output.status.code(), Some(3));
+
+    // Other commands still run to completion
+    assert!(repo.root_path().join("ok.txt").exists());
+    assert!(
+        stderr.contains("fail"),
+        "failure should name the command, got: {stderr}"
+    );
+}
+
+#[rstest]
+fn test_standalone_hook_invalid_project_config(repo: TestRepo) {
+    repo.write_project_config(
+        r#"post-start-parallelism = 0
+post-creat = "npm install"
+
+[post-start]
+server = ""
+"#,
+    );
+
+    let mut cmd = crate::common::wt_command();
+    cmd.current_dir(repo.root_path());
+    cmd.env("WORKTRUNK_CONFIG_PATH", repo.test_config_path());
+    cmd.args(["hook", "post-start", "--yes", "--foreground"]);
+
+    let output = cmd.output().unwrap();
+    assert!(
+        !output.status.success(),
+        "wt hook should fail on an invalid project config"
+    );
+
+    // All problems are reported together
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    for expected in [
+        "3 problems",
+        "Unknown key `post-creat`",
+        "`post-start` command `server` is empty",
+        "`post-start-parallelism` must be at least 1",
+    ] {
+        assert!(
+            stderr.contains(expected),
+            "missing {expected:?} in: {stderr}"
+        );
+    }
+}
+
 #[rstest]
 fn test_standalone_hook_post_start_no_background_deprecated(repo: TestRepo) {
     // Test that --no-background still works but shows deprecation warning
@@ -1279,20 +1500,31 @@ fn test_var_flag_invalid_format_fails() {
     );
 }
 
-#[test]
-fn test_var_flag_unknown_variable_fails() {
-    // Test that unknown variable names are rejected
-    let output = std::process::Command::new(env!("CARGO_BIN_EXE_wt"))
-        .args(["hook", "post-create", "--var", "custom_var=value"])
+#[rstest]
+fn test_var_flag_custom_variable(repo: TestRepo) {
+    // Keys that aren't built-in variables are injected into the template context
+    repo.write_test_config(
+        r#"[post-create]
+test = "echo '{{ custom_var }}' > custom_output.txt"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["hook", "post-create", "--yes", "--var", "custom_var=value"])
         .output()
-        .expect("Failed to run wt");
+        .expect("Failed to run wt hook");
 
-    assert!(!output.status.success(), "Unknown variable should fail");
+    assert!(
+        output.status.success(),
+        "Custom variable should be accepted"
+    );
 
-    let stderr = String::from_utf8_lossy(&output.stderr);
+    let output_file = repo.root_path().join("custom_output.txt");
+    let contents = std::fs::read_to_string(&output_file).expect("Should have created output file");
     assert!(
-        stderr.contains("unknown variable"),
-        "Error should mention unknown variable, got: {stderr}"
+        contents.contains("value"),
+        "Custom variable should be expanded, got: {contents}"
     );
 }
 
@@ -1359,3 +1591,48 @@ test = "echo '{{ main_worktree }}' > alias_output.txt"
         "Deprecated alias should be overridden, got: {contents}"
     );
 }
+
+// ============================================================================
+// Hook Shell Tests
+// ============================================================================
+
+/// Arrays are a bash feature; `sh` (dash on many systems) rejects this syntax.
+#[cfg(unix)]
+#[rstest]
+fn test_hook_shell_runs_hooks_with_configured_shell(repo: TestRepo) {
+    repo.write_test_config(
+        r#"hook-shell = "bash"
+post-create = "arr=(first second); echo \"${arr[1]}\" > fg_marker.txt"
+post-start = "arr=(first second); echo \"${arr[1]}\" > bg_marker.txt"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    let fg_marker = worktree_path.join("fg_marker.txt");
+    assert_eq!(fs::read_to_string(&fg_marker).unwrap().trim(), "second");
+
+    // Background hooks use it too
+    let bg_marker = worktree_path.join("bg_marker.txt");
+    wait_for_file_content(&bg_marker);
+    assert_eq!(fs::read_to_string(&bg_marker).unwrap().trim(), "second");
+}
+
+#[rstest]
+fn test_hook_shell_missing_warns(repo: TestRepo) {
+    repo.write_test_config("hook-shell = \"no-such-shell-for-wt-tests\"\n");
+
+    let output = repo.wt_command().args(["list"]).output().unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("no-such-shell-for-wt-tests") && stderr.contains("not found"),
+        "{stderr}"
+    );
+}